@@ -0,0 +1,73 @@
+use std::rc::Rc;
+use crate::foundation_types::{type_name, Any};
+use super::{Routine, Tuple};
+
+/// Routine that returns a result, wrapping a closure that takes its arguments as a tuple, such
+/// as `Function<(Integer, Integer), Boolean>`. Cloning a function shares the closure, and two
+/// functions are only equal if they share the same closure. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_function_class)
+pub struct Function<A: Tuple, R> {
+    routine: Rc<dyn Fn(A) -> R>,
+}
+
+impl<A: Tuple, R> Function<A, R> {
+
+    pub fn new<F>(routine: F) -> Self
+    where
+        F: Fn(A) -> R + 'static,
+    {
+        Function {
+            routine: Rc::new(routine),
+        }
+    }
+
+    /// Calls the function with the given arguments and returns its result.
+    pub fn call(&self, arguments: A) -> R {
+        (self.routine)(arguments)
+    }
+
+    /// Returns the declared type of the result.
+    pub fn result_type(&self) -> String {
+        type_name::<R>()
+    }
+
+}
+
+impl<A: Tuple, R> Routine for Function<A, R> {
+
+    fn argument_types(&self) -> Vec<String> {
+        A::item_types()
+    }
+
+}
+
+impl<A: Tuple, R> Any for Function<A, R> {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    /// Returns the signature in openEHR notation, such as `Function<<Integer, Integer>, Boolean>`.
+    fn type_of(&self) -> String {
+        format!("Function<{}, {}>", type_name::<A>(), self.result_type())
+    }
+
+}
+
+impl<A: Tuple, R> Clone for Function<A, R> {
+
+    fn clone(&self) -> Self {
+        Function {
+            routine: Rc::clone(&self.routine),
+        }
+    }
+
+}
+
+impl<A: Tuple, R> PartialEq for Function<A, R> {
+
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.routine, &other.routine)
+    }
+
+}
@@ -0,0 +1,11 @@
+mod tuple;
+mod routine;
+mod function;
+mod procedure;
+
+pub use {
+    tuple::Tuple,
+    routine::Routine,
+    function::Function,
+    procedure::Procedure,
+};
@@ -0,0 +1,68 @@
+use std::rc::Rc;
+use crate::foundation_types::{type_name, Any};
+use super::{Routine, Tuple};
+
+/// Routine that does not return a result, wrapping a closure that takes its arguments as a
+/// tuple, such as `Procedure<(String,)>`. Cloning a procedure shares the closure, and two
+/// procedures are only equal if they share the same closure. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_procedure_class)
+pub struct Procedure<A: Tuple> {
+    routine: Rc<dyn Fn(A)>,
+}
+
+impl<A: Tuple> Procedure<A> {
+
+    pub fn new<F>(routine: F) -> Self
+    where
+        F: Fn(A) + 'static,
+    {
+        Procedure {
+            routine: Rc::new(routine),
+        }
+    }
+
+    /// Calls the procedure with the given arguments.
+    pub fn call(&self, arguments: A) {
+        (self.routine)(arguments)
+    }
+
+}
+
+impl<A: Tuple> Routine for Procedure<A> {
+
+    fn argument_types(&self) -> Vec<String> {
+        A::item_types()
+    }
+
+}
+
+impl<A: Tuple> Any for Procedure<A> {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    /// Returns the signature in openEHR notation, such as `Procedure<<String>>`.
+    fn type_of(&self) -> String {
+        format!("Procedure<{}>", type_name::<A>())
+    }
+
+}
+
+impl<A: Tuple> Clone for Procedure<A> {
+
+    fn clone(&self) -> Self {
+        Procedure {
+            routine: Rc::clone(&self.routine),
+        }
+    }
+
+}
+
+impl<A: Tuple> PartialEq for Procedure<A> {
+
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.routine, &other.routine)
+    }
+
+}
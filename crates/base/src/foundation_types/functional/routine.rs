@@ -0,0 +1,16 @@
+use crate::foundation_types::Any;
+
+/// This trait is the base trait for all routines, i.e. functions and procedures, and gives
+/// access to their declared signature. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_routine_class)
+pub trait Routine: Any {
+
+    /// Returns the declared types of the arguments, in order.
+    fn argument_types(&self) -> Vec<String>;
+
+    /// Returns the number of arguments.
+    fn arity(&self) -> usize {
+        self.argument_types().len()
+    }
+
+}
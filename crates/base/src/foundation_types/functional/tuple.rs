@@ -0,0 +1,31 @@
+use crate::foundation_types::type_name;
+
+/// This trait describes the arguments of a routine: a Rust tuple whose items are the arguments
+/// in order, such as `(Integer, String)` for two arguments or `()` for none. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_tuple_class)
+pub trait Tuple {
+
+    /// Returns the type names of the items of the tuple, in order.
+    fn item_types() -> Vec<String>;
+
+}
+
+macro_rules! impl_tuple {
+    ($($item:ident),*) => {
+        impl<$($item),*> Tuple for ($($item,)*) {
+
+            fn item_types() -> Vec<String> {
+                vec![$(type_name::<$item>()),*]
+            }
+
+        }
+    };
+}
+
+impl_tuple!();
+impl_tuple!(A);
+impl_tuple!(A, B);
+impl_tuple!(A, B, C);
+impl_tuple!(A, B, C, D);
+impl_tuple!(A, B, C, D, E);
+impl_tuple!(A, B, C, D, E, F);
@@ -0,0 +1,60 @@
+use crate::foundation_types::Any;
+use super::MultiplicityInterval;
+
+/// Constraint on the number of items in a container, together with whether the items are
+/// ordered and whether they must be unique. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_cardinality_class)
+pub struct Cardinality {
+    pub interval: MultiplicityInterval,
+    pub is_ordered: bool,
+    pub is_unique: bool,
+}
+
+impl Cardinality {
+
+    pub fn new(interval: MultiplicityInterval, is_ordered: bool, is_unique: bool) -> Self {
+        Cardinality {
+            interval,
+            is_ordered,
+            is_unique,
+        }
+    }
+
+    /// Returns true if the container is unordered and may contain duplicates.
+    pub fn is_bag(&self) -> bool {
+        !self.is_ordered && !self.is_unique
+    }
+
+    /// Returns true if the container is ordered and may contain duplicates.
+    pub fn is_list(&self) -> bool {
+        self.is_ordered && !self.is_unique
+    }
+
+    /// Returns true if the container is unordered and contains no duplicates.
+    pub fn is_set(&self) -> bool {
+        !self.is_ordered && self.is_unique
+    }
+
+}
+
+impl Any for Cardinality {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn type_of(&self) -> String {
+        "Cardinality".to_string()
+    }
+
+}
+
+impl PartialEq for Cardinality {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.interval == other.interval
+            && self.is_ordered == other.is_ordered
+            && self.is_unique == other.is_unique
+    }
+
+}
@@ -0,0 +1,15 @@
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum Error {
+    /// The lower limit of the interval is greater than its upper limit, such as `|5..0|`.
+    #[error("lower limit greater than upper limit")]
+    ReversedLimits,
+
+    /// Two limits cannot be compared with each other, such as the partial date `2024` and the
+    /// date `2024-03-15`, so it is unknown which of them comes first.
+    #[error("limits cannot be compared")]
+    IncomparableLimits,
+
+    /// The lower limit of a multiplicity is negative, such as `-1..1`.
+    #[error("negative multiplicity")]
+    NegativeMultiplicity,
+}
@@ -0,0 +1,146 @@
+use crate::foundation_types::{Any, Ordered};
+
+/// Interval of ordered values, such as a reference range `|2.5..7.0|` or the occurrences
+/// `|0..*|` of an archetype node. Either end may be unbounded, and a bounded end may include or
+/// exclude its limit. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_interval_class)
+///
+/// Since [`Ordered`] only requires a partial order, a value that cannot be compared with a limit
+/// of the interval, such as the partial date `2024` against the limit `2024-03-15`, is not in
+/// the interval.
+pub trait Interval<T: Ordered>: Any {
+
+    /// Returns the lower limit of the interval, or `None` if the interval is unbounded below.
+    fn lower(&self) -> Option<&T>;
+
+    /// Returns the upper limit of the interval, or `None` if the interval is unbounded above.
+    fn upper(&self) -> Option<&T>;
+
+    /// Returns true if the lower limit is part of the interval. Always false if the interval is
+    /// unbounded below.
+    fn lower_included(&self) -> bool;
+
+    /// Returns true if the upper limit is part of the interval. Always false if the interval is
+    /// unbounded above.
+    fn upper_included(&self) -> bool;
+
+    /// Returns true if the interval has no lower limit.
+    fn lower_unbounded(&self) -> bool {
+        self.lower().is_none()
+    }
+
+    /// Returns true if the interval has no upper limit.
+    fn upper_unbounded(&self) -> bool {
+        self.upper().is_none()
+    }
+
+    /// Returns true if the value lies within the interval.
+    fn has(&self, value: &T) -> bool {
+        let above_lower = match self.lower() {
+            Some(lower) if self.lower_included() => lower <= value,
+            Some(lower) => lower < value,
+            None => true,
+        };
+        let below_upper = match self.upper() {
+            Some(upper) if self.upper_included() => value <= upper,
+            Some(upper) => value < upper,
+            None => true,
+        };
+        above_lower && below_upper
+    }
+
+    /// Returns true if every value of the other interval lies within this interval.
+    fn contains<I: Interval<T>>(&self, other: &I) -> bool {
+        starts_not_after(self, other) && ends_not_before(self, other)
+    }
+
+    /// Returns true if the two intervals have at least one value in common.
+    fn intersects<I: Interval<T>>(&self, other: &I) -> bool {
+        !ends_before(self, other) && !ends_before(other, self)
+    }
+
+}
+
+// ----------------------------------------------------------------------------------------------
+//                                         private functions
+// ----------------------------------------------------------------------------------------------
+/// Returns true if interval `a` starts at or before the start of interval `b`.
+pub(super) fn starts_not_after<T, A, B>(a: &A, b: &B) -> bool
+where
+    T: Ordered,
+    A: Interval<T> + ?Sized,
+    B: Interval<T> + ?Sized,
+{
+    match (a.lower(), b.lower()) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(x), Some(y)) => x < y || (x == y && (a.lower_included() || !b.lower_included())),
+    }
+}
+
+/// Returns true if interval `a` ends at or after the end of interval `b`.
+pub(super) fn ends_not_before<T, A, B>(a: &A, b: &B) -> bool
+where
+    T: Ordered,
+    A: Interval<T> + ?Sized,
+    B: Interval<T> + ?Sized,
+{
+    match (a.upper(), b.upper()) {
+        (None, _) => true,
+        (Some(_), None) => false,
+        (Some(x), Some(y)) => x > y || (x == y && (a.upper_included() || !b.upper_included())),
+    }
+}
+
+/// Returns true if every value of interval `a` lies before every value of interval `b`.
+pub(super) fn ends_before<T, A, B>(a: &A, b: &B) -> bool
+where
+    T: Ordered,
+    A: Interval<T> + ?Sized,
+    B: Interval<T> + ?Sized,
+{
+    match (a.upper(), b.lower()) {
+        (Some(x), Some(y)) => x < y || (x == y && !(a.upper_included() && b.lower_included())),
+        _ => false,
+    }
+}
+
+/// Returns true if every limit of interval `a` can be compared with every limit of interval `b`,
+/// so that the order of the two intervals is known.
+pub(super) fn comparable<T, A, B>(a: &A, b: &B) -> bool
+where
+    T: Ordered,
+    A: Interval<T> + ?Sized,
+    B: Interval<T> + ?Sized,
+{
+    [a.lower(), a.upper()].into_iter().flatten().all(|x| {
+        [b.lower(), b.upper()]
+            .into_iter()
+            .flatten()
+            .all(|y| x.partial_cmp(y).is_some())
+    })
+}
+
+/// Returns true if the two intervals overlap or touch, such as `|0..<5|` and `|5..10|`, so that
+/// their union is a single interval.
+pub(super) fn mergeable<T, A, B>(a: &A, b: &B) -> bool
+where
+    T: Ordered,
+    A: Interval<T> + ?Sized,
+    B: Interval<T> + ?Sized,
+{
+    !separated(a, b) && !separated(b, a)
+}
+
+/// Returns true if interval `a` lies before interval `b` with at least one value in between.
+fn separated<T, A, B>(a: &A, b: &B) -> bool
+where
+    T: Ordered,
+    A: Interval<T> + ?Sized,
+    B: Interval<T> + ?Sized,
+{
+    match (a.upper(), b.lower()) {
+        (Some(x), Some(y)) => x < y || (x == y && !a.upper_included() && !b.lower_included()),
+        _ => false,
+    }
+}
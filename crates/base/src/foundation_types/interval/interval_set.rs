@@ -0,0 +1,199 @@
+use crate::foundation_types::{type_name, Any, Ordered};
+use super::{
+    error::Error,
+    interval::{comparable, ends_before, mergeable},
+    Interval,
+    ProperInterval,
+};
+
+/// Set of values made up of disjoint intervals, such as the combined reference ranges
+/// `|0..<5|, |>10|`. Intervals that overlap or touch are merged when inserted, and empty
+/// intervals are dropped, so the intervals of the set are always ordered and separated by at
+/// least one value.
+///
+/// Intervals only touch if they share a limit that one of them includes: `|0..<5|` and
+/// `|5..10|` are merged, but `|0..4|` and `|5..10|` are not, even though no integer lies
+/// between them.
+///
+/// Every limit of the set can be compared with every other limit: an interval whose limits
+/// cannot be compared with those already in the set, such as `|2024|` in a set holding
+/// `|2024-03-01..2024-03-31|`, is rejected rather than merged.
+#[derive(Clone)]
+pub struct IntervalSet<T: Ordered> {
+    intervals: Vec<ProperInterval<T>>,
+}
+
+impl<T: Ordered> IntervalSet<T> {
+
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        IntervalSet {
+            intervals: Vec::new(),
+        }
+    }
+
+    /// Returns the intervals of the set in ascending order.
+    pub fn intervals(&self) -> &[ProperInterval<T>] {
+        &self.intervals
+    }
+
+    /// Returns the number of disjoint intervals in the set.
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    /// Returns true if the set has no values.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns true if the value lies within one of the intervals of the set.
+    pub fn has(&self, value: &T) -> bool {
+        self.intervals.iter().any(|interval| interval.has(value))
+    }
+
+    /// Returns true if every value of the interval lies within the set.
+    pub fn contains<I: Interval<T>>(&self, interval: &I) -> bool {
+        self.intervals.iter().any(|member| member.contains(interval))
+    }
+
+}
+
+impl<T: Ordered + Clone> IntervalSet<T> {
+
+    /// Adds an interval to the set, merging it with the intervals it overlaps or touches.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IncomparableLimits`], leaving the set unchanged, if a limit of the
+    /// interval cannot be compared with a limit of an interval in the set.
+    pub fn insert(&mut self, interval: ProperInterval<T>) -> Result<(), Error> {
+        if self.intervals.iter().any(|member| !comparable(&interval, member)) {
+            return Err(Error::IncomparableLimits);
+        }
+        if interval.is_empty() {
+            return Ok(());
+        }
+        let mut merged = interval;
+        let mut intervals = Vec::with_capacity(self.intervals.len() + 1);
+        for member in self.intervals.drain(..) {
+            if mergeable(&merged, &member) {
+                merged = merged.hull(&member);
+            } else {
+                intervals.push(member);
+            }
+        }
+        let position = intervals
+            .iter()
+            .position(|member| ends_before(&merged, member))
+            .unwrap_or(intervals.len());
+        intervals.insert(position, merged);
+        self.intervals = intervals;
+        Ok(())
+    }
+
+    /// Returns the values in either set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IncomparableLimits`] if a limit of one set cannot be compared with a
+    /// limit of the other.
+    pub fn union(&self, other: &IntervalSet<T>) -> Result<IntervalSet<T>, Error> {
+        let mut set = self.clone();
+        for interval in &other.intervals {
+            set.insert(interval.clone())?;
+        }
+        Ok(set)
+    }
+
+    /// Returns the values both sets have in common.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IncomparableLimits`] if a limit of one set cannot be compared with a
+    /// limit of the other.
+    pub fn intersection(&self, other: &IntervalSet<T>) -> Result<IntervalSet<T>, Error> {
+        let mut set = IntervalSet::new();
+        for interval in &self.intervals {
+            for other_interval in &other.intervals {
+                if let Some(common) = interval.intersection(other_interval)? {
+                    set.insert(common)?;
+                }
+            }
+        }
+        Ok(set)
+    }
+
+    /// Returns the values in this set that are not in the other set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IncomparableLimits`] if a limit of one set cannot be compared with a
+    /// limit of the other.
+    pub fn difference(&self, other: &IntervalSet<T>) -> Result<IntervalSet<T>, Error> {
+        let mut set = self.clone();
+        for other_interval in &other.intervals {
+            let mut remaining = IntervalSet::new();
+            for interval in &set.intervals {
+                remaining = remaining.union(&interval.difference(other_interval)?)?;
+            }
+            set = remaining;
+        }
+        Ok(set)
+    }
+
+    /// Returns the values between the lowest and highest value of the set that are not in the
+    /// set, such as `|>4..<6|` for `|0..4|, |6..10|`.
+    pub fn gaps(&self) -> IntervalSet<T> {
+        IntervalSet {
+            intervals: self
+                .intervals
+                .windows(2)
+                .filter_map(|pair| pair[0].gap(&pair[1]))
+                .collect(),
+        }
+    }
+
+}
+
+impl<T: Ordered> Default for IntervalSet<T> {
+
+    fn default() -> Self {
+        Self::new()
+    }
+
+}
+
+/// Collects intervals into a set, failing with [`Error::IncomparableLimits`] if the limits of two
+/// of the intervals cannot be compared.
+impl<T: Ordered + Clone> FromIterator<ProperInterval<T>> for Result<IntervalSet<T>, Error> {
+
+    fn from_iter<I: IntoIterator<Item = ProperInterval<T>>>(intervals: I) -> Self {
+        let mut set = IntervalSet::new();
+        for interval in intervals {
+            set.insert(interval)?;
+        }
+        Ok(set)
+    }
+
+}
+
+impl<T: Ordered> Any for IntervalSet<T> {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn type_of(&self) -> String {
+        format!("IntervalSet<{}>", type_name::<T>())
+    }
+
+}
+
+impl<T: Ordered> PartialEq for IntervalSet<T> {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.intervals == other.intervals
+    }
+
+}
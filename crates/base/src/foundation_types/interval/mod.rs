@@ -0,0 +1,18 @@
+#[allow(clippy::module_inception)]
+mod interval;
+mod error;
+mod proper_interval;
+mod point_interval;
+mod multiplicity_interval;
+mod cardinality;
+mod interval_set;
+
+pub use {
+    error::Error,
+    interval::Interval,
+    proper_interval::ProperInterval,
+    point_interval::PointInterval,
+    multiplicity_interval::MultiplicityInterval,
+    cardinality::Cardinality,
+    interval_set::IntervalSet,
+};
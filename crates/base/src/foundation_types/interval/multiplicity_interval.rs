@@ -0,0 +1,122 @@
+use crate::foundation_types::{Any, Integer};
+use super::{error::Error, Interval, ProperInterval};
+
+/// Interval of non-negative integers that constrains the number of occurrences of an item, such
+/// as `0..1` or `1..*`. Both limits are included. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_multiplicity_interval_class)
+#[derive(Clone)]
+pub struct MultiplicityInterval {
+    interval: ProperInterval<Integer>,
+}
+
+impl MultiplicityInterval {
+
+    /// Marker separating the lower and upper limit in the textual form.
+    pub const RANGE_MARKER: &'static str = "..";
+
+    /// Marker for an unbounded upper limit in the textual form.
+    pub const UNBOUNDED_MARKER: &'static str = "*";
+
+    /// Creates a multiplicity from its lower limit and its upper limit; `None` means the upper
+    /// limit is unbounded.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NegativeMultiplicity`] if the lower limit is negative and
+    /// [`Error::ReversedLimits`] if it is greater than the upper limit.
+    pub fn new(lower: i32, upper: Option<i32>) -> Result<Self, Error> {
+        if lower < 0 {
+            return Err(Error::NegativeMultiplicity);
+        }
+        Ok(MultiplicityInterval {
+            interval: ProperInterval::new(
+                Some(Integer::new(lower)),
+                upper.map(Integer::new),
+                true,
+                true,
+            )?,
+        })
+    }
+
+    /// Returns true if the multiplicity is `0..*`, i.e. does not constrain the occurrences.
+    pub fn is_open(&self) -> bool {
+        self.lower_limit() == 0 && self.upper_limit().is_none()
+    }
+
+    /// Returns true if the multiplicity is `0..1`.
+    pub fn is_optional(&self) -> bool {
+        self.lower_limit() == 0 && self.upper_limit() == Some(1)
+    }
+
+    /// Returns true if at least one occurrence is required.
+    pub fn is_mandatory(&self) -> bool {
+        self.lower_limit() >= 1
+    }
+
+    /// Returns true if the multiplicity is `0..0`, i.e. no occurrences are allowed.
+    pub fn is_prohibited(&self) -> bool {
+        self.lower_limit() == 0 && self.upper_limit() == Some(0)
+    }
+
+    // ----------------------------------------------------------------------------------------------
+    //                                         private methods
+    // ----------------------------------------------------------------------------------------------
+    fn lower_limit(&self) -> i32 {
+        self.interval.lower().map_or(0, |lower| lower.value)
+    }
+
+    fn upper_limit(&self) -> Option<i32> {
+        self.interval.upper().map(|upper| upper.value)
+    }
+
+}
+
+impl Interval<Integer> for MultiplicityInterval {
+
+    fn lower(&self) -> Option<&Integer> {
+        self.interval.lower()
+    }
+
+    fn upper(&self) -> Option<&Integer> {
+        self.interval.upper()
+    }
+
+    fn lower_included(&self) -> bool {
+        true
+    }
+
+    fn upper_included(&self) -> bool {
+        self.interval.upper_included()
+    }
+
+}
+
+impl Any for MultiplicityInterval {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn type_of(&self) -> String {
+        "MultiplicityInterval".to_string()
+    }
+
+}
+
+impl PartialEq for MultiplicityInterval {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.interval == other.interval
+    }
+
+}
+
+impl std::fmt::Display for MultiplicityInterval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", self.lower_limit(), Self::RANGE_MARKER)?;
+        match self.upper_limit() {
+            Some(upper) => write!(f, "{}", upper),
+            None => write!(f, "{}", Self::UNBOUNDED_MARKER),
+        }
+    }
+}
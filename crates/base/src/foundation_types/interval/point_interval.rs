@@ -0,0 +1,64 @@
+use crate::foundation_types::{type_name, Any, Ordered};
+use super::Interval;
+
+/// Interval consisting of a single value, such as `|5|`. Both limits are the value itself and
+/// are included. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_point_interval_class)
+pub struct PointInterval<T: Ordered> {
+    value: T,
+}
+
+impl<T: Ordered> PointInterval<T> {
+
+    pub fn new(value: T) -> Self {
+        PointInterval {
+            value
+        }
+    }
+
+    /// Returns the single value of the interval.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+}
+
+impl<T: Ordered> Interval<T> for PointInterval<T> {
+
+    fn lower(&self) -> Option<&T> {
+        Some(&self.value)
+    }
+
+    fn upper(&self) -> Option<&T> {
+        Some(&self.value)
+    }
+
+    fn lower_included(&self) -> bool {
+        true
+    }
+
+    fn upper_included(&self) -> bool {
+        true
+    }
+
+}
+
+impl<T: Ordered> Any for PointInterval<T> {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn type_of(&self) -> String {
+        format!("PointInterval<{}>", type_name::<T>())
+    }
+
+}
+
+impl<T: Ordered> PartialEq for PointInterval<T> {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+
+}
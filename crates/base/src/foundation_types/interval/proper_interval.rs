@@ -0,0 +1,240 @@
+use std::cmp::Ordering;
+use crate::foundation_types::{type_name, Any, Ordered};
+use super::{
+    error::Error,
+    interval::{comparable, ends_before, ends_not_before, mergeable, starts_not_after},
+    Interval,
+    IntervalSet,
+};
+
+/// Interval whose lower limit is not greater than its upper limit, such as `|0..5|`, `|>=2.5|`
+/// or `|<10|`. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_proper_interval_class)
+#[derive(Clone)]
+pub struct ProperInterval<T: Ordered> {
+    lower: Option<T>,
+    upper: Option<T>,
+    lower_included: bool,
+    upper_included: bool,
+}
+
+impl<T: Ordered> ProperInterval<T> {
+
+    /// Creates an interval from its limits. A limit of `None` makes that end unbounded, in
+    /// which case the corresponding `included` flag is ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::ReversedLimits`] if the lower limit is greater than the upper limit, and
+    /// [`Error::IncomparableLimits`] if the limits cannot be compared, such as the partial date
+    /// `2024` and the date `2024-03-15`.
+    pub fn new(
+        lower: Option<T>,
+        upper: Option<T>,
+        lower_included: bool,
+        upper_included: bool,
+    ) -> Result<Self, Error> {
+        if let (Some(lower), Some(upper)) = (&lower, &upper) {
+            match lower.partial_cmp(upper) {
+                Some(Ordering::Greater) => return Err(Error::ReversedLimits),
+                None => return Err(Error::IncomparableLimits),
+                _ => (),
+            }
+        }
+        Ok(Self::from_limits(lower, upper, lower_included, upper_included))
+    }
+
+    /// Creates an interval that includes both of its limits, such as `|0..5|`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`new`](#method.new).
+    pub fn closed(lower: T, upper: T) -> Result<Self, Error> {
+        Self::new(Some(lower), Some(upper), true, true)
+    }
+
+    /// Creates an interval that is unbounded at both ends and therefore has every value.
+    pub fn unbounded() -> Self {
+        Self::from_limits(None, None, false, false)
+    }
+
+    /// Returns true if the interval has no values, such as `|5..<5|`.
+    pub fn is_empty(&self) -> bool {
+        match (&self.lower, &self.upper) {
+            (Some(lower), Some(upper)) => {
+                lower == upper && !(self.lower_included && self.upper_included)
+            }
+            _ => false,
+        }
+    }
+
+    // ----------------------------------------------------------------------------------------------
+    //                                         private methods
+    // ----------------------------------------------------------------------------------------------
+    fn from_limits(
+        lower: Option<T>,
+        upper: Option<T>,
+        lower_included: bool,
+        upper_included: bool,
+    ) -> Self {
+        ProperInterval {
+            lower_included: lower_included && lower.is_some(),
+            upper_included: upper_included && upper.is_some(),
+            lower,
+            upper,
+        }
+    }
+
+}
+
+impl<T: Ordered + Clone> ProperInterval<T> {
+
+    /// Returns the values both intervals have in common, or `None` if the intervals do not
+    /// intersect.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IncomparableLimits`] if a limit of one interval cannot be compared with a
+    /// limit of the other.
+    pub fn intersection(
+        &self,
+        other: &ProperInterval<T>,
+    ) -> Result<Option<ProperInterval<T>>, Error> {
+        if !comparable(self, other) {
+            return Err(Error::IncomparableLimits);
+        }
+        if !self.intersects(other) {
+            return Ok(None);
+        }
+        let start = if starts_not_after(self, other) { other } else { self };
+        let end = if ends_not_before(self, other) { other } else { self };
+        Ok(Some(Self::from_limits(
+            start.lower.clone(),
+            end.upper.clone(),
+            start.lower_included,
+            end.upper_included,
+        )))
+    }
+
+    /// Returns the values in either interval. This is a single interval if the intervals
+    /// overlap or touch, such as `|0..<5|` and `|5..10|`, and two intervals otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IncomparableLimits`] if a limit of one interval cannot be compared with a
+    /// limit of the other.
+    pub fn union(&self, other: &ProperInterval<T>) -> Result<IntervalSet<T>, Error> {
+        let mut set = IntervalSet::new();
+        set.insert(self.clone())?;
+        set.insert(other.clone())?;
+        Ok(set)
+    }
+
+    /// Returns the values in this interval that are not in the other interval. This may be no,
+    /// one or two intervals; `|0..10|` minus `|3..5|` is `|0..<3|` and `|>5..10|`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IncomparableLimits`] if a limit of one interval cannot be compared with a
+    /// limit of the other.
+    pub fn difference(&self, other: &ProperInterval<T>) -> Result<IntervalSet<T>, Error> {
+        if !comparable(self, other) {
+            return Err(Error::IncomparableLimits);
+        }
+        let mut set = IntervalSet::new();
+        if !self.intersects(other) {
+            set.insert(self.clone())?;
+            return Ok(set);
+        }
+        if !starts_not_after(other, self) {
+            set.insert(Self::from_limits(
+                self.lower.clone(),
+                other.lower.clone(),
+                self.lower_included,
+                !other.lower_included,
+            ))?;
+        }
+        if !ends_not_before(other, self) {
+            set.insert(Self::from_limits(
+                other.upper.clone(),
+                self.upper.clone(),
+                !other.upper_included,
+                self.upper_included,
+            ))?;
+        }
+        Ok(set)
+    }
+
+    // ----------------------------------------------------------------------------------------------
+    //                                         private methods
+    // ----------------------------------------------------------------------------------------------
+    /// Returns the smallest interval covering both intervals.
+    pub(super) fn hull(&self, other: &ProperInterval<T>) -> ProperInterval<T> {
+        let start = if starts_not_after(self, other) { self } else { other };
+        let end = if ends_not_before(self, other) { self } else { other };
+        Self::from_limits(
+            start.lower.clone(),
+            end.upper.clone(),
+            start.lower_included,
+            end.upper_included,
+        )
+    }
+
+    /// Returns the interval lying strictly between this interval and a later one, such as
+    /// `|>5..<7|` between `|0..5|` and `|7..10|`, or `None` if the intervals touch or overlap.
+    pub(super) fn gap(&self, later: &ProperInterval<T>) -> Option<ProperInterval<T>> {
+        if mergeable(self, later) || !ends_before(self, later) {
+            return None;
+        }
+        Some(Self::from_limits(
+            self.upper.clone(),
+            later.lower.clone(),
+            !self.upper_included,
+            !later.lower_included,
+        ))
+    }
+
+}
+
+impl<T: Ordered> Interval<T> for ProperInterval<T> {
+
+    fn lower(&self) -> Option<&T> {
+        self.lower.as_ref()
+    }
+
+    fn upper(&self) -> Option<&T> {
+        self.upper.as_ref()
+    }
+
+    fn lower_included(&self) -> bool {
+        self.lower_included
+    }
+
+    fn upper_included(&self) -> bool {
+        self.upper_included
+    }
+
+}
+
+impl<T: Ordered> Any for ProperInterval<T> {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn type_of(&self) -> String {
+        format!("ProperInterval<{}>", type_name::<T>())
+    }
+
+}
+
+impl<T: Ordered> PartialEq for ProperInterval<T> {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.lower == other.lower
+            && self.upper == other.upper
+            && self.lower_included == other.lower_included
+            && self.upper_included == other.upper_included
+    }
+
+}
@@ -4,41 +4,95 @@ mod structured_types;
 mod terminology;
 mod time;
 mod interval;
+mod value;
+mod type_system;
 
 pub use primitive_types::{
     Any,
     Boolean,
     Numeric,
+    NumericError,
+    Literal,
+    ParseError,
     Ordered,
     OrderNumeric,
     Integer,
     Integer64,
+    Decimal,
+    Number,
     Double,
     Real,
     Character,
     Octet,
+    Octets,
+    OctetsError,
+    Encoding as OctetsEncoding,
+    ChecksumAlgorithm,
     Uri,
+    UriError,
+    UriErrorContext,
+    MissingSchemeError,
+    UriTemplate,
+    UriTemplateError,
+    UriTemplateValue,
+    EhrUri,
+    EhrUriError,
     String,
     StringExtension,
 };
 
-pub use functional::{
+pub(crate) use primitive_types::type_name;
+
+pub use terminology::{
+    TerminologyCode,
+    TerminologyTerm
+};
 
+pub use functional::{
+    Tuple,
+    Routine,
+    Function,
+    Procedure,
 };
 
 pub use structured_types::{
-
+    Container,
+    List,
+    Array,
+    Set,
+    Hash,
 };
 
-pub use terminology::{
-    TerminologyCode,
-    TerminologyTerm
+pub use interval::{
+    Error as IntervalError,
+    Interval,
+    ProperInterval,
+    PointInterval,
+    MultiplicityInterval,
+    Cardinality,
+    IntervalSet,
 };
 
 pub use time::{
-
+    Context as TimeContext,
+    Error as TimeError,
+    TimeDefinitions,
+    Temporal,
+    Iso8601Type,
+    Iso8601Timezone,
+    Iso8601Date,
+    Iso8601Time,
+    Iso8601DateTime,
+    Iso8601Duration,
 };
 
-pub use interval::{
+pub use value::{
+    Error as ValueError,
+    OpenEhrValue,
+    ValueType,
+};
 
-};
\ No newline at end of file
+pub use type_system::{
+    Error as TypeRegistryError,
+    TypeRegistry,
+};
@@ -1,4 +1,6 @@
 use std::cmp::PartialEq;
+use std::sync::PoisonError;
+use crate::foundation_types::TypeRegistry;
 
 /// This is the base trait for all types in openEHR. It contains the bare minimum of operations
 /// that all types must implement. See the
@@ -8,10 +10,54 @@ pub trait Any: PartialEq {
     /// Returns true if the two objects are equal, false otherwise.
     fn is_equal(&self, other: &Self) -> bool;
 
-    /// Returns true if the object is an instance of the given type, false otherwise.
-    fn instance_of(&self, type_name: &str) -> bool;
+    /// Returns true if the object is an instance of the given type or of one of its subtypes,
+    /// false otherwise. An `Integer` is an instance of `Integer`, `OrderNumeric`, `Ordered`,
+    /// `Numeric` and `Any`.
+    fn instance_of(&self, type_name: &str) -> bool {
+        self.conforms_to(type_name)
+    }
 
     /// Returns the type of the object as a string.
     fn type_of(&self) -> String;
 
-}
\ No newline at end of file
+    /// Returns true if the type of the object conforms to the given type according to the
+    /// [global type registry](TypeRegistry::global), such as `List<Integer>` to
+    /// `Container<Numeric>`.
+    fn conforms_to(&self, type_name: &str) -> bool {
+        TypeRegistry::global()
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .conforms_to(&self.type_of(), type_name)
+    }
+
+}
+
+/// Returns the name of type `T` without module paths, such as `Integer` for
+/// `base::foundation_types::primitive_types::integer::Integer` or `Vec<Integer>` for a vector of
+/// them. Tuples are written in the openEHR notation, so `(Integer, String)` becomes
+/// `<Integer, String>`. Used by generic types to report their type in [`Any::type_of`].
+pub(crate) fn type_name<T: ?Sized>() -> std::string::String {
+    let full_name = std::any::type_name::<T>();
+    let mut name = std::string::String::with_capacity(full_name.len());
+    let mut segment_start = 0;
+    for (index, c) in full_name.char_indices() {
+        if matches!(c, '<' | '>' | ',' | ' ' | '(' | ')' | '[' | ']' | ';' | '&') {
+            let segment = &full_name[segment_start..index];
+            name.push_str(segment.rsplit("::").next().unwrap_or(segment));
+            match c {
+                '(' => name.push('<'),
+                ')' => {
+                    if name.ends_with(',') {
+                        name.pop();
+                    }
+                    name.push('>');
+                }
+                _ => name.push(c),
+            }
+            segment_start = index + c.len_utf8();
+        }
+    }
+    let segment = &full_name[segment_start..];
+    name.push_str(segment.rsplit("::").next().unwrap_or(segment));
+    name
+}
@@ -1,7 +1,15 @@
+use std::hash::{Hash, Hasher};
 use std::ops::Not;
-use crate::foundation_types::Any;
+use std::str::FromStr;
+use crate::foundation_types::{Any, Literal, ParseError};
 
 
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_::Serialize, serde_::Deserialize),
+    serde(crate = "serde_", transparent)
+)]
 pub struct Boolean {
     pub value: bool,
 }
@@ -20,10 +28,6 @@ impl Any for Boolean {
         self.value == other.value
     }
 
-    fn instance_of(&self, class_name: &str) -> bool {
-        class_name == "Boolean"
-    }
-
     fn type_of(&self) -> String {
         "Boolean".to_string()
     }
@@ -47,6 +51,14 @@ impl PartialEq<Boolean> for Boolean {
 
 impl Eq for Boolean {}
 
+impl Hash for Boolean {
+
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+
+}
+
 impl Not for &Boolean {
     type Output = Boolean;
 
@@ -54,4 +66,28 @@ impl Not for &Boolean {
         Boolean::new(!self.value)
     }
 
-}
\ No newline at end of file
+}
+
+/// Writes the boolean as `True` or `False`.
+impl std::fmt::Display for Boolean {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", if self.value { "True" } else { "False" })
+    }
+}
+
+/// Reads a boolean literal, `True` or `False`, ignoring case.
+impl FromStr for Boolean {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            Err(ParseError::Empty(Literal::Boolean))
+        } else if s.eq_ignore_ascii_case("true") {
+            Ok(Boolean::new(true))
+        } else if s.eq_ignore_ascii_case("false") {
+            Ok(Boolean::new(false))
+        } else {
+            Err(ParseError::InvalidFormat(Literal::Boolean))
+        }
+    }
+}
@@ -1,6 +1,15 @@
 use std::cmp::Ordering;
-use crate::foundation_types::{Any, Ordered};
-
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use unicode_properties::{GeneralCategory, UnicodeGeneralCategory};
+use crate::foundation_types::{Any, Ordered, Literal, ParseError};
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_::Serialize, serde_::Deserialize),
+    serde(crate = "serde_", transparent)
+)]
 pub struct Character {
     pub value: char,
 }
@@ -11,6 +20,71 @@ impl Character {
         Character { value }
     }
 
+    /// Returns true if the character is a letter of any script, such as `a`, `ß` or `ж`.
+    pub fn is_letter(&self) -> bool {
+        self.value.is_alphabetic()
+    }
+
+    /// Returns true if the character is a decimal digit of any script, such as `7` or the
+    /// Arabic-Indic `٣`. Other numeric characters, such as `½` or `Ⅻ`, are not digits.
+    pub fn is_digit(&self) -> bool {
+        self.value.general_category() == GeneralCategory::DecimalNumber
+    }
+
+    /// Returns true if the character is white space, such as a space, tab, line feed or
+    /// no-break space.
+    pub fn is_whitespace(&self) -> bool {
+        self.value.is_whitespace()
+    }
+
+    /// Returns true if the character is an upper case letter, such as `A` or `Ж`.
+    pub fn is_uppercase(&self) -> bool {
+        self.value.is_uppercase()
+    }
+
+    /// Returns true if the character is a lower case letter, such as `a` or `ж`.
+    pub fn is_lowercase(&self) -> bool {
+        self.value.is_lowercase()
+    }
+
+    /// Returns the upper case form of the character. This is a string because some characters
+    /// map to more than one, such as `ß` to `SS`.
+    pub fn to_uppercase(&self) -> String {
+        self.value.to_uppercase().collect()
+    }
+
+    /// Returns the lower case form of the character. This is a string because some characters
+    /// map to more than one, such as `İ` to `i` followed by a combining dot above.
+    pub fn to_lowercase(&self) -> String {
+        self.value.to_lowercase().collect()
+    }
+
+    // ----------------------------------------------------------------------------------------------
+    //                                         private methods
+    // ----------------------------------------------------------------------------------------------
+    /// Returns the character of an escape sequence, given the text after the backslash.
+    fn unescape(escape: &str) -> Result<char, ParseError> {
+        let invalid = ParseError::InvalidEscape(Literal::Character);
+        match escape {
+            "'" => Ok('\''),
+            "\"" => Ok('"'),
+            "\\" => Ok('\\'),
+            "n" => Ok('\n'),
+            "r" => Ok('\r'),
+            "t" => Ok('\t'),
+            _ => {
+                let hex = escape.strip_prefix('u').ok_or(invalid)?;
+                if hex.len() != 4 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+                    return Err(invalid);
+                }
+                u32::from_str_radix(hex, 16)
+                    .ok()
+                    .and_then(char::from_u32)
+                    .ok_or(invalid)
+            }
+        }
+    }
+
 }
 
 impl Any for Character {
@@ -19,10 +93,6 @@ impl Any for Character {
         self.value == other.value
     }
 
-    fn instance_of(&self, class_name: &str) -> bool {
-        class_name == "Character"
-    }
-
     fn type_of(&self) -> String {
         "Character".to_string()
     }
@@ -39,6 +109,14 @@ impl PartialEq<Character> for Character {
 
 impl Eq for Character {}
 
+impl Hash for Character {
+
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+
+}
+
 impl PartialEq<char> for Character {
 
     fn eq(&self, other: &char) -> bool {
@@ -60,8 +138,49 @@ impl Ord for Character {
 impl PartialOrd for Character {
 
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.value.cmp(&other.value))
+        Some(self.cmp(other))
     }
 
 }
 
+/// Writes the character as a literal between single quotes, such as `'a'`. Quotes, backslashes
+/// and control characters are escaped, as in `'\''`, `'\n'` or `'\u0007'`.
+impl std::fmt::Display for Character {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.value {
+            '\'' => write!(f, "'\\''"),
+            '\\' => write!(f, "'\\\\'"),
+            '\n' => write!(f, "'\\n'"),
+            '\r' => write!(f, "'\\r'"),
+            '\t' => write!(f, "'\\t'"),
+            c if c.is_control() => write!(f, "'\\u{:04x}'", c as u32),
+            c => write!(f, "'{}'", c),
+        }
+    }
+}
+
+/// Reads a character literal between single quotes, such as `'a'`, `'\n'` or `'é'`. The
+/// escape sequences are `\'`, `\"`, `\\`, `\n`, `\r`, `\t` and `\u` followed by four hexadecimal
+/// digits.
+impl FromStr for Character {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseError::Empty(Literal::Character));
+        }
+        let content = s
+            .strip_prefix('\'')
+            .and_then(|s| s.strip_suffix('\''))
+            .filter(|content| !content.is_empty())
+            .ok_or(ParseError::InvalidFormat(Literal::Character))?;
+        let mut chars = content.chars();
+        let value = match chars.next() {
+            Some('\\') => Self::unescape(chars.as_str())?,
+            Some('\'') => return Err(ParseError::InvalidFormat(Literal::Character)),
+            Some(c) if chars.as_str().is_empty() => c,
+            _ => return Err(ParseError::InvalidFormat(Literal::Character)),
+        };
+        Ok(Character::new(value))
+    }
+}
@@ -0,0 +1,59 @@
+use std::cmp::Ordering;
+use unicode_normalization::UnicodeNormalization;
+use unicode_properties::{GeneralCategoryGroup, UnicodeGeneralCategory};
+
+// ----------------------------------------------------------------------------------------------
+//                                         private functions
+// ----------------------------------------------------------------------------------------------
+/// Compares two strings level by level, in the manner of the Unicode Collation Algorithm: first
+/// the letters ignoring accents and case, then the accents, then the case, and finally the code
+/// points of the canonical decomposition. Strings are therefore only equal if they are
+/// canonically equivalent.
+pub(super) fn collate(a: &str, b: &str) -> Ordering {
+    CollationKey::new(a).cmp(&CollationKey::new(b))
+}
+
+/// The levels of a string compared by [`collate`], in order of significance.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+struct CollationKey {
+    /// The case-folded characters without accents, so that `Émile` and `emile` have the same
+    /// letters.
+    letters: Vec<char>,
+
+    /// The combining marks following each letter, such as the acute accent of `É`. A letter
+    /// without marks sorts before the same letter with marks.
+    accents: Vec<Vec<char>>,
+
+    /// Whether each letter is upper case. Lower case sorts first.
+    upper_case: Vec<bool>,
+
+    /// The canonical decomposition of the string.
+    decomposition: Vec<char>,
+}
+
+impl CollationKey {
+
+    fn new(s: &str) -> Self {
+        let mut key = CollationKey {
+            letters: Vec::new(),
+            accents: Vec::new(),
+            upper_case: Vec::new(),
+            decomposition: s.nfd().collect(),
+        };
+        for &c in &key.decomposition {
+            if c.general_category_group() == GeneralCategoryGroup::Mark {
+                if let Some(accents) = key.accents.last_mut() {
+                    accents.push(c);
+                    continue;
+                }
+            }
+            for folded in c.to_uppercase().flat_map(char::to_lowercase) {
+                key.letters.push(folded);
+                key.accents.push(Vec::new());
+                key.upper_case.push(c.is_uppercase());
+            }
+        }
+        key
+    }
+
+}
@@ -0,0 +1,384 @@
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::iter::{Product, Sum};
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use std::str::FromStr;
+use bigdecimal::{BigDecimal, RoundingMode, Zero};
+use crate::foundation_types::{
+    Any,
+    Double,
+    Integer,
+    Literal,
+    Numeric,
+    NumericError,
+    OrderNumeric,
+    Ordered,
+    ParseError,
+};
+
+/// An exact decimal number of arbitrary precision, for values such as lab results and dose
+/// totals where `0.1 + 0.2` must be `0.3`. A decimal keeps its scale, the number of digits after
+/// the decimal point, so `5.10` is written back as `5.10` and not as `5.1`. Sums and differences
+/// have the larger scale of their operands and products the sum of both scales, as in written
+/// arithmetic.
+///
+/// Equality and ordering compare the numeric values and ignore the scale: `5.10` equals `5.1`.
+#[derive(Clone, Debug)]
+pub struct Decimal {
+    value: BigDecimal,
+}
+
+impl Decimal {
+
+    /// The maximum number of digits after the decimal point of a quotient that does not
+    /// terminate, such as `1 / 3`.
+    pub const DIVISION_SCALE: u32 = 32;
+
+    /// Creates a decimal from its digits without the decimal point and its scale, so
+    /// `Decimal::new(510, 2)` is `5.10`.
+    pub fn new(unscaled: i64, scale: u32) -> Self {
+        Decimal {
+            value: BigDecimal::new(unscaled.into(), scale as i64),
+        }
+    }
+
+    /// Interprets the given string as a decimal, such as `5.10`, `-0.003` or `+42`. Exponents,
+    /// group separators and a decimal comma are not accepted.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`ParseError::Empty`] if the string is empty and [`ParseError::InvalidFormat`]
+    /// if it is not a decimal number.
+    pub fn parse<T>(decimal_string: T) -> Result<Self, ParseError>
+    where
+        T: AsRef<str>,
+    {
+        let s = decimal_string.as_ref();
+        if s.is_empty() {
+            return Err(ParseError::Empty(Literal::Decimal));
+        }
+        let digits = s.strip_prefix(['+', '-']).unwrap_or(s);
+        let (integral, fraction) = match digits.split_once('.') {
+            Some((integral, fraction)) => (integral, Some(fraction)),
+            None => (digits, None),
+        };
+        let all_digits = |part: &str| !part.is_empty() && part.bytes().all(|b| b.is_ascii_digit());
+        if !all_digits(integral) || fraction.is_some_and(|fraction| !all_digits(fraction)) {
+            return Err(ParseError::InvalidFormat(Literal::Decimal));
+        }
+        BigDecimal::from_str(s)
+            .map(|value| Decimal { value })
+            .map_err(|_| ParseError::InvalidFormat(Literal::Decimal))
+    }
+
+    /// Returns the number of digits after the decimal point.
+    pub fn scale(&self) -> Integer {
+        Integer::new(self.scale_digits() as i32)
+    }
+
+    /// Returns the decimal with the given number of digits after the decimal point, rounding
+    /// half away from zero if digits are dropped: `2.345` rounded to scale 2 is `2.35`, and
+    /// `2.3` rounded to scale 3 is `2.300`.
+    pub fn round(&self, scale: u32) -> Decimal {
+        Decimal {
+            value: self.value.with_scale_round(scale as i64, RoundingMode::HalfUp),
+        }
+    }
+
+    /// Returns the quotient, exact if it terminates within
+    /// [`DIVISION_SCALE`](Self::DIVISION_SCALE) digits after the decimal point and rounded half
+    /// away from zero otherwise.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NumericError::DivisionByZero`] if the divisor is zero.
+    pub fn try_div(&self, other: &Decimal) -> Result<Decimal, NumericError> {
+        if other.value.is_zero() {
+            return Err(NumericError::DivisionByZero);
+        }
+        let quotient = (&self.value / &other.value)
+            .with_scale_round(Self::DIVISION_SCALE as i64, RoundingMode::HalfUp)
+            .normalized();
+        let scale = quotient.fractional_digit_count().max(0);
+        Ok(Decimal {
+            value: quotient.with_scale(scale),
+        })
+    }
+
+    /// Returns the nearest `Double`. This loses precision if the decimal has more significant
+    /// digits than a `Double` can hold.
+    pub fn to_double(&self) -> Double {
+        Double::new(self.to_string().parse().unwrap_or(f64::NAN))
+    }
+
+    // ----------------------------------------------------------------------------------------------
+    //                                         private methods
+    // ----------------------------------------------------------------------------------------------
+    fn scale_digits(&self) -> u32 {
+        self.value.fractional_digit_count().max(0) as u32
+    }
+
+}
+
+impl Any for Decimal {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn type_of(&self) -> String {
+        "Decimal".to_string()
+    }
+
+}
+
+impl Numeric for Decimal {
+
+    fn pow(&self, exponent: &Double) -> Double {
+        Double::new(self.to_double().value.powf(exponent.value))
+    }
+
+}
+
+impl Ordered for Decimal {
+}
+
+impl PartialOrd for Decimal {
+
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+
+}
+
+impl Ord for Decimal {
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.value.cmp(&other.value)
+    }
+
+}
+
+impl PartialEq for Decimal {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.value == other.value
+    }
+
+}
+
+impl Eq for Decimal {
+}
+
+impl Hash for Decimal {
+
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+
+}
+
+impl Add for Decimal {
+    type Output = Decimal;
+
+    fn add(self, other: Self) -> Decimal {
+        Decimal {
+            value: self.value + other.value,
+        }
+    }
+
+}
+
+impl Sub for Decimal {
+    type Output = Decimal;
+
+    fn sub(self, other: Self) -> Decimal {
+        Decimal {
+            value: self.value - other.value,
+        }
+    }
+
+}
+
+/// See [`Decimal::try_div`]. Panics if the divisor is zero.
+impl Div for Decimal {
+    type Output = Decimal;
+
+    fn div(self, other: Self) -> Decimal {
+        self.try_div(&other).expect("attempt to divide by zero")
+    }
+
+}
+
+impl Mul for Decimal {
+    type Output = Decimal;
+
+    fn mul(self, other: Self) -> Decimal {
+        Decimal {
+            value: self.value * other.value,
+        }
+    }
+
+}
+
+impl Neg for Decimal {
+    type Output = Decimal;
+
+    fn neg(self) -> Decimal {
+        Decimal {
+            value: -self.value,
+        }
+    }
+
+}
+
+impl OrderNumeric for Decimal {
+}
+
+impl Add<&Decimal> for &Decimal {
+    type Output = Decimal;
+
+    fn add(self, other: &Decimal) -> Decimal {
+        Decimal {
+            value: &self.value + &other.value,
+        }
+    }
+
+}
+
+impl Sub<&Decimal> for &Decimal {
+    type Output = Decimal;
+
+    fn sub(self, other: &Decimal) -> Decimal {
+        Decimal {
+            value: &self.value - &other.value,
+        }
+    }
+
+}
+
+/// See [`Decimal::try_div`]. Panics if the divisor is zero.
+impl Div<&Decimal> for &Decimal {
+    type Output = Decimal;
+
+    fn div(self, other: &Decimal) -> Decimal {
+        self.try_div(other).expect("attempt to divide by zero")
+    }
+
+}
+
+impl Mul<&Decimal> for &Decimal {
+    type Output = Decimal;
+
+    fn mul(self, other: &Decimal) -> Decimal {
+        Decimal {
+            value: &self.value * &other.value,
+        }
+    }
+
+}
+
+impl Neg for &Decimal {
+    type Output = Decimal;
+
+    fn neg(self) -> Decimal {
+        Decimal {
+            value: -&self.value,
+        }
+    }
+
+}
+
+impl Sum for Decimal {
+
+    fn sum<I: Iterator<Item = Decimal>>(iter: I) -> Decimal {
+        iter.fold(Decimal::new(0, 0), |total, value| total + value)
+    }
+
+}
+
+impl<'a> Sum<&'a Decimal> for Decimal {
+
+    fn sum<I: Iterator<Item = &'a Decimal>>(iter: I) -> Decimal {
+        iter.fold(Decimal::new(0, 0), |total, value| &total + value)
+    }
+
+}
+
+impl Product for Decimal {
+
+    fn product<I: Iterator<Item = Decimal>>(iter: I) -> Decimal {
+        iter.fold(Decimal::new(1, 0), |total, value| total * value)
+    }
+
+}
+
+impl<'a> Product<&'a Decimal> for Decimal {
+
+    fn product<I: Iterator<Item = &'a Decimal>>(iter: I) -> Decimal {
+        iter.fold(Decimal::new(1, 0), |total, value| &total * value)
+    }
+
+}
+
+/// Writes the decimal in plain notation with all the digits of its scale, such as `5.10`.
+impl std::fmt::Display for Decimal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value.with_scale(self.scale_digits() as i64).to_plain_string())
+    }
+}
+
+impl FromStr for Decimal {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Decimal::parse(s)
+    }
+}
+
+/// Converts a `Double` to the shortest decimal that converts back to the same `Double`, so
+/// `0.1` becomes the decimal `0.1` rather than `0.1000000000000000055511151231257827`.
+impl TryFrom<Double> for Decimal {
+    type Error = NumericError;
+
+    fn try_from(value: Double) -> Result<Self, Self::Error> {
+        if value.value.is_nan() {
+            return Err(NumericError::NotANumber);
+        }
+        if value.value.is_infinite() {
+            return Err(NumericError::Overflow);
+        }
+        let text = value.value.to_string();
+        Ok(Decimal::parse(text).expect("finite doubles are written in decimal notation"))
+    }
+
+}
+
+impl From<Decimal> for Double {
+
+    fn from(value: Decimal) -> Self {
+        value.to_double()
+    }
+
+}
+
+/// A decimal is serialised as a string, such as `"5.10"`, so that neither digits nor scale are
+/// lost.
+#[cfg(feature = "serde")]
+impl serde_::Serialize for Decimal {
+
+    fn serialize<S: serde_::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde_::Deserialize<'de> for Decimal {
+
+    fn deserialize<D: serde_::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = <std::string::String as serde_::Deserialize>::deserialize(deserializer)?;
+        Decimal::parse(&text).map_err(serde_::de::Error::custom)
+    }
+
+}
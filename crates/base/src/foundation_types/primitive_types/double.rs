@@ -1,7 +1,15 @@
 use std::cmp::Ordering;
 use std::ops::{Add, Div, Mul, Neg, Sub};
-use crate::foundation_types::{Any, Integer, Numeric, OrderNumeric, Ordered};
-
+use std::str::FromStr;
+use crate::foundation_types::{Any, Integer, Numeric, OrderNumeric, Ordered, Literal, ParseError};
+use super::literal;
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_::Serialize, serde_::Deserialize),
+    serde(crate = "serde_", transparent)
+)]
 pub struct Double {
     pub value: f64,
 }
@@ -26,10 +34,6 @@ impl Any for Double {
         self.value == other.value
     }
 
-    fn instance_of(&self, type_name: &str) -> bool {
-        type_name == "Double"
-    }
-
     fn type_of(&self) -> String {
         "Double".to_string()
     }
@@ -50,7 +54,7 @@ impl Ordered for Double {
 impl PartialOrd for Double {
 
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.value.total_cmp(&other.value))
+        Some(self.cmp(other))
     }
 
 }
@@ -121,3 +125,25 @@ impl Neg for Double {
 
 impl OrderNumeric for Double {
 }
+
+/// Writes the number as a real literal, which always has a decimal point, such as `5.0`.
+impl std::fmt::Display for Double {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        literal::write_real(f, self.value)
+    }
+}
+
+/// Reads a real literal, such as `2.5`, `-0.5` or `1.0e-3`. The decimal point is mandatory, so
+/// `5` is rejected with [`ParseError::MissingDecimalPoint`].
+impl FromStr for Double {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        literal::check_real(s, Literal::Double)?;
+        s.parse()
+            .ok()
+            .filter(|value: &f64| value.is_finite())
+            .map(Double::new)
+            .ok_or(ParseError::OutOfRange(Literal::Double))
+    }
+}
@@ -1,7 +1,28 @@
 use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
 use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
-use crate::foundation_types::{Any, Numeric, Ordered, OrderNumeric, Double};
-
+use std::str::FromStr;
+use crate::foundation_types::{
+    Any,
+    Double,
+    Literal,
+    Numeric,
+    NumericError,
+    Ordered,
+    OrderNumeric,
+    ParseError,
+};
+use super::literal;
+
+/// A 32-bit signed integer. The arithmetic operators panic when the result overflows, in both
+/// debug and release builds, so a calculation never silently wraps around. Use the `checked_*`,
+/// `saturating_*` or `try_*` methods to handle overflow instead.
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_::Serialize, serde_::Deserialize),
+    serde(crate = "serde_", transparent)
+)]
 pub struct Integer {
     pub value: i32,
 }
@@ -14,6 +35,140 @@ impl Integer {
         }
     }
 
+    /// Returns the sum, or `None` if it overflows.
+    pub fn checked_add(&self, other: &Integer) -> Option<Integer> {
+        self.value.checked_add(other.value).map(Integer::new)
+    }
+
+    /// Returns the difference, or `None` if it overflows.
+    pub fn checked_sub(&self, other: &Integer) -> Option<Integer> {
+        self.value.checked_sub(other.value).map(Integer::new)
+    }
+
+    /// Returns the product, or `None` if it overflows.
+    pub fn checked_mul(&self, other: &Integer) -> Option<Integer> {
+        self.value.checked_mul(other.value).map(Integer::new)
+    }
+
+    /// Returns the quotient of the integer division, rounded towards zero, or `None` if the
+    /// divisor is zero or the quotient overflows. Note that the `/` operator returns the exact
+    /// quotient as a `Double` instead.
+    pub fn checked_div(&self, other: &Integer) -> Option<Integer> {
+        self.value.checked_div(other.value).map(Integer::new)
+    }
+
+    /// Returns the remainder, or `None` if the divisor is zero or the remainder overflows.
+    pub fn checked_rem(&self, other: &Integer) -> Option<Integer> {
+        self.value.checked_rem(other.value).map(Integer::new)
+    }
+
+    /// Returns the negation, or `None` if it overflows.
+    pub fn checked_neg(&self) -> Option<Integer> {
+        self.value.checked_neg().map(Integer::new)
+    }
+
+    /// Returns the absolute value, or `None` if it overflows.
+    pub fn checked_abs(&self) -> Option<Integer> {
+        self.value.checked_abs().map(Integer::new)
+    }
+
+    /// Returns the number raised to the given power, or `None` if it overflows.
+    pub fn checked_pow(&self, exponent: u32) -> Option<Integer> {
+        self.value.checked_pow(exponent).map(Integer::new)
+    }
+
+    /// Returns the sum, limited to the range of the type.
+    pub fn saturating_add(&self, other: &Integer) -> Integer {
+        Integer::new(self.value.saturating_add(other.value))
+    }
+
+    /// Returns the difference, limited to the range of the type.
+    pub fn saturating_sub(&self, other: &Integer) -> Integer {
+        Integer::new(self.value.saturating_sub(other.value))
+    }
+
+    /// Returns the product, limited to the range of the type.
+    pub fn saturating_mul(&self, other: &Integer) -> Integer {
+        Integer::new(self.value.saturating_mul(other.value))
+    }
+
+    /// Returns the negation, limited to the range of the type.
+    pub fn saturating_neg(&self) -> Integer {
+        Integer::new(self.value.saturating_neg())
+    }
+
+    /// Returns the absolute value, limited to the range of the type.
+    pub fn saturating_abs(&self) -> Integer {
+        Integer::new(self.value.saturating_abs())
+    }
+
+    /// Returns the number raised to the given power, limited to the range of the type.
+    pub fn saturating_pow(&self, exponent: u32) -> Integer {
+        Integer::new(self.value.saturating_pow(exponent))
+    }
+
+    /// Returns the sum.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NumericError::Overflow`] if the sum overflows.
+    pub fn try_add(&self, other: &Integer) -> Result<Integer, NumericError> {
+        self.checked_add(other).ok_or(NumericError::Overflow)
+    }
+
+    /// Returns the difference.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NumericError::Overflow`] if the difference overflows.
+    pub fn try_sub(&self, other: &Integer) -> Result<Integer, NumericError> {
+        self.checked_sub(other).ok_or(NumericError::Overflow)
+    }
+
+    /// Returns the product.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NumericError::Overflow`] if the product overflows.
+    pub fn try_mul(&self, other: &Integer) -> Result<Integer, NumericError> {
+        self.checked_mul(other).ok_or(NumericError::Overflow)
+    }
+
+    /// Returns the quotient of the integer division, rounded towards zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NumericError::DivisionByZero`] if the divisor is zero and
+    /// [`NumericError::Overflow`] if the quotient overflows.
+    pub fn try_div(&self, other: &Integer) -> Result<Integer, NumericError> {
+        if other.value == 0 {
+            return Err(NumericError::DivisionByZero);
+        }
+        self.checked_div(other).ok_or(NumericError::Overflow)
+    }
+
+    /// Returns the remainder.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NumericError::DivisionByZero`] if the divisor is zero and
+    /// [`NumericError::Overflow`] if the remainder overflows.
+    pub fn try_rem(&self, other: &Integer) -> Result<Integer, NumericError> {
+        if other.value == 0 {
+            return Err(NumericError::DivisionByZero);
+        }
+        self.checked_rem(other).ok_or(NumericError::Overflow)
+    }
+
+    /// Returns the negation.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`NumericError::Overflow`] if the negation overflows.
+    pub fn try_neg(&self) -> Result<Integer, NumericError> {
+        self.checked_neg().ok_or(NumericError::Overflow)
+    }
+
 }
 
 impl Any for Integer {
@@ -22,10 +177,6 @@ impl Any for Integer {
         self.value == other.value
     }
 
-    fn instance_of(&self, type_name: &str) -> bool {
-        type_name == "Integer"
-    }
-
     fn type_of(&self) -> String {
         "Integer".to_string()
     }
@@ -46,7 +197,7 @@ impl Ordered for Integer {
 impl PartialOrd for Integer {
 
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.value.cmp(&other.value))
+        Some(self.cmp(other))
     }
 
 }
@@ -70,11 +221,19 @@ impl PartialEq for Integer {
 impl Eq for Integer {
 }
 
+impl Hash for Integer {
+
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+
+}
+
 impl Add for Integer {
     type Output = Integer;
 
     fn add(self, other: Self) -> Integer {
-        Integer::new(self.value + other.value)
+        self.checked_add(&other).expect("attempt to add with overflow")
     }
 
 }
@@ -83,7 +242,7 @@ impl Sub for Integer {
     type Output = Integer;
 
     fn sub(self, other: Self) -> Integer {
-        Integer::new(self.value - other.value)
+        self.checked_sub(&other).expect("attempt to subtract with overflow")
     }
 
 }
@@ -101,7 +260,7 @@ impl Mul for Integer {
     type Output = Integer;
 
     fn mul(self, other: Self) -> Integer {
-        Integer::new(self.value * other.value)
+        self.checked_mul(&other).expect("attempt to multiply with overflow")
     }
 
 }
@@ -110,7 +269,7 @@ impl Neg for Integer {
     type Output = Integer;
 
     fn neg(self) -> Integer {
-        Integer::new(-self.value)
+        self.checked_neg().expect("attempt to negate with overflow")
     }
 
 }
@@ -122,7 +281,41 @@ impl Rem for Integer {
     type Output = Integer;
 
     fn rem(self, other: Self) -> Self {
-        Integer::new(self.value % other.value)
+        self.try_rem(&other).expect("attempt to calculate the remainder")
+    }
+
+}
+/// Converts a `Double` to an `Integer`, dropping the fractional part.
+impl TryFrom<Double> for Integer {
+    type Error = NumericError;
+
+    fn try_from(value: Double) -> Result<Self, Self::Error> {
+        if value.value.is_nan() {
+            return Err(NumericError::NotANumber);
+        }
+        let truncated = value.value.trunc();
+        if truncated < i32::MIN as f64 || truncated >= -(i32::MIN as f64) {
+            return Err(NumericError::Overflow);
+        }
+        Ok(Integer::new(truncated as i32))
     }
 
-}
\ No newline at end of file
+}
+
+impl std::fmt::Display for Integer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+/// Reads an integer literal, such as `-42`.
+impl FromStr for Integer {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        literal::check_integer(s, Literal::Integer)?;
+        s.parse()
+            .map(Integer::new)
+            .map_err(|_| ParseError::OutOfRange(Literal::Integer))
+    }
+}
@@ -0,0 +1,98 @@
+use super::ParseError;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Literal {
+    /// This is an integer literal, such as `42` or `-7`.
+    Integer,
+
+    /// This is a 64-bit integer literal, such as `9007199254740993`.
+    Integer64,
+
+    /// This is a single precision real literal, such as `2.5` or `1.0e-3`.
+    Real,
+
+    /// This is a double precision real literal, such as `2.5` or `1.0e-3`.
+    Double,
+
+    /// This is a decimal literal, such as `5.10` or `42`.
+    Decimal,
+
+    /// This is a boolean literal, `True` or `False`.
+    Boolean,
+
+    /// This is a character literal between single quotes, such as `'a'` or `'\n'`.
+    Character,
+
+    /// This is an octet literal, such as `255`.
+    Octet,
+}
+
+impl std::fmt::Display for Literal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Literal::Integer => write!(f, "integer"),
+            Literal::Integer64 => write!(f, "64-bit integer"),
+            Literal::Real => write!(f, "real"),
+            Literal::Double => write!(f, "double"),
+            Literal::Decimal => write!(f, "decimal"),
+            Literal::Boolean => write!(f, "boolean"),
+            Literal::Character => write!(f, "character"),
+            Literal::Octet => write!(f, "octet"),
+        }
+    }
+}
+
+// ----------------------------------------------------------------------------------------------
+//                                         private functions
+// ----------------------------------------------------------------------------------------------
+/// Checks that the text is an integer literal: an optional sign followed by one or more digits.
+pub(super) fn check_integer(text: &str, literal: Literal) -> Result<(), ParseError> {
+    if text.is_empty() {
+        return Err(ParseError::Empty(literal));
+    }
+    if !is_digits(text.strip_prefix(['+', '-']).unwrap_or(text)) {
+        return Err(ParseError::InvalidFormat(literal));
+    }
+    Ok(())
+}
+
+/// Checks that the text is a real literal: an optional sign, one or more digits, a decimal point,
+/// one or more digits and an optional exponent, such as `-2.5` or `1.0e-3`.
+pub(super) fn check_real(text: &str, literal: Literal) -> Result<(), ParseError> {
+    if text.is_empty() {
+        return Err(ParseError::Empty(literal));
+    }
+    let unsigned = text.strip_prefix(['+', '-']).unwrap_or(text);
+    let (mantissa, exponent) = match unsigned.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, Some(exponent)),
+        None => (unsigned, None),
+    };
+    if let Some(exponent) = exponent {
+        if !is_digits(exponent.strip_prefix(['+', '-']).unwrap_or(exponent)) {
+            return Err(ParseError::InvalidFormat(literal));
+        }
+    }
+    match mantissa.split_once('.') {
+        Some((integral, fraction)) if is_digits(integral) && is_digits(fraction) => Ok(()),
+        None if is_digits(mantissa) => Err(ParseError::MissingDecimalPoint(literal)),
+        _ => Err(ParseError::InvalidFormat(literal)),
+    }
+}
+
+/// Writes a real number with at least one digit after the decimal point, such as `5.0` for five.
+/// Infinite values and NaN, which have no literal, are written as `inf`, `-inf` and `NaN`.
+pub(super) fn write_real<T>(f: &mut std::fmt::Formatter<'_>, value: T) -> std::fmt::Result
+where
+    T: std::fmt::Display,
+{
+    let text = value.to_string();
+    if text.bytes().all(|b| b.is_ascii_digit() || b == b'-') {
+        write!(f, "{}.0", text)
+    } else {
+        write!(f, "{}", text)
+    }
+}
+
+fn is_digits(text: &str) -> bool {
+    !text.is_empty() && text.bytes().all(|b| b.is_ascii_digit())
+}
@@ -1,4 +1,7 @@
 mod any;
+mod numeric_error;
+mod literal;
+mod parse_error;
 mod numeric;
 mod ordered;
 mod numeric_ordered;
@@ -6,13 +9,24 @@ mod integer;
 mod double;
 mod real;
 mod integer64;
+mod decimal;
+mod promotion;
+mod operators;
+mod number;
 mod boolean;
 mod character;
 mod octet;
+mod octets;
+mod octets_error;
 mod uri;
 mod string;
+mod collation;
 
 pub use any::Any;
+pub(crate) use any::type_name;
+pub use numeric_error::NumericError;
+pub use literal::Literal;
+pub use parse_error::ParseError;
 pub use numeric::Numeric;
 pub use ordered::Ordered;
 pub use numeric_ordered::OrderNumeric;
@@ -20,10 +34,24 @@ pub use integer::Integer;
 pub use double::Double;
 pub use real::Real;
 pub use integer64::Integer64;
+pub use decimal::Decimal;
+pub use number::Number;
 pub use boolean::Boolean;
 pub use character::Character;
 pub use octet::Octet;
-pub use uri::Uri;
+pub use octets::{ChecksumAlgorithm, Encoding, Octets};
+pub use octets_error::OctetsError;
+pub use uri::{
+    EhrUri,
+    EhrUriError,
+    Context as UriErrorContext,
+    Error as UriError,
+    MissingSchemeError,
+    Uri,
+    UriTemplate,
+    UriTemplateError,
+    UriTemplateValue,
+};
 pub use string::{String, StringExtension};
 
 
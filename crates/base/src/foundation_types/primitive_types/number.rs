@@ -0,0 +1,222 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Neg, Sub};
+use crate::foundation_types::{
+    Any,
+    Double,
+    Integer,
+    Integer64,
+    Numeric,
+    OrderNumeric,
+    Ordered,
+    Real,
+};
+use super::promotion::Exact;
+
+/// A value of any of the numeric types, for calculations whose operand types are only known at
+/// run time, such as a count multiplied by a measured magnitude. Operations on two numbers widen
+/// the narrower operand following the promotion order `Integer` → `Integer64` → `Real` →
+/// `Double`, so `Number::from(Integer::new(2)) * Number::from(Real::new(1.5))` is the `Real` 3.0.
+#[derive(Clone, Copy, Debug)]
+pub enum Number {
+    Integer(Integer),
+    Integer64(Integer64),
+    Real(Real),
+    Double(Double),
+}
+
+impl Number {
+
+    /// Returns the value widened to a `Double`.
+    pub fn to_double(&self) -> Double {
+        match self {
+            Number::Integer(value) => Double::from(*value),
+            Number::Integer64(value) => Double::from(*value),
+            Number::Real(value) => Double::from(*value),
+            Number::Double(value) => *value,
+        }
+    }
+
+    // ----------------------------------------------------------------------------------------------
+    //                                         private methods
+    // ----------------------------------------------------------------------------------------------
+    /// Returns the position of the type of the number in the promotion order.
+    fn rank(&self) -> u8 {
+        match self {
+            Number::Integer(_) => 0,
+            Number::Integer64(_) => 1,
+            Number::Real(_) => 2,
+            Number::Double(_) => 3,
+        }
+    }
+
+    /// Converts the number to the type at the given position in the promotion order, which must
+    /// not be lower than its own.
+    fn widen(self, rank: u8) -> Number {
+        match (self, rank) {
+            (Number::Integer(value), 1) => Number::Integer64(Integer64::from(value)),
+            (Number::Integer(value), 2) => Number::Real(Real::from(value)),
+            (Number::Integer(value), 3) => Number::Double(Double::from(value)),
+            (Number::Integer64(value), 2) => Number::Real(Real::from(value)),
+            (Number::Integer64(value), 3) => Number::Double(Double::from(value)),
+            (Number::Real(value), 3) => Number::Double(Double::from(value)),
+            (number, _) => number,
+        }
+    }
+
+    /// Widens both numbers to the wider of their two types.
+    fn promote(self, other: Number) -> (Number, Number) {
+        let rank = self.rank().max(other.rank());
+        (self.widen(rank), other.widen(rank))
+    }
+
+}
+
+impl Any for Number {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn type_of(&self) -> String {
+        match self {
+            Number::Integer(value) => value.type_of(),
+            Number::Integer64(value) => value.type_of(),
+            Number::Real(value) => value.type_of(),
+            Number::Double(value) => value.type_of(),
+        }
+    }
+
+}
+
+impl Numeric for Number {
+
+    fn pow(&self, exponent: &Double) -> Double {
+        match self {
+            Number::Integer(value) => value.pow(exponent),
+            Number::Integer64(value) => value.pow(exponent),
+            Number::Real(value) => value.pow(exponent),
+            Number::Double(value) => value.pow(exponent),
+        }
+    }
+
+}
+
+impl Ordered for Number {
+}
+
+impl OrderNumeric for Number {
+}
+
+/// Numbers are compared by their exact values rather than after promotion, as the operators
+/// between the numeric types are.
+impl PartialEq for Number {
+
+    fn eq(&self, other: &Self) -> bool {
+        Exact::from(*self).equals(Exact::from(*other))
+    }
+
+}
+
+impl PartialOrd for Number {
+
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(Exact::from(*self).compare(Exact::from(*other)))
+    }
+
+}
+
+impl From<Number> for Exact {
+
+    fn from(value: Number) -> Self {
+        match value {
+            Number::Integer(value) => value.into(),
+            Number::Integer64(value) => value.into(),
+            Number::Real(value) => value.into(),
+            Number::Double(value) => value.into(),
+        }
+    }
+
+}
+
+/// Implements an arithmetic operator by promoting both operands to the same type and applying
+/// the operator of that type.
+macro_rules! impl_arithmetic {
+    ($operator:ident, $method:ident) => {
+        impl $operator for Number {
+            type Output = Number;
+
+            fn $method(self, other: Number) -> Number {
+                match self.promote(other) {
+                    (Number::Integer(a), Number::Integer(b)) => Number::from(a.$method(b)),
+                    (Number::Integer64(a), Number::Integer64(b)) => Number::from(a.$method(b)),
+                    (Number::Real(a), Number::Real(b)) => Number::from(a.$method(b)),
+                    (Number::Double(a), Number::Double(b)) => Number::from(a.$method(b)),
+                    _ => unreachable!("promoted numbers have the same type"),
+                }
+            }
+
+        }
+    };
+}
+
+impl_arithmetic!(Add, add);
+impl_arithmetic!(Sub, sub);
+impl_arithmetic!(Mul, mul);
+impl_arithmetic!(Div, div);
+
+impl Neg for Number {
+    type Output = Number;
+
+    fn neg(self) -> Number {
+        match self {
+            Number::Integer(value) => Number::Integer(-value),
+            Number::Integer64(value) => Number::Integer64(-value),
+            Number::Real(value) => Number::Real(-value),
+            Number::Double(value) => Number::Double(-value),
+        }
+    }
+
+}
+
+impl std::fmt::Display for Number {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Number::Integer(value) => value.fmt(f),
+            Number::Integer64(value) => value.fmt(f),
+            Number::Real(value) => value.fmt(f),
+            Number::Double(value) => value.fmt(f),
+        }
+    }
+}
+
+impl From<Integer> for Number {
+
+    fn from(value: Integer) -> Self {
+        Number::Integer(value)
+    }
+
+}
+
+impl From<Integer64> for Number {
+
+    fn from(value: Integer64) -> Self {
+        Number::Integer64(value)
+    }
+
+}
+
+impl From<Real> for Number {
+
+    fn from(value: Real) -> Self {
+        Number::Real(value)
+    }
+
+}
+
+impl From<Double> for Number {
+
+    fn from(value: Double) -> Self {
+        Number::Double(value)
+    }
+
+}
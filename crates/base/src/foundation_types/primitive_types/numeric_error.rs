@@ -0,0 +1,14 @@
+#[derive(Debug, Clone, Copy, thiserror::Error, PartialEq, Eq)]
+pub enum NumericError {
+    /// The result does not fit in the type, such as `2147483647 + 1` for an `Integer`.
+    #[error("numeric overflow")]
+    Overflow,
+
+    /// The divisor of a division or remainder is zero.
+    #[error("division by zero")]
+    DivisionByZero,
+
+    /// The value is not a number, such as the `Double` result of `0.0 / 0.0`.
+    #[error("not a number")]
+    NotANumber,
+}
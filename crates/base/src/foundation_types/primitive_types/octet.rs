@@ -1,6 +1,15 @@
 use std::cmp::Ordering;
-use crate::foundation_types::Any;
-
+use std::hash::{Hash, Hasher};
+use std::str::FromStr;
+use crate::foundation_types::{Any, Literal, ParseError};
+use super::literal;
+
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_::Serialize, serde_::Deserialize),
+    serde(crate = "serde_", transparent)
+)]
 pub struct Octet {
     pub value: u8
 }
@@ -19,10 +28,6 @@ impl Any for Octet {
         self.value == other.value
     }
 
-    fn instance_of(&self, class_name: &str) -> bool {
-        class_name == "Octet"
-    }
-
     fn type_of(&self) -> String {
         "Octet".to_string()
     }
@@ -46,10 +51,18 @@ impl PartialEq<Octet> for Octet {
 
 impl Eq for Octet {}
 
+impl Hash for Octet {
+
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.value.hash(state);
+    }
+
+}
+
 impl PartialOrd for Octet {
 
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.value.partial_cmp(&other.value)
+        Some(self.cmp(other))
     }
 
 }
@@ -60,4 +73,22 @@ impl Ord for Octet {
         self.value.cmp(&other.value)
     }
 
-}
\ No newline at end of file
+}
+
+impl std::fmt::Display for Octet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+/// Reads an octet literal, such as `255`.
+impl FromStr for Octet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        literal::check_integer(s, Literal::Octet)?;
+        s.parse()
+            .map(Octet::new)
+            .map_err(|_| ParseError::OutOfRange(Literal::Octet))
+    }
+}
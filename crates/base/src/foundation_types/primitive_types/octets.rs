@@ -0,0 +1,304 @@
+#[cfg(feature = "encoding")]
+use base64::engine::general_purpose::{STANDARD, URL_SAFE_NO_PAD};
+#[cfg(feature = "encoding")]
+use base64::Engine;
+#[cfg(feature = "checksum")]
+use sha1::Sha1;
+#[cfg(feature = "checksum")]
+use sha2::{Digest, Sha256};
+use crate::foundation_types::{Any, Integer, Octet};
+use super::OctetsError;
+
+/// A sequence of octets, such as the data of a `DV_MULTIMEDIA` or `DV_ENCAPSULATED` value or
+/// the hash in its `integrity_check`. With the `encoding` feature, octets convert to and from
+/// text in base64 or hex, and are serialised as standard base64. With the `checksum` feature,
+/// their CRC32, SHA-1 or SHA-256 checksum can be calculated.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Octets {
+    value: Vec<u8>,
+}
+
+impl Octets {
+
+    /// Creates an empty sequence.
+    pub fn new() -> Self {
+        Octets {
+            value: Vec::new(),
+        }
+    }
+
+    /// Creates a sequence from the given octets, provided there are no more than `max_length`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OctetsError::TooLong`] if there are more than `max_length` octets.
+    pub fn with_max_length<T>(bytes: T, max_length: usize) -> Result<Self, OctetsError>
+    where
+        T: Into<Vec<u8>>,
+    {
+        let octets = Octets::from(bytes.into());
+        octets.check_length(max_length)?;
+        Ok(octets)
+    }
+
+    /// Decodes text in the given encoding. Whitespace is not allowed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OctetsError::InvalidEncoding`] if the text is not valid in the encoding.
+    #[cfg(feature = "encoding")]
+    pub fn decode(text: &str, encoding: Encoding) -> Result<Self, OctetsError> {
+        let decoded = match encoding {
+            Encoding::Base64 => STANDARD.decode(text).ok(),
+            Encoding::Base64Url => URL_SAFE_NO_PAD.decode(text.trim_end_matches('=')).ok(),
+            Encoding::Hex => hex::decode(text).ok(),
+        };
+        decoded
+            .map(Octets::from)
+            .ok_or(OctetsError::InvalidEncoding(encoding))
+    }
+
+    /// Decodes text in the given encoding, provided it holds no more than `max_length` octets.
+    /// The length is checked before decoding, so an oversized text is rejected without
+    /// allocating its content.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OctetsError::TooLong`] if the text holds more than `max_length` octets and
+    /// [`OctetsError::InvalidEncoding`] if it is not valid in the encoding.
+    #[cfg(feature = "encoding")]
+    pub fn decode_with_max_length(
+        text: &str,
+        encoding: Encoding,
+        max_length: usize,
+    ) -> Result<Self, OctetsError> {
+        let length = encoding.decoded_length(text);
+        if length > max_length {
+            return Err(OctetsError::TooLong { length, max_length });
+        }
+        Self::decode(text, encoding)
+    }
+
+    /// Encodes the octets as text in the given encoding. Standard base64 is padded with `=`,
+    /// URL-safe base64 is not and hex uses lower case.
+    #[cfg(feature = "encoding")]
+    pub fn encode(&self, encoding: Encoding) -> String {
+        match encoding {
+            Encoding::Base64 => STANDARD.encode(&self.value),
+            Encoding::Base64Url => URL_SAFE_NO_PAD.encode(&self.value),
+            Encoding::Hex => hex::encode(&self.value),
+        }
+    }
+
+    /// Returns the checksum of the octets calculated with the given algorithm, such as the
+    /// 32 octets of a SHA-256 hash. A CRC32 checksum is returned as four octets, most
+    /// significant first.
+    #[cfg(feature = "checksum")]
+    pub fn checksum(&self, algorithm: ChecksumAlgorithm) -> Octets {
+        match algorithm {
+            ChecksumAlgorithm::Crc32 => {
+                Octets::from(crc32fast::hash(&self.value).to_be_bytes().to_vec())
+            }
+            ChecksumAlgorithm::Sha1 => Octets::from(Sha1::digest(&self.value).to_vec()),
+            ChecksumAlgorithm::Sha256 => Octets::from(Sha256::digest(&self.value).to_vec()),
+        }
+    }
+
+    /// Returns true if the checksum of the octets calculated with the given algorithm equals
+    /// the expected checksum.
+    #[cfg(feature = "checksum")]
+    pub fn verify(&self, algorithm: ChecksumAlgorithm, expected: &Octets) -> bool {
+        self.checksum(algorithm) == *expected
+    }
+
+    /// Checks that there are no more than `max_length` octets.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OctetsError::TooLong`] if there are more than `max_length` octets.
+    pub fn check_length(&self, max_length: usize) -> Result<(), OctetsError> {
+        match self.value.len() {
+            length if length > max_length => Err(OctetsError::TooLong { length, max_length }),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns the number of octets. A sequence with more octets than an `Integer` can hold
+    /// returns the largest `Integer`.
+    pub fn count(&self) -> Integer {
+        Integer::new(i32::try_from(self.value.len()).unwrap_or(i32::MAX))
+    }
+
+    /// Returns the number of octets.
+    pub fn len(&self) -> usize {
+        self.value.len()
+    }
+
+    /// Returns true if there are no octets.
+    pub fn is_empty(&self) -> bool {
+        self.value.is_empty()
+    }
+
+    /// Returns the octet at the given position, if any.
+    pub fn get(&self, index: usize) -> Option<Octet> {
+        self.value.get(index).copied().map(Octet::new)
+    }
+
+    /// Adds an octet to the end of the sequence.
+    pub fn push(&mut self, octet: Octet) {
+        self.value.push(octet.value);
+    }
+
+    /// Returns the octets in order.
+    pub fn iter(&self) -> impl Iterator<Item = Octet> + '_ {
+        self.value.iter().copied().map(Octet::new)
+    }
+
+    /// Returns the octets as a byte slice.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.value
+    }
+
+}
+
+impl Any for Octets {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn type_of(&self) -> String {
+        "Octets".to_string()
+    }
+
+}
+
+impl From<Vec<u8>> for Octets {
+
+    fn from(value: Vec<u8>) -> Self {
+        Octets { value }
+    }
+
+}
+
+impl From<&[u8]> for Octets {
+
+    fn from(value: &[u8]) -> Self {
+        Octets::from(value.to_vec())
+    }
+
+}
+
+impl From<Octets> for Vec<u8> {
+
+    fn from(octets: Octets) -> Self {
+        octets.value
+    }
+
+}
+
+impl FromIterator<Octet> for Octets {
+
+    fn from_iter<I: IntoIterator<Item = Octet>>(octets: I) -> Self {
+        Octets::from(octets.into_iter().map(|octet| octet.value).collect::<Vec<u8>>())
+    }
+
+}
+
+/// Octets are serialised as a standard base64 string, as in the openEHR JSON and XML formats.
+#[cfg(feature = "serde")]
+impl serde_::Serialize for Octets {
+
+    fn serialize<S: serde_::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.encode(Encoding::Base64))
+    }
+
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde_::Deserialize<'de> for Octets {
+
+    fn deserialize<D: serde_::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = <String as serde_::Deserialize>::deserialize(deserializer)?;
+        Octets::decode(&text, Encoding::Base64).map_err(serde_::de::Error::custom)
+    }
+
+}
+
+/// Text encoding of octets.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// Standard base64 of RFC 4648 with padding, such as `aGk/Pw==`.
+    Base64,
+
+    /// URL and file name safe base64 of RFC 4648, such as `aGk_Pw`. Padding is accepted when
+    /// decoding but not written.
+    Base64Url,
+
+    /// Hexadecimal digits, two per octet, such as `68693f3f`. Both cases are accepted when
+    /// decoding.
+    Hex,
+}
+
+#[cfg(feature = "encoding")]
+impl Encoding {
+
+    // ----------------------------------------------------------------------------------------------
+    //                                         private methods
+    // ----------------------------------------------------------------------------------------------
+    /// Returns the number of octets the text decodes to, provided it is valid.
+    fn decoded_length(&self, text: &str) -> usize {
+        match self {
+            Encoding::Base64 | Encoding::Base64Url => {
+                let digits = text.trim_end_matches('=').len();
+                digits / 4 * 3 + digits % 4 * 3 / 4
+            }
+            Encoding::Hex => text.len() / 2,
+        }
+    }
+
+}
+
+impl std::fmt::Display for Encoding {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Encoding::Base64 => write!(f, "base64"),
+            Encoding::Base64Url => write!(f, "URL-safe base64"),
+            Encoding::Hex => write!(f, "hex"),
+        }
+    }
+}
+
+/// Algorithm to calculate the checksum of octets, such as the `integrity_check_algorithm` of a
+/// `DV_MULTIMEDIA` value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChecksumAlgorithm {
+    /// The CRC-32 checksum of ISO 3309, as used by zip and PNG.
+    Crc32,
+
+    /// The SHA-1 hash of FIPS 180-4.
+    Sha1,
+
+    /// The SHA-256 hash of FIPS 180-4.
+    Sha256,
+}
+
+impl ChecksumAlgorithm {
+
+    /// Returns the name of the algorithm, such as `SHA-256`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Crc32 => "CRC32",
+            ChecksumAlgorithm::Sha1 => "SHA-1",
+            ChecksumAlgorithm::Sha256 => "SHA-256",
+        }
+    }
+
+    /// Returns the algorithm with the given name, ignoring case, such as `SHA-256`.
+    pub fn from_name(name: &str) -> Option<ChecksumAlgorithm> {
+        [ChecksumAlgorithm::Crc32, ChecksumAlgorithm::Sha1, ChecksumAlgorithm::Sha256]
+            .into_iter()
+            .find(|algorithm| algorithm.name().eq_ignore_ascii_case(name))
+    }
+
+}
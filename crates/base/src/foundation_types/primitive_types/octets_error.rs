@@ -0,0 +1,16 @@
+use super::octets::Encoding;
+
+#[derive(Debug, Clone, Copy, thiserror::Error, PartialEq, Eq)]
+pub enum OctetsError {
+    /// The text is not valid in the encoding, such as `SGVsbG8` without its padding for
+    /// standard base64 or `0g` for hex.
+    #[error("invalid {0} text")]
+    InvalidEncoding(Encoding),
+
+    /// The octets exceed the maximum length, such as a 2 MB image where at most 1 MB is allowed.
+    #[error("{length} octets exceed the maximum of {max_length}")]
+    TooLong {
+        length: usize,
+        max_length: usize,
+    },
+}
@@ -0,0 +1,97 @@
+//! Operators on references and iterator folds for the numeric types, so that values can be
+//! combined without giving them up, as in `&a + &b`, and summed or multiplied over an iterator,
+//! as in `observations.iter().sum::<Double>()`.
+
+use std::iter::{Product, Sum};
+use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
+use super::{Double, Integer, Integer64, Number, Real};
+
+macro_rules! impl_reference_operator {
+    ($type:ident, $operator:ident, $method:ident) => {
+        impl $operator<&$type> for &$type {
+            type Output = <$type as $operator>::Output;
+
+            fn $method(self, other: &$type) -> Self::Output {
+                (*self).$method(*other)
+            }
+
+        }
+
+        impl $operator<&$type> for $type {
+            type Output = <$type as $operator>::Output;
+
+            fn $method(self, other: &$type) -> Self::Output {
+                self.$method(*other)
+            }
+
+        }
+
+        impl $operator<$type> for &$type {
+            type Output = <$type as $operator>::Output;
+
+            fn $method(self, other: $type) -> Self::Output {
+                (*self).$method(other)
+            }
+
+        }
+    };
+}
+
+macro_rules! impl_numeric_operators {
+    ($type:ident, $zero:expr, $one:expr) => {
+        impl_reference_operator!($type, Add, add);
+        impl_reference_operator!($type, Sub, sub);
+        impl_reference_operator!($type, Mul, mul);
+        impl_reference_operator!($type, Div, div);
+
+        impl Neg for &$type {
+            type Output = $type;
+
+            fn neg(self) -> $type {
+                -*self
+            }
+
+        }
+
+        impl Sum for $type {
+
+            fn sum<I: Iterator<Item = $type>>(iter: I) -> $type {
+                iter.fold($zero, |total, value| total + value)
+            }
+
+        }
+
+        impl<'a> Sum<&'a $type> for $type {
+
+            fn sum<I: Iterator<Item = &'a $type>>(iter: I) -> $type {
+                iter.fold($zero, |total, value| total + *value)
+            }
+
+        }
+
+        impl Product for $type {
+
+            fn product<I: Iterator<Item = $type>>(iter: I) -> $type {
+                iter.fold($one, |total, value| total * value)
+            }
+
+        }
+
+        impl<'a> Product<&'a $type> for $type {
+
+            fn product<I: Iterator<Item = &'a $type>>(iter: I) -> $type {
+                iter.fold($one, |total, value| total * *value)
+            }
+
+        }
+    };
+}
+
+impl_numeric_operators!(Integer, Integer::new(0), Integer::new(1));
+impl_numeric_operators!(Integer64, Integer64::new(0), Integer64::new(1));
+impl_numeric_operators!(Real, Real::new(0.0), Real::new(1.0));
+impl_numeric_operators!(Double, Double::new(0.0), Double::new(1.0));
+impl_numeric_operators!(Number, Number::Integer(Integer::new(0)), Number::Integer(Integer::new(1)));
+
+impl_reference_operator!(Integer, Rem, rem);
+impl_reference_operator!(Integer64, Rem, rem);
@@ -1,4 +1,12 @@
 use crate::foundation_types::Any;
 
-pub trait Ordered: Any + PartialOrd + Ord {
+/// This trait is the base trait for all types that have an order. It extends the `Any` trait
+/// with the comparison operators. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_ordered_class)
+///
+/// Only a partial order is required: values such as the partial dates `2024` and `2024-03`
+/// cannot be ordered with respect to each other, in which case `partial_cmp` returns `None` and
+/// all of `<`, `<=`, `>` and `>=` return false. Types with a total order additionally implement
+/// `Ord`.
+pub trait Ordered: Any + PartialOrd {
 }
@@ -0,0 +1,26 @@
+use super::Literal;
+
+#[derive(Debug, Clone, Copy, thiserror::Error, PartialEq, Eq)]
+pub enum ParseError {
+    /// The text is empty, such as `""` for an integer.
+    #[error("empty {0} literal")]
+    Empty(Literal),
+
+    /// The text does not follow the ODIN syntax of the literal, such as `12a` for an integer,
+    /// `yes` for a boolean or `.5` for a real.
+    #[error("invalid {0} literal")]
+    InvalidFormat(Literal),
+
+    /// A real literal lacks its mandatory decimal point, such as `5` instead of `5.0`.
+    #[error("{0} literal without decimal point")]
+    MissingDecimalPoint(Literal),
+
+    /// The value does not fit in the type, such as `256` for an octet or `1.0e999` for a double.
+    #[error("{0} literal out of range")]
+    OutOfRange(Literal),
+
+    /// A character literal contains an unknown or malformed escape sequence, such as `'\q'` or
+    /// `'\u12'`.
+    #[error("invalid escape sequence in {0} literal")]
+    InvalidEscape(Literal),
+}
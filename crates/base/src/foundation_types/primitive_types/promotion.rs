@@ -0,0 +1,230 @@
+//! Mixed-type arithmetic and comparison between the numeric types. Following the openEHR
+//! promotion order `Integer` → `Integer64` → `Real` → `Double`, the narrower operand is widened
+//! to the type of the wider one before the operation, so `Integer + Double` is a `Double` and
+//! `Integer64 * Real` is a `Real`. Dividing two integer types gives a `Double`, as it does for
+//! operands of the same integer type.
+//!
+//! Comparisons do not widen, as that loses precision: `Integer64(16_777_217)` would equal
+//! `Real(16_777_216.0)` while not equalling `Integer64(16_777_216)`, which does. Integers are
+//! compared with reals and doubles by their exact values instead, and reals and doubles by the
+//! same rules as among themselves: `==` for equality and the total order for ordering.
+
+use std::cmp::Ordering;
+use std::ops::{Add, Div, Mul, Sub};
+use super::{Double, Integer, Integer64, Real};
+
+impl From<Integer> for Integer64 {
+
+    fn from(value: Integer) -> Self {
+        Integer64::new(value.value as i64)
+    }
+
+}
+
+impl From<Integer> for Real {
+
+    fn from(value: Integer) -> Self {
+        Real::new(value.value as f32)
+    }
+
+}
+
+impl From<Integer> for Double {
+
+    fn from(value: Integer) -> Self {
+        Double::new(value.value as f64)
+    }
+
+}
+
+/// Values beyond ±2^24 lose precision, as `Real` has a 24-bit mantissa.
+impl From<Integer64> for Real {
+
+    fn from(value: Integer64) -> Self {
+        Real::new(value.value as f32)
+    }
+
+}
+
+/// Values beyond ±2^53 lose precision, as `Double` has a 53-bit mantissa.
+impl From<Integer64> for Double {
+
+    fn from(value: Integer64) -> Self {
+        Double::new(value.value as f64)
+    }
+
+}
+
+impl From<Real> for Double {
+
+    fn from(value: Real) -> Self {
+        Double::new(value.value as f64)
+    }
+
+}
+
+/// Implements the arithmetic operators and comparisons in both directions between a narrow type
+/// and a wider type it converts into.
+macro_rules! impl_promotion {
+    ($narrow:ident, $wide:ident) => {
+        impl_promotion!(@operator $narrow, $wide, Add, add);
+        impl_promotion!(@operator $narrow, $wide, Sub, sub);
+        impl_promotion!(@operator $narrow, $wide, Mul, mul);
+        impl_promotion!(@operator $narrow, $wide, Div, div);
+
+        impl PartialEq<$wide> for $narrow {
+
+            fn eq(&self, other: &$wide) -> bool {
+                Exact::from(*self).equals(Exact::from(*other))
+            }
+
+        }
+
+        impl PartialEq<$narrow> for $wide {
+
+            fn eq(&self, other: &$narrow) -> bool {
+                Exact::from(*self).equals(Exact::from(*other))
+            }
+
+        }
+
+        impl PartialOrd<$wide> for $narrow {
+
+            fn partial_cmp(&self, other: &$wide) -> Option<Ordering> {
+                Some(Exact::from(*self).compare(Exact::from(*other)))
+            }
+
+        }
+
+        impl PartialOrd<$narrow> for $wide {
+
+            fn partial_cmp(&self, other: &$narrow) -> Option<Ordering> {
+                Some(Exact::from(*self).compare(Exact::from(*other)))
+            }
+
+        }
+    };
+    (@operator $narrow:ident, $wide:ident, $operator:ident, $method:ident) => {
+        impl $operator<$wide> for $narrow {
+            type Output = <$wide as $operator>::Output;
+
+            fn $method(self, other: $wide) -> Self::Output {
+                $wide::from(self).$method(other)
+            }
+
+        }
+
+        impl $operator<$narrow> for $wide {
+            type Output = <$wide as $operator>::Output;
+
+            fn $method(self, other: $narrow) -> Self::Output {
+                self.$method($wide::from(other))
+            }
+
+        }
+    };
+}
+
+impl_promotion!(Integer, Integer64);
+impl_promotion!(Integer, Real);
+impl_promotion!(Integer, Double);
+impl_promotion!(Integer64, Real);
+impl_promotion!(Integer64, Double);
+impl_promotion!(Real, Double);
+
+// ----------------------------------------------------------------------------------------------
+//                                         private types
+// ----------------------------------------------------------------------------------------------
+/// A numeric value converted without loss, so that values of different types can be compared.
+#[derive(Clone, Copy)]
+pub(super) enum Exact {
+    Integer(i64),
+    Float(f64),
+}
+
+impl Exact {
+
+    /// Returns true if the values are equal. Floats are compared with `==`, so `NaN` equals
+    /// nothing and `-0.0` equals `0`.
+    pub(super) fn equals(self, other: Exact) -> bool {
+        match (self, other) {
+            (Exact::Integer(integer), Exact::Integer(other)) => integer == other,
+            (Exact::Float(float), Exact::Float(other)) => float == other,
+            (Exact::Integer(integer), Exact::Float(float))
+            | (Exact::Float(float), Exact::Integer(integer)) => {
+                float.fract() == 0.0 && in_integer_range(float) && float as i64 == integer
+            }
+        }
+    }
+
+    /// Compares the values. Floats are compared by their total order, as if integers were
+    /// converted to floats without loss, so `-0.0` is less than `0` and `NaN` is greater than all
+    /// integers, or less if it is negative.
+    pub(super) fn compare(self, other: Exact) -> Ordering {
+        match (self, other) {
+            (Exact::Integer(integer), Exact::Integer(other)) => integer.cmp(&other),
+            (Exact::Float(float), Exact::Float(other)) => float.total_cmp(&other),
+            (Exact::Integer(integer), Exact::Float(float)) => compare_integer(integer, float),
+            (Exact::Float(float), Exact::Integer(integer)) => {
+                compare_integer(integer, float).reverse()
+            }
+        }
+    }
+
+}
+
+impl From<Integer> for Exact {
+
+    fn from(value: Integer) -> Self {
+        Exact::Integer(value.value as i64)
+    }
+
+}
+
+impl From<Integer64> for Exact {
+
+    fn from(value: Integer64) -> Self {
+        Exact::Integer(value.value)
+    }
+
+}
+
+impl From<Real> for Exact {
+
+    fn from(value: Real) -> Self {
+        Exact::Float(value.value as f64)
+    }
+
+}
+
+impl From<Double> for Exact {
+
+    fn from(value: Double) -> Self {
+        Exact::Float(value.value)
+    }
+
+}
+
+// ----------------------------------------------------------------------------------------------
+//                                         private functions
+// ----------------------------------------------------------------------------------------------
+/// Returns true if the float lies within the range of `i64`, from -2^63 up to but excluding 2^63.
+fn in_integer_range(float: f64) -> bool {
+    (-9_223_372_036_854_775_808.0..9_223_372_036_854_775_808.0).contains(&float)
+}
+
+/// Compares an integer with a float by the total order of floats, without converting the
+/// integer to a float.
+fn compare_integer(integer: i64, float: f64) -> Ordering {
+    if float.is_nan() || float == 0.0 {
+        // Only the sign matters here, and converting keeps the sign of the integer.
+        return (integer as f64).total_cmp(&float);
+    }
+    if !in_integer_range(float) {
+        return if float > 0.0 { Ordering::Less } else { Ordering::Greater };
+    }
+    let whole = float.trunc();
+    integer
+        .cmp(&(whole as i64))
+        .then_with(|| 0.0_f64.total_cmp(&(float - whole)))
+}
@@ -1,20 +1,170 @@
-use crate::foundation_types::{Any, Ordered};
+use std::cmp::Ordering;
+use unicode_normalization::UnicodeNormalization;
+use crate::foundation_types::{Any, Boolean, Double, Integer64, Number, Ordered, Real};
+use super::collation;
 
 pub type String = std::string::String;
 
+/// The functions of the openEHR String type that Rust strings lack. Conversions follow the ODIN
+/// literal syntax rather than Rust's `parse`: an integer is an optional sign and digits, a real
+/// requires a decimal point, as in `5.0`, and a boolean is `True` or `False` in any case. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_string_class)
+///
+/// Positions count characters, not bytes, starting at zero. The names of `is_empty_str`,
+/// `starts_with_str` and `contains_str` end in `_str` so that they do not shadow the methods of
+/// `str`, which also take characters and closures as patterns.
 pub trait StringExtension {
+
+    /// Returns true if the string has no characters.
+    fn is_empty_str(&self) -> bool;
+
+    /// Returns true if the string is an integer literal, such as `-42`, that fits in an
+    /// `Integer64`.
     fn is_integer(&self) -> bool;
-    fn as_integer(&self) -> Option<i64>;
+
+    /// Returns the value of an integer literal, or `None` if the string is not one.
+    fn as_integer(&self) -> Option<Integer64>;
+
+    /// Returns true if the string is a real literal, such as `2.5` or `1.0e-3`.
+    fn is_real(&self) -> bool;
+
+    /// Returns the value of a real literal, or `None` if the string is not one.
+    fn as_real(&self) -> Option<Real>;
+
+    /// Returns true if the string is a real literal whose value fits in a `Double`.
+    fn is_double(&self) -> bool;
+
+    /// Returns the value of a real literal in double precision, or `None` if the string is not
+    /// one.
+    fn as_double(&self) -> Option<Double>;
+
+    /// Returns true if the string is an integer or a real literal.
+    fn is_numeric(&self) -> bool;
+
+    /// Returns the value of an integer literal as an `Integer64` or of a real literal as a
+    /// `Double`, or `None` if the string is neither.
+    fn as_number(&self) -> Option<Number>;
+
+    /// Returns true if the string is a boolean literal, `True` or `False` in any case.
+    fn is_boolean(&self) -> bool;
+
+    /// Returns the value of a boolean literal, or `None` if the string is not one.
+    fn as_boolean(&self) -> Option<Boolean>;
+
+    /// Returns the characters from position `start` up to but not including position `end`, or
+    /// `None` if the range is reversed or extends beyond the end of the string.
+    fn substring(&self, start: usize, end: usize) -> Option<String>;
+
+    /// Returns true if the string starts with the given string.
+    fn starts_with_str(&self, prefix: &str) -> bool;
+
+    /// Returns true if the given string occurs in the string.
+    fn contains_str(&self, other: &str) -> bool;
+
+    /// Returns the string in Unicode normalization form C, in which characters are composed
+    /// where possible, so `e` followed by a combining acute accent becomes `é`.
+    fn to_nfc(&self) -> String;
+
+    /// Returns the string in Unicode normalization form D, in which characters are decomposed,
+    /// so `é` becomes `e` followed by a combining acute accent.
+    fn to_nfd(&self) -> String;
+
+    /// Returns true if both strings are canonically equivalent, that is equal after
+    /// normalization, such as the composed and decomposed forms of `Müller`.
+    fn canonical_eq(&self, other: &str) -> bool;
+
+    /// Compares the strings for sorting by people rather than by code point: letters are
+    /// compared first, ignoring accents and case, then accents and then case, with lower case
+    /// first. So `émile` sorts between `Emil` and `Emma`, and `Zoë` after `zoe`. Letters of
+    /// different scripts, and letters without a decomposition such as `ø` or `æ`, are ordered
+    /// by code point, and no locale specific rules are applied.
+    fn collate(&self, other: &str) -> Ordering;
+
 }
 
 impl StringExtension for String {
 
+    fn is_empty_str(&self) -> bool {
+        self.as_str().is_empty()
+    }
+
     fn is_integer(&self) -> bool {
-        self.parse::<i64>().is_ok()
+        self.as_integer().is_some()
+    }
+
+    fn as_integer(&self) -> Option<Integer64> {
+        self.parse().ok()
+    }
+
+    fn is_real(&self) -> bool {
+        self.as_real().is_some()
+    }
+
+    fn as_real(&self) -> Option<Real> {
+        self.parse().ok()
     }
 
-    fn as_integer(&self) -> Option<i64> {
-        self.parse::<i64>().ok()
+    fn is_double(&self) -> bool {
+        self.as_double().is_some()
+    }
+
+    fn as_double(&self) -> Option<Double> {
+        self.parse().ok()
+    }
+
+    fn is_numeric(&self) -> bool {
+        self.as_number().is_some()
+    }
+
+    fn as_number(&self) -> Option<Number> {
+        self.as_integer()
+            .map(Number::from)
+            .or_else(|| self.as_double().map(Number::from))
+    }
+
+    fn is_boolean(&self) -> bool {
+        self.as_boolean().is_some()
+    }
+
+    fn as_boolean(&self) -> Option<Boolean> {
+        self.parse().ok()
+    }
+
+    fn substring(&self, start: usize, end: usize) -> Option<String> {
+        if start > end {
+            return None;
+        }
+        let mut positions = self
+            .char_indices()
+            .map(|(position, _)| position)
+            .chain(std::iter::once(self.len()));
+        let from = positions.nth(start)?;
+        let to = if end == start { from } else { positions.nth(end - start - 1)? };
+        Some(self[from..to].to_string())
+    }
+
+    fn starts_with_str(&self, prefix: &str) -> bool {
+        self.as_str().starts_with(prefix)
+    }
+
+    fn contains_str(&self, other: &str) -> bool {
+        self.as_str().contains(other)
+    }
+
+    fn to_nfc(&self) -> String {
+        self.nfc().collect()
+    }
+
+    fn to_nfd(&self) -> String {
+        self.nfd().collect()
+    }
+
+    fn canonical_eq(&self, other: &str) -> bool {
+        self.nfd().eq(other.nfd())
+    }
+
+    fn collate(&self, other: &str) -> Ordering {
+        collation::collate(self, other)
     }
 
 }
@@ -24,14 +174,10 @@ impl Any for String {
         self == other
     }
 
-    fn instance_of(&self, type_name: &str) -> bool {
-        "String" == type_name
-    }
-
     fn type_of(&self) -> std::string::String {
         "String".to_string()
     }
 
 }
 
-impl Ordered for String {}
\ No newline at end of file
+impl Ordered for String {}
@@ -8,26 +8,49 @@ mod port;
 mod ipv6_address;
 mod ipv4_address;
 mod absolute_uri;
+mod path;
+mod query;
+mod fragment;
+mod ehr_uri;
+mod ehr_uri_error;
+mod template;
+mod template_error;
+#[cfg(feature = "iri")]
+mod iri;
 
 use authority::Authority;
-use characters::{ALPHA, PCHAR_NOT_PCT_ENCODED, QUERY_NOT_PCT_ENCODED_WITHOUT_PLUS,
-                  QUERY_OR_FRAGMENT_NOT_PCT_ENCODED, SCHEME_NOT_FIRST};
-use codec::{decode_element, encode_element};
-use context::Context;
-use error::Error;
+use characters::{ALPHA, SCHEME_NOT_FIRST};
+pub use context::Context;
+pub use error::{Error, MissingSchemeError};
+pub use ehr_uri::EhrUri;
+pub use ehr_uri_error::EhrUriError;
+pub use template::{UriTemplate, UriTemplateValue};
+pub use template_error::UriTemplateError;
 use absolute_uri::AbsoluteUri;
+use path::Path;
+use query::Query;
+use fragment::Fragment;
 use std::{collections::HashSet, convert::TryFrom, ops::Deref};
 use std::{fmt::Write, string::FromUtf8Error};
 use std::cmp::Ordering;
 use crate::foundation_types::{Any, Ordered};
 
+/// The schemes with the ports they use by default, which are removed when a URI is normalized.
+const DEFAULT_PORTS: &[(&str, u16)] = &[
+    ("ftp", 21),
+    ("http", 80),
+    ("https", 443),
+    ("ws", 80),
+    ("wss", 443),
+];
+
 #[derive(Clone, Default, Hash, PartialEq, Eq)]
 pub struct Uri {
     scheme: Option<String>,
     authority: Option<Authority>,
-    path: Vec<Vec<u8>>,
-    query: Option<Vec<u8>>,
-    fragment: Option<Vec<u8>>,
+    path: Path,
+    query: Option<Query>,
+    fragment: Option<Fragment>,
     raw: String,
 }
 
@@ -42,13 +65,13 @@ impl Uri {
     /// path.
     #[must_use]
     pub fn contains_relative_path(&self) -> bool {
-        !Self::is_path_absolute(&self.path)
+        !Self::is_path_absolute(self.path.decoded())
     }
 
     /// Borrow the fragment (if any) of the URI.
     #[must_use]
     pub fn fragment(&self) -> Option<&[u8]> {
-        self.fragment.as_deref()
+        self.fragment.as_ref().map(Fragment::decoded)
     }
 
     /// Convert the fragment (if any) into a string.
@@ -61,7 +84,7 @@ impl Uri {
     /// [CannotExpressAsUtf8]: enum.Error.html#variant.CannotExpressAsUtf8
     pub fn fragment_to_string(&self) -> Result<Option<String>, FromUtf8Error> {
         self.fragment()
-            .map(|fragment| String::from_utf8(fragment.to_vec()).map_err(Into::into))
+            .map(|fragment| String::from_utf8(fragment.to_vec()))
             .transpose()
     }
 
@@ -85,6 +108,20 @@ impl Uri {
             .transpose()
     }
 
+    /// Convert the host portion of the Authority (if any) into a string in its Unicode form,
+    /// decoding internationalized domain names with IDNA, so `xn--bcher-kva.example` becomes
+    /// `bücher.example`. Use [`host_to_string`](Self::host_to_string) for the form in which the
+    /// host appears in the URI.
+    ///
+    /// # Errors
+    ///
+    /// Since host names may contain non-UTF8 byte sequences, this function may
+    /// return [`FromUtf8Error`].
+    #[cfg(feature = "iri")]
+    pub fn host_to_unicode_string(&self) -> Result<Option<String>, FromUtf8Error> {
+        Ok(self.host_to_string()?.map(|host| iri::host_to_unicode(&host)))
+    }
+
     /// Determines if the URI is a `relative-ref` (relative reference), as
     /// defined in [RFC 3986 section
     /// 4.2](https://tools.ietf.org/html/rfc3986#section-4.2).  A relative
@@ -94,8 +131,45 @@ impl Uri {
         self.scheme.is_none()
     }
 
+    /// Determines if the URI is equivalent to the other URI after both are normalized, as
+    /// described in [RFC 3986 section
+    /// 6.2.2](https://tools.ietf.org/html/rfc3986#section-6.2.2) and
+    /// [6.2.3](https://tools.ietf.org/html/rfc3986#section-6.2.3). For example,
+    /// `HTTP://Example.com:80/a/./b` is equivalent to `http://example.com/a/b`, while the two are
+    /// not equal.
+    #[must_use]
+    pub fn equivalent(&self, other: &Self) -> bool {
+        let mut this = self.clone();
+        let mut other = other.clone();
+        this.normalize();
+        other.normalize();
+        this == other
+    }
+
+    /// Applies the syntax-based and scheme-based normalization of [RFC 3986 section
+    /// 6](https://tools.ietf.org/html/rfc3986#section-6) to the URI:
+    /// * the scheme and the host are converted to lowercase;
+    /// * the dot-segments (`.` and `..`) are removed from the path;
+    /// * the port is removed if it is the default port of the scheme, such as 80 for `http` and
+    ///   443 for `https`.
+    ///
+    /// Percent-encodings are normalized as described in [RFC 3986 section
+    /// 6.2.2.2](https://tools.ietf.org/html/rfc3986#section-6.2.2.2): hexadecimal digits are
+    /// uppercase and percent-encoded unreserved characters such as `%7E` are decoded, while
+    /// percent-encoded reserved characters such as the `%3A` in `/a%3Ab` or `?x=%3A` are kept
+    /// in the path, query and fragment, since decoding them could change the meaning of the URI.
     pub fn normalize(&mut self) {
-        self.path = Self::normalize_path(&self.path);
+        if let Some(scheme) = &mut self.scheme {
+            scheme.make_ascii_lowercase();
+        }
+        let default_port = self.scheme.as_deref().and_then(Self::default_port);
+        if let Some(authority) = &mut self.authority {
+            authority.set_host(authority.host().to_ascii_lowercase());
+            if authority.port().is_some() && authority.port() == default_port {
+                authority.set_port(None);
+            }
+        }
+        self.path = Self::normalize_path(self.path.encoded());
         self.update_raw();
     }
 
@@ -132,6 +206,25 @@ impl Uri {
         Ok(this)
     }
 
+    /// Interpret the given string as an IRI (internationalized resource identifier), as defined
+    /// in [RFC 3987](https://tools.ietf.org/html/rfc3987), and convert it into a URI. Non-ASCII
+    /// characters in a host name are converted with IDNA and all other non-ASCII characters are
+    /// percent-encoded as UTF-8, so `http://bücher.example/straße` becomes
+    /// `http://xn--bcher-kva.example/stra%C3%9Fe`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::IllegalCharacter`] if the IRI contains a character that is not allowed in
+    /// an IRI, [`Error::InvalidDomainName`] if its host name cannot be converted with IDNA, and
+    /// any error of [`Uri::parse`] for the URI it converts into.
+    #[cfg(feature = "iri")]
+    pub fn parse_iri<T>(iri_string: T) -> Result<Self, Error>
+    where
+        T: AsRef<str>,
+    {
+        Self::parse(iri::iri_to_uri(iri_string.as_ref())?)
+    }
+
     /// Borrow the path component of the URI.
     ///
     /// The path is represented as a two-dimensional vector:
@@ -159,7 +252,7 @@ impl Uri {
     /// ```
     #[must_use]
     pub fn path(&self) -> &Vec<Vec<u8>> {
-        &self.path
+        self.path.decoded()
     }
 
     /// Convert the path portion of the URI into a string.
@@ -172,7 +265,7 @@ impl Uri {
     ///
     /// [CannotExpressAsUtf8]: enum.Error.html#variant.CannotExpressAsUtf8
     pub fn path_to_string(&self) -> Result<String, FromUtf8Error> {
-        match &*self.path {
+        match &**self.path.decoded() {
             [segment] if segment.is_empty() => Ok("/".to_string()),
             path => Ok(String::from_utf8(path.join(&b"/"[..]))?),
         }
@@ -186,7 +279,30 @@ impl Uri {
     /// Borrow the query (if any) of the URI.
     #[must_use]
     pub fn query(&self) -> Option<&[u8]> {
-        self.query.as_deref()
+        self.query.as_ref().map(Query::decoded)
+    }
+
+    /// Return the value of the first parameter with the given name (if any) in the query, read
+    /// as `application/x-www-form-urlencoded`, such as `2` for `version` in
+    /// `?version=2&filter=a%26b`.
+    ///
+    /// Byte sequences in the value that are not valid UTF-8 are replaced by the replacement
+    /// character.
+    #[must_use]
+    pub fn query_parameter(&self, name: &str) -> Option<String> {
+        self.query_parameters()
+            .into_iter()
+            .find_map(|(parameter_name, value)| (parameter_name == name).then_some(value))
+    }
+
+    /// Return the names and values of the parameters in the query, in order, read as
+    /// `application/x-www-form-urlencoded`. A `+` is read as a space, and a parameter without
+    /// a `=`, such as `verbose` in `?verbose&page=2`, has an empty value.
+    ///
+    /// Byte sequences that are not valid UTF-8 are replaced by the replacement character.
+    #[must_use]
+    pub fn query_parameters(&self) -> Vec<(String, String)> {
+        self.query.as_ref().map(Query::parameters).unwrap_or_default()
     }
 
     /// Convert the query (if any) into a string.
@@ -204,13 +320,29 @@ impl Uri {
     }
 
 
+    /// Remove all parameters with the given name from the query. The query is removed once it has
+    /// no parameters left. Returns true if any parameter was removed.
+    pub fn remove_query_parameter(&mut self, name: &str) -> bool {
+        let Some(query) = &mut self.query else {
+            return false;
+        };
+        if !query.remove_parameter(name) {
+            return false;
+        }
+        if query.encoded().is_empty() {
+            self.query = None;
+        }
+        self.update_raw();
+        true
+    }
+
     #[must_use]
     pub fn resolve(&self, relative_reference: &Self) -> Self {
         let (scheme, authority, path, query) = if relative_reference.scheme.is_some() {
             (
                 relative_reference.scheme.clone(),
                 relative_reference.authority.clone(),
-                Self::normalize_path(&relative_reference.path),
+                Self::normalize_path(relative_reference.path.encoded()),
                 relative_reference.query.clone(),
             )
         } else {
@@ -218,7 +350,7 @@ impl Uri {
                 || {
                     let scheme = self.scheme.clone();
                     let authority = self.authority.clone();
-                    if relative_reference.path.is_empty() {
+                    if relative_reference.path.decoded().is_empty() {
                         let path = self.path.clone();
                         let query = if relative_reference.query.is_none() {
                             self.query.clone()
@@ -231,16 +363,16 @@ impl Uri {
 
                         // RFC describes this as:
                         // "if (R.path starts-with "/") then"
-                        if Self::is_path_absolute(&relative_reference.path) {
+                        if Self::is_path_absolute(relative_reference.path.decoded()) {
                             (scheme, authority, relative_reference.path.clone(), query)
                         } else {
                             // RFC describes this as:
                             // "T.path = merge(Base.path, R.path);"
-                            let mut path = self.path.clone();
+                            let mut path = self.path.encoded().to_vec();
                             if self.authority.is_none() || path.len() > 1 {
                                 path.pop();
                             }
-                            path.extend(relative_reference.path.iter().cloned());
+                            path.extend(relative_reference.path.encoded().iter().cloned());
                             (scheme, authority, Self::normalize_path(&path), query)
                         }
                     }
@@ -249,7 +381,7 @@ impl Uri {
                     (
                         self.scheme.clone(),
                         Some(authority.clone()),
-                        Self::normalize_path(&relative_reference.path),
+                        Self::normalize_path(relative_reference.path.encoded()),
                         relative_reference.query.clone(),
                     )
                 },
@@ -280,6 +412,15 @@ impl Uri {
         self.scheme.as_deref()
     }
 
+    /// Append a parameter to the query, adding a query if there is none. The name and value are
+    /// percent-encoded as needed, including any `&`, `=` and `+` in them.
+    pub fn append_query_parameter(&mut self, name: &str, value: &str) {
+        self.query
+            .get_or_insert_with(Query::default)
+            .append_parameter(name, value);
+        self.update_raw();
+    }
+
     /// Change the authority of the URI.
     pub fn set_authority<T>(&mut self, authority: T)
     where
@@ -294,7 +435,7 @@ impl Uri {
     where
         T: Into<Option<Vec<u8>>>,
     {
-        self.fragment = fragment.into();
+        self.fragment = fragment.into().map(Fragment::from_decoded);
         self.update_raw();
     }
 
@@ -306,7 +447,7 @@ impl Uri {
     where
         T: Into<Vec<Vec<u8>>>,
     {
-        self.path = path.into();
+        self.path = Path::from_decoded(path.into());
         self.update_raw();
     }
 
@@ -335,7 +476,16 @@ impl Uri {
     where
         T: Into<Option<Vec<u8>>>,
     {
-        self.query = query.into();
+        self.query = query.into().map(Query::from_decoded);
+        self.update_raw();
+    }
+
+    /// Set the parameter with the given name in the query to the value, replacing all parameters
+    /// with that name, or append it if there are none.
+    pub fn set_query_parameter(&mut self, name: &str, value: &str) {
+        self.query
+            .get_or_insert_with(Query::default)
+            .set_parameter(name, value);
         self.update_raw();
     }
 
@@ -427,15 +577,12 @@ impl Uri {
         matches!(path.as_ref(), [segment, ..] if segment.is_empty())
     }
 
-    fn decode_query_or_fragment<T>(query_or_fragment: T, context: Context) -> Result<Vec<u8>, Error>
-    where
-        T: AsRef<str>,
-    {
-        decode_element(
-            query_or_fragment,
-            &QUERY_OR_FRAGMENT_NOT_PCT_ENCODED,
-            context,
-        )
+    /// Returns the default port of the scheme, such as 80 for `http`.
+    fn default_port(scheme: &str) -> Option<u16> {
+        DEFAULT_PORTS
+            .iter()
+            .find(|(name, _)| *name == scheme)
+            .map(|(_, port)| *port)
     }
 
     fn update_raw(&mut self) {
@@ -448,46 +595,24 @@ impl Uri {
             write!(&mut raw, "//{}", authority).unwrap();
         }
         // Special case: absolute but otherwise empty path.
-        if Self::is_path_absolute(&self.path) && self.path.len() == 1 {
+        let path = self.path.encoded();
+        if Self::is_path_absolute(self.path.decoded()) && path.len() == 1 {
             write!(&mut raw, "/").unwrap();
         }
-        for (i, segment) in self.path.iter().enumerate() {
-            write!(
-                &mut raw,
-                "{}",
-                encode_element(segment, &PCHAR_NOT_PCT_ENCODED)
-            )
-                .unwrap();
-            if i + 1 < self.path.len() {
-                write!(&mut raw, "/").unwrap();
-            }
-        }
+        write!(&mut raw, "{}", path.join("/")).unwrap();
         if let Some(query) = &self.query {
-            write!(
-                &mut raw,
-                "?{}",
-                encode_element(query, &QUERY_NOT_PCT_ENCODED_WITHOUT_PLUS)
-            )
-                .unwrap();
+            write!(&mut raw, "?{}", query.encoded()).unwrap();
         }
         if let Some(fragment) = &self.fragment {
-            write!(
-                raw,
-                "#{}",
-                encode_element(fragment, &QUERY_OR_FRAGMENT_NOT_PCT_ENCODED)
-            )
-                .unwrap();
+            write!(&mut raw, "#{}", fragment.encoded()).unwrap();
         }
 
         self.raw = raw;
     }
 
-    fn parse_fragment(query_and_or_fragment: &str) -> Result<(Option<Vec<u8>>, &str), Error> {
+    fn parse_fragment(query_and_or_fragment: &str) -> Result<(Option<Fragment>, &str), Error> {
         if let Some(fragment_delimiter) = query_and_or_fragment.find('#') {
-            let fragment = Self::decode_query_or_fragment(
-                &query_and_or_fragment[fragment_delimiter + 1..],
-                Context::Fragment,
-            )?;
+            let fragment = Fragment::parse(&query_and_or_fragment[fragment_delimiter + 1..])?;
             Ok((
                 Some(fragment),
                 &query_and_or_fragment[0..fragment_delimiter],
@@ -497,32 +622,7 @@ impl Uri {
         }
     }
 
-    fn parse_path<T>(path_string: T) -> Result<Vec<Vec<u8>>, Error>
-    where
-        T: AsRef<str>,
-    {
-        match path_string.as_ref() {
-            "/" => {
-                // Special case of an empty absolute path, which we want to
-                // represent as single empty-string element to indicate that it
-                // is absolute.
-                Ok(vec![vec![]])
-            }
-
-            "" => {
-                // Special case of an empty relative path, which we want to
-                // represent as an empty vector.
-                Ok(vec![])
-            }
-
-            path_string => path_string
-                .split('/')
-                .map(|segment| decode_element(&segment, &PCHAR_NOT_PCT_ENCODED, Context::Path))
-                .collect(),
-        }
-    }
-
-    fn parse_query<T>(query_and_or_fragment: T) -> Result<Option<Vec<u8>>, Error>
+    fn parse_query<T>(query_and_or_fragment: T) -> Result<Option<Query>, Error>
     where
         T: AsRef<str>,
     {
@@ -530,8 +630,7 @@ impl Uri {
         if query_and_or_fragment.is_empty() {
             Ok(None)
         } else {
-            let query =
-                Self::decode_query_or_fragment(&query_and_or_fragment[1..], Context::Query)?;
+            let query = Query::parse(&query_and_or_fragment[1..])?;
             Ok(Some(query))
         }
     }
@@ -550,10 +649,7 @@ impl Uri {
         }
     }
 
-    fn normalize_path<T>(original_path: T) -> Vec<Vec<u8>>
-    where
-        T: AsRef<[Vec<u8>]>,
-    {
+    fn normalize_path(original_path: &[String]) -> Path {
         // Rebuild the path one segment
         // at a time, removing and applying special
         // navigation segments ("." and "..") as we go.
@@ -562,10 +658,10 @@ impl Uri {
         // the `normalized_path` refers to a directory.
         let mut at_directory_level = false;
         let mut normalized_path = Vec::new();
-        for segment in original_path.as_ref() {
-            if segment == b"." {
+        for segment in original_path {
+            if segment == "." {
                 at_directory_level = true;
-            } else if segment == b".." {
+            } else if segment == ".." {
                 // Remove last path element
                 // if we can navigate up a level.
                 if !normalized_path.is_empty()
@@ -594,16 +690,16 @@ impl Uri {
         // add an empty segment to mark the fact.
         match (at_directory_level, normalized_path.last()) {
             (true, Some(segment)) if !segment.is_empty() => {
-                normalized_path.push(vec![]);
+                normalized_path.push(String::new());
             }
             _ => (),
         }
-        normalized_path
+        Path::from_encoded(normalized_path)
     }
 
     fn split_authority_from_path_and_parse_them<T>(
         authority_and_path_string: T,
-    ) -> Result<(Option<Authority>, Vec<Vec<u8>>), Error>
+    ) -> Result<(Option<Authority>, Path), Error>
     where
         T: AsRef<str>,
     {
@@ -620,22 +716,18 @@ impl Uri {
             // Parse the elements inside the authority string.
             let authority = Authority::parse(authority_string)?;
             let path = if path_string.is_empty() {
-                vec![vec![]]
+                Path::from_encoded(vec![String::new()])
             } else {
-                Self::parse_path(path_string)?
+                Path::parse(path_string)?
             };
             Ok((Some(authority), path))
         } else {
-            let path = Self::parse_path(authority_and_path_string)?;
+            let path = Path::parse(authority_and_path_string)?;
             Ok((None, path))
         }
     }
 
-    fn can_navigate_path_up_one_level<T>(path: T) -> bool
-    where
-        T: AsRef<[Vec<u8>]>,
-    {
-        let path = path.as_ref();
+    fn can_navigate_path_up_one_level(path: &[String]) -> bool {
         match path.first() {
             // First segment empty means path has leading slash,
             // so we can only navigate up if there are two or more segments.
@@ -721,10 +813,6 @@ impl Any for Uri {
         self.raw == other.raw
     }
 
-    fn instance_of(&self, type_name: &str) -> bool {
-        "Uri" == type_name
-    }
-
     fn type_of(&self) -> String {
         "Uri".to_string()
     }
@@ -732,7 +820,7 @@ impl Any for Uri {
 
 impl PartialOrd for Uri {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.raw.partial_cmp(&other.raw)
+        Some(self.cmp(other))
     }
 }
 
@@ -757,7 +845,7 @@ impl TryFrom<Uri> for url_::Url {
     type Error = url_::ParseError;
 
     fn try_from(value: Uri) -> Result<Self, Self::Error> {
-        value.try_into()
+        url_::Url::parse(&value.raw)
     }
 }
 
@@ -65,6 +65,26 @@ impl AbsoluteUri {
         self.uri.host_to_string()
     }
 
+    /// Convert the host portion of the Authority (if any) into a string in its Unicode form. See
+    /// [`Uri::host_to_unicode_string`].
+    ///
+    /// # Errors
+    ///
+    /// Since host names may contain non-UTF8 byte sequences, this function may
+    /// return [`FromUtf8Error`].
+    #[cfg(feature = "iri")]
+    pub fn host_to_unicode_string(&self) -> Result<Option<String>, FromUtf8Error> {
+        self.uri.host_to_unicode_string()
+    }
+
+    /// Determines if the URI is equivalent to the other URI after both are normalized. See
+    /// [`Uri::equivalent`].
+    #[must_use]
+    pub fn equivalent(&self, other: &Self) -> bool {
+        self.uri.equivalent(&other.uri)
+    }
+
+    /// Normalizes the URI. See [`Uri::normalize`].
     pub fn normalize(&mut self) {
         self.uri.normalize()
     }
@@ -84,6 +104,21 @@ impl AbsoluteUri {
         Uri::parse(uri_string.as_ref())?.try_into()
     }
 
+    /// Interpret the given string as an IRI and convert it into an absolute URI. See
+    /// [`Uri::parse_iri`].
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`Uri::parse_iri`], and [`Error::MissingScheme`] if the IRI has no
+    /// scheme.
+    #[cfg(feature = "iri")]
+    pub fn parse_iri<T>(iri_string: T) -> Result<Self, Error>
+    where
+        T: AsRef<str>,
+    {
+        Uri::parse_iri(iri_string)?.try_into()
+    }
+
     /// Borrow the path component of the URI.
     ///
     /// The path is represented as a two-dimensional vector:
@@ -139,6 +174,20 @@ impl AbsoluteUri {
         self.uri.query()
     }
 
+    /// Return the value of the first parameter with the given name (if any) in the query. See
+    /// [`Uri::query_parameter`].
+    #[must_use]
+    pub fn query_parameter(&self, name: &str) -> Option<String> {
+        self.uri.query_parameter(name)
+    }
+
+    /// Return the names and values of the parameters in the query. See
+    /// [`Uri::query_parameters`].
+    #[must_use]
+    pub fn query_parameters(&self) -> Vec<(String, String)> {
+        self.uri.query_parameters()
+    }
+
     /// Convert the query (if any) into a string.
     ///
     /// # Errors
@@ -151,6 +200,12 @@ impl AbsoluteUri {
         self.uri.query_to_string()
     }
 
+    /// Remove all parameters with the given name from the query. See
+    /// [`Uri::remove_query_parameter`].
+    pub fn remove_query_parameter(&mut self, name: &str) -> bool {
+        self.uri.remove_query_parameter(name)
+    }
+
     #[must_use]
     pub fn resolve(&self, relative_reference: impl Borrow<Uri>) -> Self {
         // the unwrap bel
@@ -173,6 +228,11 @@ impl AbsoluteUri {
         self.uri.scheme().expect("Absolute URI did not contain a scheme. This is a bug. Please report it to https://github.com/chanced/uniresid/issues")
     }
 
+    /// Append a parameter to the query. See [`Uri::append_query_parameter`].
+    pub fn append_query_parameter(&mut self, name: &str, value: &str) {
+        self.uri.append_query_parameter(name, value);
+    }
+
     /// Change the authority of the URI.
     pub fn set_authority<T>(&mut self, authority: T)
     where
@@ -220,6 +280,12 @@ impl AbsoluteUri {
         self.uri.set_query(query);
     }
 
+    /// Set the parameter with the given name in the query to the value. See
+    /// [`Uri::set_query_parameter`].
+    pub fn set_query_parameter(&mut self, name: &str, value: &str) {
+        self.uri.set_query_parameter(name, value);
+    }
+
     /// Change the scheme of the URI.
     ///
     /// # Errors
@@ -227,6 +293,7 @@ impl AbsoluteUri {
     /// The set of characters allowed in the scheme of a URI is limited.
     /// [`Error::IllegalCharacter`](enum.Error.html#variant.IllegalCharacter)
     /// is returned if you try to use a character that isn't allowed.
+    #[allow(clippy::extra_unused_type_parameters)]
     pub fn set_scheme<T>(&mut self, scheme: &str) -> Result<(), Error> {
         if scheme.is_empty() {
             Err(Error::EmptyScheme)
@@ -36,6 +36,26 @@ pub static SUB_DELIMS: Lazy<HashSet<char>> = Lazy::new(|| {
         .collect()
 });
 
+// This is the character set corresponds to the "gen-delims" syntax specified in RFC 3986.
+pub static GEN_DELIMS: Lazy<HashSet<char>> = Lazy::new(|| {
+    [':', '/', '?', '#', '[', ']', '@']
+        .iter()
+        .copied()
+        .collect()
+});
+
+// This is the character set corresponds to the "reserved" and the "unreserved" syntax specified
+// in RFC 3986 together, which the "+" and "#" operators of a URI template (RFC 6570) leave
+// unencoded.
+pub static RESERVED_OR_UNRESERVED: Lazy<HashSet<char>> = Lazy::new(|| {
+    UNRESERVED
+        .iter()
+        .chain(GEN_DELIMS.iter())
+        .chain(SUB_DELIMS.iter())
+        .copied()
+        .collect()
+});
+
 // This is the character set corresponds to the second part of the "scheme" syntax specified in
 // RFC 3986.
 pub static SCHEME_NOT_FIRST: Lazy<HashSet<char>> = Lazy::new(|| {
@@ -84,6 +104,16 @@ pub static QUERY_NOT_PCT_ENCODED_WITHOUT_PLUS: Lazy<HashSet<char>> = Lazy::new(|
         .collect()
 });
 
+// This is the character set of QUERY_NOT_PCT_ENCODED_WITHOUT_PLUS, leaving out '&' and '=',
+// which delimit the names and values of an "application/x-www-form-urlencoded" query.
+pub static QUERY_PARAMETER_NOT_PCT_ENCODED: Lazy<HashSet<char>> = Lazy::new(|| {
+    QUERY_NOT_PCT_ENCODED_WITHOUT_PLUS
+        .iter()
+        .filter(|c| !['&', '='].contains(c))
+        .copied()
+        .collect()
+});
+
 // This is the character set corresponds to the "user_info" syntax specified in RFC 3986,
 // leaving out "pct-encoded".
 pub static USER_INFO_NOT_PCT_ENCODED: Lazy<HashSet<char>> = Lazy::new(|| {
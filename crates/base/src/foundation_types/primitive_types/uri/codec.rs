@@ -1,10 +1,10 @@
 use std::{
     collections::HashSet,
-    convert::TryFrom,
     fmt::Write,
 };
 
 use super::{
+    characters::UNRESERVED,
     context::Context,
     error::Error,
     decoder::PercentEncodedCharacterDecoder,
@@ -25,9 +25,8 @@ where
         .chars()
         .filter_map(|c| {
             if decoding_pec {
-                pec_decoder.next(c).map_err(Into::into).transpose().map(|c| {
+                pec_decoder.next(c).transpose().inspect(|_| {
                     decoding_pec = false;
-                    c
                 })
             } else if c == '%' {
                 decoding_pec = true;
@@ -47,10 +46,51 @@ pub fn encode_element(
 ) -> String {
     let mut encoding = String::with_capacity(element.len());
     for ci in element {
-        match char::try_from(*ci) {
-            Ok(c) if allowed_characters.contains(&c) => encoding.push(c),
-            _ => write!(encoding, "%{:02X}", ci).unwrap(),
+        let c = char::from(*ci);
+        if allowed_characters.contains(&c) {
+            encoding.push(c);
+        } else {
+            write!(encoding, "%{:02X}", ci).unwrap();
         }
     }
     encoding
-}
\ No newline at end of file
+}
+
+/// Normalizes the percent-encoding of an element without decoding it, as described in
+/// [RFC 3986 section 6.2.2](https://tools.ietf.org/html/rfc3986#section-6.2.2): the hexadecimal
+/// digits are converted to uppercase and percent-encoded unreserved characters are decoded, so
+/// `%7e%3a` becomes `~%3A`. Other percent-encoded characters, such as the `%26` of a `&` in a
+/// query, are kept encoded.
+pub fn normalize_element<T>(
+    element: T,
+    allowed_characters: &'static HashSet<char>,
+    context: Context,
+) -> Result<String, Error>
+where
+    T: AsRef<str>,
+{
+    let element = element.as_ref();
+    let mut normalized = String::with_capacity(element.len());
+    let mut chars = element.chars();
+    while let Some(c) = chars.next() {
+        if c == '%' {
+            let mut pec_decoder = PercentEncodedCharacterDecoder::new();
+            let mut decoded = None;
+            while decoded.is_none() {
+                let digit = chars.next().ok_or(Error::IllegalPercentEncoding)?;
+                decoded = pec_decoder.next(digit)?;
+            }
+            let ci = decoded.unwrap();
+            if UNRESERVED.contains(&char::from(ci)) {
+                normalized.push(char::from(ci));
+            } else {
+                write!(normalized, "%{:02X}", ci).unwrap();
+            }
+        } else if allowed_characters.contains(&c) {
+            normalized.push(c);
+        } else {
+            return Err(Error::IllegalCharacter(context));
+        }
+    }
+    Ok(normalized)
+}
@@ -0,0 +1,362 @@
+use std::borrow::Cow;
+
+use super::{
+    absolute_uri::AbsoluteUri,
+    characters::QUERY_OR_FRAGMENT_NOT_PCT_ENCODED,
+    codec::encode_element,
+    ehr_uri_error::EhrUriError,
+    Uri,
+};
+
+/// An openEHR `ehr:` URI, as used by `DV_EHR_URI` and `LINK`, which refers to an EHR, to a
+/// versioned object in an EHR or to an item inside such an object:
+///
+/// ```text
+/// ehr://system_id/ehr_id/versioned_object#archetype_path
+/// ```
+///
+/// The versioned object is either the UID of the versioned object or a version id of the form
+/// `object_id::creating_system_id::version_tree_id`, such as
+/// `ehr://rmh.nhs.net/347a5490-55ee-4da9-b91a-9bba710f730e/87284370-2d4b-4e3d-a3f3-f303d2f4f34b::rmh.nhs.net::2#/content[openEHR-EHR-SECTION.vital_signs.v1]`.
+/// See the [openEHR architecture overview](https://specifications.openehr.org/releases/BASE/Release-1.2.0/architecture_overview.html).
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct EhrUri {
+    uri: AbsoluteUri,
+    system_id: String,
+    ehr_id: String,
+    versioned_object: Option<String>,
+    archetype_path: Option<String>,
+}
+
+impl EhrUri {
+    /// Interpret the given string as an `ehr:` URI. The archetype path may be written as is, as
+    /// it usually is in openEHR, such as `/items[at0001 and name/value='BP']`; characters that
+    /// are not allowed in a fragment are percent-encoded, while percent-encoded triplets such as
+    /// `%5B` are kept.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EhrUriError::InvalidUri`] if the string is not a URI, and one of the other
+    /// variants of [`EhrUriError`] if it is not a well-formed `ehr:` URI.
+    pub fn parse<T>(uri_string: T) -> Result<Self, EhrUriError>
+    where
+        T: AsRef<str>,
+    {
+        let uri_string = uri_string.as_ref();
+        let uri_string = match uri_string.split_once('#') {
+            Some((uri, archetype_path)) => {
+                Cow::Owned(format!("{}#{}", uri, encode_archetype_path(archetype_path)))
+            }
+            None => Cow::Borrowed(uri_string),
+        };
+        Self::try_from(Uri::parse(uri_string)?)
+    }
+
+    /// Borrow the archetype path (if any) of the URI, such as
+    /// `/content[openEHR-EHR-SECTION.vital_signs.v1]`.
+    #[must_use]
+    pub fn archetype_path(&self) -> Option<&str> {
+        self.archetype_path.as_deref()
+    }
+
+    /// Borrow the id of the system that created the version (if any) the URI refers to, such
+    /// as `rmh.nhs.net` in `87284370-2d4b-4e3d-a3f3-f303d2f4f34b::rmh.nhs.net::2`.
+    #[must_use]
+    pub fn creating_system_id(&self) -> Option<&str> {
+        self.versioned_object
+            .as_deref()
+            .and_then(|versioned_object| versioned_object.split("::").nth(1))
+    }
+
+    /// Borrow the id of the EHR the URI refers to.
+    #[must_use]
+    pub fn ehr_id(&self) -> &str {
+        &self.ehr_id
+    }
+
+    /// Borrow the id of the system that holds the EHR, such as `rmh.nhs.net`.
+    #[must_use]
+    pub fn system_id(&self) -> &str {
+        &self.system_id
+    }
+
+    /// Borrow the `Uri` representation of the URI.
+    #[must_use]
+    pub fn uri(&self) -> &Uri {
+        &self.uri
+    }
+
+    /// Borrow the version tree id of the version (if any) the URI refers to, such as `2` in
+    /// `87284370-2d4b-4e3d-a3f3-f303d2f4f34b::rmh.nhs.net::2`.
+    #[must_use]
+    pub fn version(&self) -> Option<&str> {
+        self.versioned_object
+            .as_deref()
+            .and_then(|versioned_object| versioned_object.split("::").nth(2))
+    }
+
+    /// Borrow the UID of the versioned object (if any) the URI refers to, such as
+    /// `87284370-2d4b-4e3d-a3f3-f303d2f4f34b` in
+    /// `87284370-2d4b-4e3d-a3f3-f303d2f4f34b::rmh.nhs.net::2`.
+    #[must_use]
+    pub fn versioned_object_uid(&self) -> Option<&str> {
+        self.versioned_object
+            .as_deref()
+            .and_then(|versioned_object| versioned_object.split("::").next())
+    }
+
+    // ----------------------------------------------------------------------------------------------
+    //                                         private methods
+    // ----------------------------------------------------------------------------------------------
+    fn parse_system_id(uri: &AbsoluteUri) -> Result<String, EhrUriError> {
+        match uri.authority() {
+            Some(authority) if authority.user_info().is_none() && authority.port().is_none() => {
+                let system_id = String::from_utf8_lossy(authority.host()).into_owned();
+                if is_uid(&system_id) {
+                    Ok(system_id)
+                } else {
+                    Err(EhrUriError::InvalidSystemId)
+                }
+            }
+            _ => Err(EhrUriError::InvalidSystemId),
+        }
+    }
+
+    fn parse_archetype_path(uri: &AbsoluteUri) -> Result<Option<String>, EhrUriError> {
+        let Some(fragment) = uri.fragment() else {
+            return Ok(None);
+        };
+        let archetype_path = String::from_utf8_lossy(fragment).into_owned();
+        if is_archetype_path(&archetype_path) {
+            Ok(Some(archetype_path))
+        } else {
+            Err(EhrUriError::InvalidArchetypePath(archetype_path))
+        }
+    }
+}
+
+impl TryFrom<Uri> for EhrUri {
+    type Error = EhrUriError;
+
+    fn try_from(uri: Uri) -> Result<Self, Self::Error> {
+        let uri = AbsoluteUri::try_from(uri)?;
+        if uri.scheme() != "ehr" {
+            return Err(EhrUriError::WrongScheme(uri.scheme().to_string()));
+        }
+        let system_id = Self::parse_system_id(&uri)?;
+
+        // The path starts with an empty segment because it is absolute, and may end with one
+        // for a trailing slash.
+        let mut segments = uri.path().get(1..).unwrap_or_default();
+        if let [rest @ .., last] = segments {
+            if last.is_empty() {
+                segments = rest;
+            }
+        }
+        let mut segments = segments
+            .iter()
+            .map(|segment| String::from_utf8_lossy(segment).into_owned());
+        let ehr_id = segments
+            .next()
+            .filter(|ehr_id| is_hier_object_id(ehr_id))
+            .ok_or(EhrUriError::InvalidEhrId)?;
+        let versioned_object = segments.next();
+        if let Some(versioned_object) = &versioned_object {
+            if !is_uid(versioned_object) && !is_object_version_id(versioned_object) {
+                return Err(EhrUriError::InvalidVersionId(versioned_object.clone()));
+            }
+        }
+        if let Some(segment) = segments.next() {
+            return Err(EhrUriError::UnexpectedPathSegment(segment));
+        }
+        let archetype_path = Self::parse_archetype_path(&uri)?;
+        Ok(Self {
+            uri,
+            system_id,
+            ehr_id,
+            versioned_object,
+            archetype_path,
+        })
+    }
+}
+
+impl TryFrom<&'_ str> for EhrUri {
+    type Error = EhrUriError;
+
+    fn try_from(uri_string: &'_ str) -> Result<Self, Self::Error> {
+        EhrUri::parse(uri_string)
+    }
+}
+
+impl From<EhrUri> for Uri {
+    fn from(ehr_uri: EhrUri) -> Self {
+        ehr_uri.uri.uri
+    }
+}
+
+impl std::fmt::Debug for EhrUri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("EhrUri")
+            .field(&self.to_string())
+            .finish()
+    }
+}
+
+impl std::fmt::Display for EhrUri {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.uri.uri)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde_::Serialize for EhrUri {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde_::Serializer,
+    {
+        serializer.serialize_str(&self.uri)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde_::Deserialize<'de> for EhrUri {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde_::Deserializer<'de>,
+    {
+        let s = String::deserialize(deserializer)?;
+        Self::parse(s).map_err(serde_::de::Error::custom)
+    }
+}
+
+// ----------------------------------------------------------------------------------------------
+//                                         private functions
+// ----------------------------------------------------------------------------------------------
+/// Percent-encodes the characters of an archetype path that are not allowed in a fragment,
+/// leaving percent-encoded triplets such as `%5B` as they are.
+fn encode_archetype_path(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(c) = rest.chars().next() {
+        let bytes = rest.as_bytes();
+        if c == '%' && bytes.len() >= 3 && bytes[1..3].iter().all(u8::is_ascii_hexdigit) {
+            encoded.push_str(&rest[..3]);
+            rest = &rest[3..];
+        } else {
+            let mut buffer = [0; 4];
+            encoded.push_str(&encode_element(
+                c.encode_utf8(&mut buffer).as_bytes(),
+                &QUERY_OR_FRAGMENT_NOT_PCT_ENCODED,
+            ));
+            rest = &rest[c.len_utf8()..];
+        }
+    }
+    encoded
+}
+
+/// Returns true if the text is an openEHR `UID`: a UUID, an ISO OID or an internet id.
+fn is_uid(text: &str) -> bool {
+    is_uuid(text) || is_iso_oid(text) || is_internet_id(text)
+}
+
+/// Returns true if the text is a UUID, such as `347a5490-55ee-4da9-b91a-9bba710f730e`.
+fn is_uuid(text: &str) -> bool {
+    text.len() == 36
+        && text.char_indices().all(|(index, c)| match index {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+/// Returns true if the text is an ISO OID, such as `1.2.840.113554.1.2.2`.
+fn is_iso_oid(text: &str) -> bool {
+    text.split('.')
+        .all(|arc| !arc.is_empty() && arc.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Returns true if the text is an internet id, such as `rmh.nhs.net`.
+fn is_internet_id(text: &str) -> bool {
+    text.split('.').all(|label| {
+        label.starts_with(|c: char| c.is_ascii_alphabetic())
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    })
+}
+
+/// Returns true if the text is an openEHR `HIER_OBJECT_ID`: a UID with an optional extension,
+/// such as `1.2.840.113554::ehr`.
+fn is_hier_object_id(text: &str) -> bool {
+    match text.split_once("::") {
+        Some((root, extension)) => is_uid(root) && !extension.is_empty(),
+        None => is_uid(text),
+    }
+}
+
+/// Returns true if the text is an openEHR `OBJECT_VERSION_ID`, such as
+/// `87284370-2d4b-4e3d-a3f3-f303d2f4f34b::rmh.nhs.net::1.2.1`.
+fn is_object_version_id(text: &str) -> bool {
+    match text.split("::").collect::<Vec<_>>().as_slice() {
+        [object_id, creating_system_id, version_tree_id] => {
+            is_uid(object_id) && is_uid(creating_system_id) && is_version_tree_id(version_tree_id)
+        }
+        _ => false,
+    }
+}
+
+/// Returns true if the text is an openEHR version tree id: a trunk version, optionally followed
+/// by a branch number and a branch version, such as `2` or `1.2.1`.
+fn is_version_tree_id(text: &str) -> bool {
+    let parts: Vec<&str> = text.split('.').collect();
+    matches!(parts.len(), 1 | 3)
+        && parts
+            .iter()
+            .all(|part| !part.is_empty() && part.chars().all(|c| c.is_ascii_digit()))
+}
+
+/// Returns true if the text is an absolute archetype path, such as
+/// `/content[openEHR-EHR-SECTION.vital_signs.v1]/items[at0001]`. Slashes inside the predicates
+/// between square brackets do not separate segments.
+fn is_archetype_path(text: &str) -> bool {
+    let Some(path) = text.strip_prefix('/') else {
+        return false;
+    };
+    if path.is_empty() {
+        return true;
+    }
+    let mut segments = Vec::new();
+    let mut depth = 0;
+    let mut start = 0;
+    for (index, c) in path.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' if depth == 0 => return false,
+            ']' => depth -= 1,
+            '/' if depth == 0 => {
+                segments.push(&path[start..index]);
+                start = index + 1;
+            }
+            _ => (),
+        }
+    }
+    segments.push(&path[start..]);
+    depth == 0 && segments.into_iter().all(is_archetype_path_segment)
+}
+
+/// Returns true if the text is an attribute name with an optional predicate, such as `items` or
+/// `items[at0001]`.
+fn is_archetype_path_segment(text: &str) -> bool {
+    let name = match text.split_once('[') {
+        Some((name, predicate)) => {
+            if predicate.strip_suffix(']').is_none_or(str::is_empty) {
+                return false;
+            }
+            name
+        }
+        None => text,
+    };
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
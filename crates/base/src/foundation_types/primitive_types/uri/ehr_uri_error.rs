@@ -0,0 +1,40 @@
+use super::error::Error;
+
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum EhrUriError {
+    /// The text is not a valid URI, such as `ehr://rmh.nhs.net/%GG`.
+    #[error("invalid URI")]
+    InvalidUri(
+        #[source]
+        #[from]
+        Error,
+    ),
+
+    /// The URI has another scheme than `ehr`, such as `http://rmh.nhs.net/`.
+    #[error("expected the ehr scheme but found {0}")]
+    WrongScheme(String),
+
+    /// The URI has no system id, or one that is not a valid identifier, such as
+    /// `ehr:///347a5490-55ee-4da9-b91a-9bba710f730e` or `ehr://user@rmh.nhs.net/...`.
+    #[error("invalid system id")]
+    InvalidSystemId,
+
+    /// The URI has no EHR id, or one that is not a valid identifier, such as
+    /// `ehr://rmh.nhs.net/` or `ehr://rmh.nhs.net/not-an-id!`.
+    #[error("invalid EHR id")]
+    InvalidEhrId,
+
+    /// The versioned object is not a valid object or version id, such as `1234::rmh.nhs.net`
+    /// or `87284370-2d4b-4e3d-a3f3-f303d2f4f34b::rmh.nhs.net::two`.
+    #[error("invalid version id {0}")]
+    InvalidVersionId(String),
+
+    /// The path has more segments than the EHR id and the versioned object, such as
+    /// `ehr://rmh.nhs.net/347a5490-55ee-4da9-b91a-9bba710f730e/composition/extra`.
+    #[error("unexpected path segment {0}")]
+    UnexpectedPathSegment(String),
+
+    /// The fragment is not a valid archetype path, such as `#content[` or `#/items]`.
+    #[error("invalid archetype path {0}")]
+    InvalidArchetypePath(String),
+}
@@ -52,6 +52,12 @@ pub enum Error {
     #[error("truncated host")]
     TruncatedHost,
 
+    /// IRI contains a host name that cannot be converted to ASCII with IDNA,
+    /// such as a label that begins with a combining mark; only returned when
+    /// the `iri` feature is enabled
+    #[error("invalid internationalized domain name")]
+    InvalidDomainName,
+
     /// An AbsoluteUri was parsed without a scheme.
     #[error("missing scheme")]
     MissingScheme(
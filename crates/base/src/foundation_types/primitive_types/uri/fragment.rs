@@ -0,0 +1,50 @@
+use super::{
+    characters::QUERY_OR_FRAGMENT_NOT_PCT_ENCODED,
+    codec::{decode_element, encode_element, normalize_element},
+    context::Context,
+    error::Error,
+};
+
+/// The fragment of a URI. It is kept percent-encoded as well as decoded, so that a reserved
+/// character that was percent-encoded, such as the `%2C` in `x%2Cy`, keeps its encoding.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub(super) struct Fragment {
+    encoded: String,
+    decoded: Vec<u8>,
+}
+
+impl Fragment {
+    /// Interprets the given string as the fragment of a URI, without its leading `#`. The
+    /// percent-encoding is normalized.
+    pub(super) fn parse<T>(fragment_string: T) -> Result<Self, Error>
+    where
+        T: AsRef<str>,
+    {
+        let encoded = normalize_element(
+            fragment_string,
+            &QUERY_OR_FRAGMENT_NOT_PCT_ENCODED,
+            Context::Fragment,
+        )?;
+        let decoded =
+            decode_element(&encoded, &QUERY_OR_FRAGMENT_NOT_PCT_ENCODED, Context::Fragment)?;
+        Ok(Self { encoded, decoded })
+    }
+
+    /// Creates the fragment from its decoded bytes.
+    pub(super) fn from_decoded(decoded: Vec<u8>) -> Self {
+        Self {
+            encoded: encode_element(&decoded, &QUERY_OR_FRAGMENT_NOT_PCT_ENCODED),
+            decoded,
+        }
+    }
+
+    /// Borrow the decoded bytes of the fragment.
+    pub(super) fn decoded(&self) -> &[u8] {
+        &self.decoded
+    }
+
+    /// Borrow the percent-encoded fragment.
+    pub(super) fn encoded(&self) -> &str {
+        &self.encoded
+    }
+}
@@ -87,7 +87,7 @@ impl<'a> State<'a> {
         }
     }
 
-    fn next_no_groups_yet(state: Shared<'a>, i: usize, c: char) -> Result<Self, MachineExitStatus> {
+    fn next_no_groups_yet(state: Shared<'a>, i: usize, c: char) -> Result<Self, MachineExitStatus<'a>> {
         let mut state = state;
         if c == ':' {
             Ok(Self::ColonButNoGroupsYet(state))
@@ -103,7 +103,7 @@ impl<'a> State<'a> {
         }
     }
 
-    fn next_colon_but_no_groups_yet(state: Shared<'a>, c: char) -> Result<Self, MachineExitStatus> {
+    fn next_colon_but_no_groups_yet(state: Shared<'a>, c: char) -> Result<Self, MachineExitStatus<'a>> {
         let mut state = state;
         if c == ':' {
             state.double_colon_encountered = true;
@@ -117,7 +117,7 @@ impl<'a> State<'a> {
         state: Shared<'a>,
         i: usize,
         c: char,
-    ) -> Result<Self, MachineExitStatus> {
+    ) -> Result<Self, MachineExitStatus<'a>> {
         let mut state = state;
         state.num_digits += 1;
         if state.num_digits > 4 {
@@ -132,7 +132,7 @@ impl<'a> State<'a> {
         }
     }
 
-    fn next_in_group_not_ipv4(state: Shared<'a>, c: char) -> Result<Self, MachineExitStatus> {
+    fn next_in_group_not_ipv4(state: Shared<'a>, c: char) -> Result<Self, MachineExitStatus<'a>> {
         let mut state = state;
         if c == ':' {
             state.num_digits = 0;
@@ -150,7 +150,7 @@ impl<'a> State<'a> {
         }
     }
 
-    fn next_in_group_could_be_ipv4(state: Shared<'a>, c: char) -> Result<Self, MachineExitStatus> {
+    fn next_in_group_could_be_ipv4(state: Shared<'a>, c: char) -> Result<Self, MachineExitStatus<'a>> {
         let mut state = state;
         if c == ':' {
             state.num_digits = 0;
@@ -176,7 +176,7 @@ impl<'a> State<'a> {
         state: Shared<'a>,
         i: usize,
         c: char,
-    ) -> Result<Self, MachineExitStatus> {
+    ) -> Result<Self, MachineExitStatus<'a>> {
         let mut state = state;
         if c == ':' {
             if state.double_colon_encountered {
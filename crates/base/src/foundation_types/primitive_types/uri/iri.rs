@@ -0,0 +1,128 @@
+use super::{
+    characters::UNRESERVED,
+    codec::encode_element,
+    context::Context,
+    error::Error,
+};
+
+/// Converts an IRI into a URI string, as described in [RFC 3987 section
+/// 3.1](https://tools.ietf.org/html/rfc3987#section-3.1): a host name with non-ASCII characters
+/// is converted to its ASCII form with IDNA, such as `bücher.example` to
+/// `xn--bcher-kva.example`, and the other non-ASCII characters are percent-encoded as UTF-8.
+/// Characters from the private use areas are only allowed in the query.
+pub fn iri_to_uri(iri: &str) -> Result<String, Error> {
+    let (before_fragment, fragment) = split_off(iri, '#');
+    let (before_query, query) = split_off(before_fragment, '?');
+    let scheme_end = before_query
+        .find('/')
+        .map_or(before_query, |path_start| &before_query[..path_start])
+        .find(':')
+        .map_or(0, |colon| colon + 1);
+    let (scheme, hier_part) = before_query.split_at(scheme_end);
+    let mut uri = encode_non_ascii(scheme, Context::Scheme, false)?;
+    match hier_part.strip_prefix("//") {
+        Some(authority_and_path) => {
+            let path_start = authority_and_path
+                .find('/')
+                .unwrap_or(authority_and_path.len());
+            let (authority, path) = authority_and_path.split_at(path_start);
+            uri.push_str("//");
+            uri.push_str(&authority_to_ascii(authority)?);
+            uri.push_str(&encode_non_ascii(path, Context::Path, false)?);
+        }
+        None => uri.push_str(&encode_non_ascii(hier_part, Context::Path, false)?),
+    }
+    if let Some(query) = query {
+        uri.push('?');
+        uri.push_str(&encode_non_ascii(query, Context::Query, true)?);
+    }
+    if let Some(fragment) = fragment {
+        uri.push('#');
+        uri.push_str(&encode_non_ascii(fragment, Context::Fragment, false)?);
+    }
+    Ok(uri)
+}
+
+/// Converts a host name into its Unicode form with IDNA, such as `xn--bcher-kva.example` to
+/// `bücher.example`. IP addresses, and host names that are not valid IDNA, are returned as is.
+pub fn host_to_unicode(host: &str) -> String {
+    if host.contains(':') {
+        return host.to_string();
+    }
+    match idna::domain_to_unicode(host) {
+        (unicode, Ok(())) => unicode,
+        (_, Err(_)) => host.to_string(),
+    }
+}
+
+// ----------------------------------------------------------------------------------------------
+//                                         private functions
+// ----------------------------------------------------------------------------------------------
+/// Splits the text at the first occurrence of the delimiter, dropping the delimiter.
+fn split_off(text: &str, delimiter: char) -> (&str, Option<&str>) {
+    match text.split_once(delimiter) {
+        Some((before, after)) => (before, Some(after)),
+        None => (text, None),
+    }
+}
+
+/// Converts the authority of an IRI into the authority of a URI. The user info is
+/// percent-encoded and the host name is converted with IDNA; IP literals must be ASCII already.
+fn authority_to_ascii(authority: &str) -> Result<String, Error> {
+    let (user_info, host_and_port) = match authority.split_once('@') {
+        Some((user_info, host_and_port)) => (Some(user_info), host_and_port),
+        None => (None, authority),
+    };
+    let mut converted = String::with_capacity(authority.len());
+    if let Some(user_info) = user_info {
+        converted.push_str(&encode_non_ascii(user_info, Context::UserInfo, false)?);
+        converted.push('@');
+    }
+    if host_and_port.starts_with('[') {
+        if !host_and_port.is_ascii() {
+            return Err(Error::IllegalCharacter(Context::Host));
+        }
+        converted.push_str(host_and_port);
+        return Ok(converted);
+    }
+    let port_start = host_and_port.rfind(':').unwrap_or(host_and_port.len());
+    let (host, port) = host_and_port.split_at(port_start);
+    if host.is_ascii() {
+        converted.push_str(host);
+    } else {
+        let host = idna::domain_to_ascii(host).map_err(|_| Error::InvalidDomainName)?;
+        converted.push_str(&host);
+    }
+    converted.push_str(port);
+    Ok(converted)
+}
+
+/// Percent-encodes the non-ASCII characters of an IRI component as UTF-8, leaving the ASCII
+/// characters for [`Uri::parse`](super::Uri::parse) to check.
+fn encode_non_ascii(text: &str, context: Context, allow_private: bool) -> Result<String, Error> {
+    let mut encoded = String::with_capacity(text.len());
+    for c in text.chars() {
+        if c.is_ascii() {
+            encoded.push(c);
+        } else if is_ucschar(c) || (allow_private && is_iprivate(c)) {
+            let mut buffer = [0; 4];
+            encoded.push_str(&encode_element(c.encode_utf8(&mut buffer).as_bytes(), &UNRESERVED));
+        } else {
+            return Err(Error::IllegalCharacter(context));
+        }
+    }
+    Ok(encoded)
+}
+
+/// Returns true if the character is a `ucschar` of RFC 3987: a non-ASCII character that is not
+/// a control character, a surrogate, a private use character or a noncharacter.
+fn is_ucschar(c: char) -> bool {
+    let c = u32::from(c);
+    matches!(c, 0xA0..=0xD7FF | 0xF900..=0xFDCF | 0xFDF0..=0xFFEF | 0xE_1000..=0xE_FFFD)
+        || ((0x1_0000..=0xD_FFFF).contains(&c) && c & 0xFFFF <= 0xFFFD)
+}
+
+/// Returns true if the character is an `iprivate` of RFC 3987: a private use character.
+fn is_iprivate(c: char) -> bool {
+    matches!(u32::from(c), 0xE000..=0xF8FF | 0xF_0000..=0xF_FFFD | 0x10_0000..=0x10_FFFD)
+}
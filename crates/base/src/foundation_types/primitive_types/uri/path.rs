@@ -0,0 +1,73 @@
+use super::{
+    characters::PCHAR_NOT_PCT_ENCODED,
+    codec::{decode_element, encode_element, normalize_element},
+    context::Context,
+    error::Error,
+};
+
+/// The path of a URI, split into its segments. Each segment is kept percent-encoded as well as
+/// decoded, so that a reserved character that was percent-encoded, such as the `%3A` in `a%3Ab`,
+/// keeps its encoding and a `%2F` is not mistaken for the `/` between two segments.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub(super) struct Path {
+    encoded: Vec<String>,
+    decoded: Vec<Vec<u8>>,
+}
+
+impl Path {
+    /// Interprets the given string as the path of a URI. The percent-encoding of each segment is
+    /// normalized.
+    pub(super) fn parse<T>(path_string: T) -> Result<Self, Error>
+    where
+        T: AsRef<str>,
+    {
+        match path_string.as_ref() {
+            // Special case of an empty absolute path, which we want to represent as single
+            // empty-string element to indicate that it is absolute.
+            "/" => Ok(Self::from_encoded(vec![String::new()])),
+
+            // Special case of an empty relative path, which we want to represent as an empty
+            // vector.
+            "" => Ok(Self::default()),
+
+            path_string => path_string
+                .split('/')
+                .map(|segment| normalize_element(segment, &PCHAR_NOT_PCT_ENCODED, Context::Path))
+                .collect::<Result<_, _>>()
+                .map(Self::from_encoded),
+        }
+    }
+
+    /// Creates the path from the decoded bytes of its segments.
+    pub(super) fn from_decoded(decoded: Vec<Vec<u8>>) -> Self {
+        Self {
+            encoded: decoded
+                .iter()
+                .map(|segment| encode_element(segment, &PCHAR_NOT_PCT_ENCODED))
+                .collect(),
+            decoded,
+        }
+    }
+
+    /// Creates the path from its percent-encoded segments, which must be valid.
+    pub(super) fn from_encoded(encoded: Vec<String>) -> Self {
+        let decoded = encoded
+            .iter()
+            .map(|segment| {
+                decode_element(segment, &PCHAR_NOT_PCT_ENCODED, Context::Path)
+                    .expect("encoded path segment is valid")
+            })
+            .collect();
+        Self { encoded, decoded }
+    }
+
+    /// Borrow the decoded bytes of the segments.
+    pub(super) fn decoded(&self) -> &Vec<Vec<u8>> {
+        &self.decoded
+    }
+
+    /// Borrow the percent-encoded segments.
+    pub(super) fn encoded(&self) -> &[String] {
+        &self.encoded
+    }
+}
@@ -0,0 +1,151 @@
+use super::{
+    characters::{
+        QUERY_NOT_PCT_ENCODED_WITHOUT_PLUS,
+        QUERY_OR_FRAGMENT_NOT_PCT_ENCODED,
+        QUERY_PARAMETER_NOT_PCT_ENCODED,
+    },
+    codec::{decode_element, encode_element, normalize_element},
+    context::Context,
+    error::Error,
+};
+
+/// The query of a URI. It is kept percent-encoded as well as decoded, so that a delimiter that
+/// was percent-encoded, such as the `%26` in `filter=a%26b`, is not mistaken for the `&` between
+/// two parameters.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub(super) struct Query {
+    encoded: String,
+    decoded: Vec<u8>,
+}
+
+impl Query {
+    /// Interprets the given string as the query of a URI, without its leading `?`. The
+    /// percent-encoding is normalized.
+    pub(super) fn parse<T>(query_string: T) -> Result<Self, Error>
+    where
+        T: AsRef<str>,
+    {
+        let encoded = normalize_element(
+            query_string,
+            &QUERY_OR_FRAGMENT_NOT_PCT_ENCODED,
+            Context::Query,
+        )?;
+        Ok(Self::from_encoded(encoded))
+    }
+
+    /// Creates the query from its decoded bytes. A `+` is percent-encoded, so it is not read as a
+    /// space.
+    pub(super) fn from_decoded(decoded: Vec<u8>) -> Self {
+        Self {
+            encoded: encode_element(&decoded, &QUERY_NOT_PCT_ENCODED_WITHOUT_PLUS),
+            decoded,
+        }
+    }
+
+    /// Borrow the decoded bytes of the query.
+    pub(super) fn decoded(&self) -> &[u8] {
+        &self.decoded
+    }
+
+    /// Borrow the percent-encoded query.
+    pub(super) fn encoded(&self) -> &str {
+        &self.encoded
+    }
+
+    /// Returns the names and values of the `application/x-www-form-urlencoded` parameters of the
+    /// query, in order. A `+` is read as a space and a parameter without a `=` has an empty
+    /// value.
+    pub(super) fn parameters(&self) -> Vec<(String, String)> {
+        self.encoded_parameters()
+            .map(|parameter| {
+                let (name, value) = parameter.split_once('=').unwrap_or((parameter, ""));
+                (Self::decode_parameter(name), Self::decode_parameter(value))
+            })
+            .collect()
+    }
+
+    /// Appends a parameter to the query.
+    pub(super) fn append_parameter(&mut self, name: &str, value: &str) {
+        let mut encoded = std::mem::take(&mut self.encoded);
+        if !encoded.is_empty() {
+            encoded.push('&');
+        }
+        encoded.push_str(&Self::encode_parameter(name, value));
+        *self = Self::from_encoded(encoded);
+    }
+
+    /// Removes all parameters with the given name from the query. Returns true if any were
+    /// removed.
+    pub(super) fn remove_parameter(&mut self, name: &str) -> bool {
+        let parameters = self.encoded_parameters().count();
+        let encoded = self
+            .encoded_parameters()
+            .filter(|parameter| {
+                let parameter_name = parameter.split_once('=').map_or(*parameter, |(name, _)| name);
+                Self::decode_parameter(parameter_name) != name
+            })
+            .collect::<Vec<_>>();
+        if encoded.len() == parameters {
+            return false;
+        }
+        *self = Self::from_encoded(encoded.join("&"));
+        true
+    }
+
+    /// Replaces the parameters with the given name by a single one with the given value, in the
+    /// place of the first of them, or appends it if there are none.
+    pub(super) fn set_parameter(&mut self, name: &str, value: &str) {
+        let mut replaced = false;
+        let encoded = self
+            .encoded_parameters()
+            .filter_map(|parameter| {
+                let parameter_name = parameter.split_once('=').map_or(parameter, |(name, _)| name);
+                if Self::decode_parameter(parameter_name) != name {
+                    Some(parameter.to_string())
+                } else if replaced {
+                    None
+                } else {
+                    replaced = true;
+                    Some(Self::encode_parameter(name, value))
+                }
+            })
+            .collect::<Vec<_>>();
+        *self = Self::from_encoded(encoded.join("&"));
+        if !replaced {
+            self.append_parameter(name, value);
+        }
+    }
+
+    // ----------------------------------------------------------------------------------------------
+    //                                         private methods
+    // ----------------------------------------------------------------------------------------------
+    fn from_encoded(encoded: String) -> Self {
+        let decoded = decode_element(&encoded, &QUERY_OR_FRAGMENT_NOT_PCT_ENCODED, Context::Query)
+            .expect("encoded query is valid");
+        Self { encoded, decoded }
+    }
+
+    fn encoded_parameters(&self) -> impl Iterator<Item = &str> {
+        self.encoded.split('&').filter(|parameter| !parameter.is_empty())
+    }
+
+    fn encode_parameter(name: &str, value: &str) -> String {
+        format!(
+            "{}={}",
+            encode_element(name.as_bytes(), &QUERY_PARAMETER_NOT_PCT_ENCODED),
+            encode_element(value.as_bytes(), &QUERY_PARAMETER_NOT_PCT_ENCODED)
+        )
+    }
+
+    fn decode_parameter(encoded: &str) -> String {
+        let decoded = encoded
+            .split('+')
+            .map(|part| {
+                decode_element(part, &QUERY_OR_FRAGMENT_NOT_PCT_ENCODED, Context::Query)
+                    .expect("encoded query is valid")
+            })
+            .collect::<Vec<_>>()
+            .join(&b' ');
+        String::from_utf8_lossy(&decoded).into_owned()
+    }
+}
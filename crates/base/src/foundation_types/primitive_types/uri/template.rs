@@ -0,0 +1,476 @@
+use std::collections::HashMap;
+
+use super::{
+    characters::{RESERVED_OR_UNRESERVED, UNRESERVED},
+    codec::encode_element,
+    template_error::UriTemplateError,
+    Uri,
+};
+
+/// The value of a variable of a [`UriTemplate`]: a string, a list of strings or an associative
+/// array of names and strings. Lists and associative arrays without items are undefined, just
+/// like variables without a value.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum UriTemplateValue {
+    String(String),
+    List(Vec<String>),
+    Map(Vec<(String, String)>),
+}
+
+impl UriTemplateValue {
+    /// Creates a list value from the items.
+    pub fn list<I, T>(items: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        Self::List(items.into_iter().map(Into::into).collect())
+    }
+
+    /// Creates an associative array value from the names and values, keeping their order.
+    pub fn map<I, K, V>(pairs: I) -> Self
+    where
+        I: IntoIterator<Item = (K, V)>,
+        K: Into<String>,
+        V: Into<String>,
+    {
+        Self::Map(
+            pairs
+                .into_iter()
+                .map(|(name, value)| (name.into(), value.into()))
+                .collect(),
+        )
+    }
+
+    fn is_defined(&self) -> bool {
+        match self {
+            Self::String(_) => true,
+            Self::List(items) => !items.is_empty(),
+            Self::Map(pairs) => !pairs.is_empty(),
+        }
+    }
+}
+
+impl From<&str> for UriTemplateValue {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_string())
+    }
+}
+
+impl From<String> for UriTemplateValue {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+/// A URI template as specified in [RFC 6570](https://tools.ietf.org/html/rfc6570), up to and
+/// including level 4, such as `/ehr/{ehr_id}/composition/{uid}{?version_at_time}`. Expanding
+/// the template with values for its variables results in a [`Uri`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct UriTemplate {
+    template: String,
+    parts: Vec<Part>,
+}
+
+impl UriTemplate {
+    /// Interpret the given string as a URI template.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UriTemplateError::UnclosedExpression`] if an expression is not closed,
+    /// [`UriTemplateError::IllegalLiteral`] if a character outside the expressions is not
+    /// allowed and [`UriTemplateError::InvalidExpression`] if an expression is not well formed.
+    pub fn parse<T>(template: T) -> Result<Self, UriTemplateError>
+    where
+        T: AsRef<str>,
+    {
+        let template = template.as_ref();
+        let mut parts = Vec::new();
+        let mut literal = String::new();
+        let mut rest = template;
+        while let Some(c) = rest.chars().next() {
+            if c == '{' {
+                let position = template.len() - rest.len();
+                let end = rest
+                    .find('}')
+                    .ok_or(UriTemplateError::UnclosedExpression(position))?;
+                if !literal.is_empty() {
+                    parts.push(Part::Literal(encode_reserved(&std::mem::take(&mut literal))));
+                }
+                parts.push(Self::parse_expression(&rest[1..end])?);
+                rest = &rest[end + 1..];
+            } else {
+                if is_illegal_in_literal(c) && !starts_with_pct_encoded(rest) {
+                    return Err(UriTemplateError::IllegalLiteral(c));
+                }
+                literal.push(c);
+                rest = &rest[c.len_utf8()..];
+            }
+        }
+        if !literal.is_empty() {
+            parts.push(Part::Literal(encode_reserved(&literal)));
+        }
+        Ok(Self {
+            template: template.to_string(),
+            parts,
+        })
+    }
+
+    /// Expand the template with the given values for its variables into a URI. Variables without
+    /// a value are left out, along with the separators and names that would precede them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`UriTemplateError::PrefixOnComposite`] if a prefix modifier is applied to a
+    /// list or an associative array, and [`UriTemplateError::InvalidUri`] if the expansion is not
+    /// a valid URI.
+    pub fn expand(
+        &self,
+        variables: &HashMap<&str, UriTemplateValue>,
+    ) -> Result<Uri, UriTemplateError> {
+        let mut expansion = String::new();
+        for part in &self.parts {
+            match part {
+                Part::Literal(literal) => expansion.push_str(literal),
+                Part::Expression {
+                    operator,
+                    variables: specs,
+                } => {
+                    let mut first = true;
+                    for spec in specs {
+                        let Some(value) = variables.get(spec.name.as_str()) else {
+                            continue;
+                        };
+                        if !value.is_defined() {
+                            continue;
+                        }
+                        expansion.push_str(if first {
+                            operator.first()
+                        } else {
+                            operator.separator()
+                        });
+                        first = false;
+                        Self::expand_variable(&mut expansion, *operator, spec, value)?;
+                    }
+                }
+            }
+        }
+        Ok(Uri::parse(expansion)?)
+    }
+
+    /// Return the names of the variables in the template, in the order they first appear.
+    #[must_use]
+    pub fn variable_names(&self) -> Vec<&str> {
+        let mut names: Vec<&str> = Vec::new();
+        for part in &self.parts {
+            if let Part::Expression { variables, .. } = part {
+                for spec in variables {
+                    if !names.contains(&spec.name.as_str()) {
+                        names.push(&spec.name);
+                    }
+                }
+            }
+        }
+        names
+    }
+
+    // ----------------------------------------------------------------------------------------------
+    //                                         private methods
+    // ----------------------------------------------------------------------------------------------
+    fn parse_expression(expression: &str) -> Result<Part, UriTemplateError> {
+        let invalid = || UriTemplateError::InvalidExpression(expression.to_string());
+        let (operator, variable_list) = match expression.chars().next().and_then(Operator::parse) {
+            Some(operator) => (operator, &expression[1..]),
+            None => (Operator::Simple, expression),
+        };
+        let variables = variable_list
+            .split(',')
+            .map(|spec| VariableSpec::parse(spec).ok_or_else(invalid))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Part::Expression {
+            operator,
+            variables,
+        })
+    }
+
+    fn expand_variable(
+        expansion: &mut String,
+        operator: Operator,
+        spec: &VariableSpec,
+        value: &UriTemplateValue,
+    ) -> Result<(), UriTemplateError> {
+        let encode = |text: &str| operator.encode(text);
+        let push_named = |expansion: &mut String, name: &str, encoded: &str| {
+            expansion.push_str(name);
+            if encoded.is_empty() {
+                expansion.push_str(operator.if_empty());
+            } else {
+                expansion.push('=');
+                expansion.push_str(encoded);
+            }
+        };
+        match (value, spec.modifier) {
+            (UriTemplateValue::String(text), modifier) => {
+                let text = match modifier {
+                    Modifier::Prefix(length) => text.chars().take(length).collect(),
+                    _ => text.clone(),
+                };
+                if operator.named() {
+                    push_named(expansion, &spec.name, &encode(&text));
+                } else {
+                    expansion.push_str(&encode(&text));
+                }
+            }
+            (_, Modifier::Prefix(_)) => {
+                return Err(UriTemplateError::PrefixOnComposite(spec.name.clone()));
+            }
+            (UriTemplateValue::List(items), Modifier::Explode) => {
+                let items: Vec<String> = items
+                    .iter()
+                    .map(|item| {
+                        let mut expanded = String::new();
+                        if operator.named() {
+                            push_named(&mut expanded, &spec.name, &encode(item));
+                        } else {
+                            expanded.push_str(&encode(item));
+                        }
+                        expanded
+                    })
+                    .collect();
+                expansion.push_str(&items.join(operator.separator()));
+            }
+            (UriTemplateValue::Map(pairs), Modifier::Explode) => {
+                let pairs: Vec<String> = pairs
+                    .iter()
+                    .map(|(name, value)| {
+                        let mut expanded = String::new();
+                        if operator.named() {
+                            push_named(&mut expanded, &encode(name), &encode(value));
+                        } else {
+                            expanded.push_str(&format!("{}={}", encode(name), encode(value)));
+                        }
+                        expanded
+                    })
+                    .collect();
+                expansion.push_str(&pairs.join(operator.separator()));
+            }
+            (composite, _) => {
+                let items: Vec<String> = match composite {
+                    UriTemplateValue::Map(pairs) => pairs
+                        .iter()
+                        .flat_map(|(name, value)| [encode(name), encode(value)])
+                        .collect(),
+                    UriTemplateValue::List(items) => {
+                        items.iter().map(|item| encode(item)).collect()
+                    }
+                    UriTemplateValue::String(_) => unreachable!("strings are expanded above"),
+                };
+                if operator.named() {
+                    push_named(expansion, &spec.name, &items.join(","));
+                } else {
+                    expansion.push_str(&items.join(","));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<&'_ str> for UriTemplate {
+    type Error = UriTemplateError;
+
+    fn try_from(template: &'_ str) -> Result<Self, Self::Error> {
+        UriTemplate::parse(template)
+    }
+}
+
+impl std::fmt::Display for UriTemplate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.template)
+    }
+}
+
+/// A part of a URI template: a literal, kept percent-encoded, or an expression.
+#[derive(Clone, Debug, PartialEq, Eq)]
+enum Part {
+    Literal(String),
+    Expression {
+        operator: Operator,
+        variables: Vec<VariableSpec>,
+    },
+}
+
+/// The operator of an expression, which determines how its variables are expanded. See
+/// [RFC 6570 appendix A](https://tools.ietf.org/html/rfc6570#appendix-A).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Operator {
+    /// `{var}`
+    Simple,
+    /// `{+var}`
+    Reserved,
+    /// `{#var}`
+    Fragment,
+    /// `{.var}`
+    Label,
+    /// `{/var}`
+    PathSegment,
+    /// `{;var}`
+    PathParameter,
+    /// `{?var}`
+    Query,
+    /// `{&var}`
+    QueryContinuation,
+}
+
+impl Operator {
+    fn parse(c: char) -> Option<Self> {
+        match c {
+            '+' => Some(Self::Reserved),
+            '#' => Some(Self::Fragment),
+            '.' => Some(Self::Label),
+            '/' => Some(Self::PathSegment),
+            ';' => Some(Self::PathParameter),
+            '?' => Some(Self::Query),
+            '&' => Some(Self::QueryContinuation),
+            _ => None,
+        }
+    }
+
+    fn first(self) -> &'static str {
+        match self {
+            Self::Simple | Self::Reserved => "",
+            Self::Fragment => "#",
+            Self::Label => ".",
+            Self::PathSegment => "/",
+            Self::PathParameter => ";",
+            Self::Query => "?",
+            Self::QueryContinuation => "&",
+        }
+    }
+
+    fn separator(self) -> &'static str {
+        match self {
+            Self::Simple | Self::Reserved | Self::Fragment => ",",
+            Self::Label => ".",
+            Self::PathSegment => "/",
+            Self::PathParameter => ";",
+            Self::Query | Self::QueryContinuation => "&",
+        }
+    }
+
+    fn named(self) -> bool {
+        matches!(self, Self::PathParameter | Self::Query | Self::QueryContinuation)
+    }
+
+    fn if_empty(self) -> &'static str {
+        match self {
+            Self::Query | Self::QueryContinuation => "=",
+            _ => "",
+        }
+    }
+
+    /// Percent-encodes the text, leaving the reserved characters and percent-encoded triplets
+    /// as they are for the `+` and `#` operators.
+    fn encode(self, text: &str) -> String {
+        match self {
+            Self::Reserved | Self::Fragment => encode_reserved(text),
+            _ => encode_element(text.as_bytes(), &UNRESERVED),
+        }
+    }
+}
+
+/// A variable of an expression with its modifier, such as `uid:8` or `ids*`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct VariableSpec {
+    name: String,
+    modifier: Modifier,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Modifier {
+    None,
+    Prefix(usize),
+    Explode,
+}
+
+impl VariableSpec {
+    fn parse(spec: &str) -> Option<Self> {
+        let (name, modifier) = if let Some(name) = spec.strip_suffix('*') {
+            (name, Modifier::Explode)
+        } else if let Some((name, length)) = spec.split_once(':') {
+            let valid_length = !length.is_empty()
+                && length.len() <= 4
+                && !length.starts_with('0')
+                && length.chars().all(|c| c.is_ascii_digit());
+            if !valid_length {
+                return None;
+            }
+            (name, Modifier::Prefix(length.parse().ok()?))
+        } else {
+            (spec, Modifier::None)
+        };
+        is_variable_name(name).then(|| Self {
+            name: name.to_string(),
+            modifier,
+        })
+    }
+}
+
+// ----------------------------------------------------------------------------------------------
+//                                         private functions
+// ----------------------------------------------------------------------------------------------
+/// Percent-encodes the characters of the text that are neither reserved nor unreserved, leaving
+/// percent-encoded triplets such as `%20` as they are.
+fn encode_reserved(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while !rest.is_empty() {
+        if starts_with_pct_encoded(rest) {
+            encoded.push_str(&rest[..3]);
+            rest = &rest[3..];
+        } else {
+            let first = rest.chars().next().map_or(1, char::len_utf8);
+            let end = rest[first..].find('%').map_or(rest.len(), |index| index + first);
+            encoded.push_str(&encode_element(&rest.as_bytes()[..end], &RESERVED_OR_UNRESERVED));
+            rest = &rest[end..];
+        }
+    }
+    encoded
+}
+
+fn starts_with_pct_encoded(text: &str) -> bool {
+    let bytes = text.as_bytes();
+    bytes.len() >= 3
+        && bytes[0] == b'%'
+        && bytes[1].is_ascii_hexdigit()
+        && bytes[2].is_ascii_hexdigit()
+}
+
+/// Returns true if the character may not appear in the literals of a template, which are the
+/// control characters, the space and the characters excluded by
+/// [RFC 6570 section 2.1](https://tools.ietf.org/html/rfc6570#section-2.1), such as `<` and `|`.
+/// A `%` is allowed when it starts a percent-encoded triplet.
+fn is_illegal_in_literal(c: char) -> bool {
+    c.is_control()
+        || matches!(c, ' ' | '"' | '\'' | '%' | '<' | '>' | '\\' | '^' | '`' | '{' | '|' | '}')
+}
+
+/// Returns true if the text is a variable name: characters that are letters, digits, `_` or
+/// percent-encoded triplets, optionally separated by single dots, such as `ehr_id` or
+/// `version.at_time`.
+fn is_variable_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.split('.').all(|part| {
+            let mut rest = part;
+            while let Some(c) = rest.chars().next() {
+                if starts_with_pct_encoded(rest) {
+                    rest = &rest[3..];
+                } else if c.is_ascii_alphanumeric() || c == '_' {
+                    rest = &rest[1..];
+                } else {
+                    return false;
+                }
+            }
+            !part.is_empty()
+        })
+}
@@ -0,0 +1,31 @@
+use super::error::Error;
+
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum UriTemplateError {
+    /// An expression is not closed, such as `/ehr/{ehr_id`.
+    #[error("unclosed expression at {0}")]
+    UnclosedExpression(usize),
+
+    /// A literal contains a character that is not allowed, such as the `}` in `/ehr/ehr_id}` or
+    /// the space in `/ehr /{ehr_id}`.
+    #[error("illegal character {0:?} in literal")]
+    IllegalLiteral(char),
+
+    /// An expression is not well formed, such as `{}`, `{=ehr_id}` or `{ehr_id:0}`.
+    #[error("invalid expression {{{0}}}")]
+    InvalidExpression(String),
+
+    /// A prefix modifier is applied to a list or an associative array, such as `{ids:3}` with a
+    /// list of ids.
+    #[error("prefix modifier applied to composite value {0}")]
+    PrefixOnComposite(String),
+
+    /// The expanded template is not a valid URI, such as `http://example.com:{port}` with
+    /// `port` set to `http`.
+    #[error("expanded template is not a valid URI")]
+    InvalidUri(
+        #[source]
+        #[from]
+        Error,
+    ),
+}
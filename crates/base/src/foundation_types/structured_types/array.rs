@@ -0,0 +1,80 @@
+use crate::foundation_types::{type_name, Any};
+use super::Container;
+
+/// Container of a fixed number of items that are accessed by their position. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_array_class)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Array<T> {
+    items: Vec<T>,
+}
+
+impl<T> Array<T> {
+
+    /// Returns the item at the given position, if any.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.items.get(index)
+    }
+
+    /// Replaces the item at the given position and returns the previous item, or gives the
+    /// item back if the position lies outside the array.
+    pub fn set(&mut self, index: usize, item: T) -> Result<T, T> {
+        match self.items.get_mut(index) {
+            Some(current) => Ok(std::mem::replace(current, item)),
+            None => Err(item),
+        }
+    }
+
+}
+
+impl<T: PartialEq> Container<T> for Array<T> {
+
+    fn items<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.items.iter()
+    }
+
+}
+
+impl<T: PartialEq> Any for Array<T> {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn type_of(&self) -> String {
+        format!("Array<{}>", type_name::<T>())
+    }
+
+}
+
+impl<T> From<Vec<T>> for Array<T> {
+
+    fn from(items: Vec<T>) -> Self {
+        Array {
+            items
+        }
+    }
+
+}
+
+impl<T> FromIterator<T> for Array<T> {
+
+    fn from_iter<I: IntoIterator<Item = T>>(items: I) -> Self {
+        Array {
+            items: items.into_iter().collect(),
+        }
+    }
+
+}
+
+impl<T> IntoIterator for Array<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+
+}
@@ -0,0 +1,58 @@
+use crate::foundation_types::{Any, Integer};
+use super::List;
+
+/// This trait is the base trait for all containers of items. It provides the query and
+/// quantifier operations on the items. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_container_class)
+pub trait Container<T>: Any {
+
+    /// Returns an iterator over the items of the container.
+    fn items<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a;
+
+    /// Returns true if the value is one of the items of the container.
+    fn has(&self, value: &T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.items().any(|item| item == value)
+    }
+
+    /// Returns the number of items in the container. A container with more items than an
+    /// `Integer` can hold returns the largest `Integer`.
+    fn count(&self) -> Integer {
+        Integer::new(i32::try_from(self.items().count()).unwrap_or(i32::MAX))
+    }
+
+    /// Returns true if the container has no items.
+    fn is_empty(&self) -> bool {
+        self.items().next().is_none()
+    }
+
+    /// Returns true if the test holds for at least one item of the container.
+    fn there_exists<F>(&self, test: F) -> bool
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.items().any(test)
+    }
+
+    /// Returns true if the test holds for every item of the container.
+    fn for_all<F>(&self, test: F) -> bool
+    where
+        F: Fn(&T) -> bool,
+    {
+        self.items().all(test)
+    }
+
+    /// Returns the items of the container for which the test holds.
+    fn matching<F>(&self, test: F) -> List<T>
+    where
+        T: Clone,
+        F: Fn(&T) -> bool,
+    {
+        self.items().filter(|item| test(item)).cloned().collect()
+    }
+
+}
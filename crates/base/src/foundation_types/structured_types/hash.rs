@@ -0,0 +1,106 @@
+use indexmap::IndexMap;
+use crate::foundation_types::{type_name, Any};
+use super::Container;
+
+/// Container of items that are accessed by a unique key. Keys are hashed, so adding an item and
+/// looking one up take constant time; the keys are iterated in the order they were added. See
+/// the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_hash_class)
+#[derive(Clone, Debug)]
+pub struct Hash<K, V> {
+    entries: IndexMap<K, V>,
+}
+
+impl<K, V> Hash<K, V> {
+
+    /// Creates an empty hash.
+    pub fn new() -> Self {
+        Hash {
+            entries: IndexMap::new(),
+        }
+    }
+
+    /// Returns an iterator over the keys of the hash.
+    pub fn keys(&self) -> impl Iterator<Item = &K> {
+        self.entries.keys()
+    }
+
+}
+
+impl<K: std::hash::Hash + Eq, V> Hash<K, V> {
+
+    /// Returns true if the hash has an item with the given key.
+    pub fn has_key(&self, key: &K) -> bool {
+        self.entries.contains_key(key)
+    }
+
+    /// Returns the item with the given key, if any.
+    pub fn item(&self, key: &K) -> Option<&V> {
+        self.entries.get(key)
+    }
+
+    /// Adds an item with the given key, and returns the item it replaces, if any.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.entries.insert(key, value)
+    }
+
+    /// Removes the item with the given key and returns it, if any.
+    pub fn remove(&mut self, key: &K) -> Option<V> {
+        self.entries.swap_remove(key)
+    }
+
+}
+
+impl<K: std::hash::Hash + Eq, V: PartialEq> Container<V> for Hash<K, V> {
+
+    fn items<'a>(&'a self) -> impl Iterator<Item = &'a V>
+    where
+        V: 'a,
+    {
+        self.entries.values()
+    }
+
+}
+
+impl<K: std::hash::Hash + Eq, V: PartialEq> Any for Hash<K, V> {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn type_of(&self) -> String {
+        format!("Hash<{}, {}>", type_name::<K>(), type_name::<V>())
+    }
+
+}
+
+/// Two hashes are equal if they have the same keys with the same items, regardless of the
+/// order they were added in.
+impl<K: std::hash::Hash + Eq, V: PartialEq> PartialEq for Hash<K, V> {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.entries == other.entries
+    }
+
+}
+
+impl<K: std::hash::Hash + Eq, V: Eq> Eq for Hash<K, V> {
+}
+
+impl<K, V> Default for Hash<K, V> {
+
+    fn default() -> Self {
+        Self::new()
+    }
+
+}
+
+impl<K: std::hash::Hash + Eq, V> FromIterator<(K, V)> for Hash<K, V> {
+
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(entries: I) -> Self {
+        Hash {
+            entries: entries.into_iter().collect(),
+        }
+    }
+
+}
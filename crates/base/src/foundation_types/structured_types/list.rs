@@ -0,0 +1,101 @@
+use crate::foundation_types::{type_name, Any};
+use super::Container;
+
+/// Ordered container of items that may contain duplicates. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_list_class)
+#[derive(Clone, Debug, PartialEq)]
+pub struct List<T> {
+    items: Vec<T>,
+}
+
+impl<T> List<T> {
+
+    /// Creates an empty list.
+    pub fn new() -> Self {
+        List {
+            items: Vec::new(),
+        }
+    }
+
+    /// Returns the first item of the list, if any.
+    pub fn first(&self) -> Option<&T> {
+        self.items.first()
+    }
+
+    /// Returns the last item of the list, if any.
+    pub fn last(&self) -> Option<&T> {
+        self.items.last()
+    }
+
+    /// Returns the item at the given position, if any.
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.items.get(index)
+    }
+
+    /// Appends an item to the end of the list.
+    pub fn push(&mut self, item: T) {
+        self.items.push(item);
+    }
+
+}
+
+impl<T: PartialEq> Container<T> for List<T> {
+
+    fn items<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.items.iter()
+    }
+
+}
+
+impl<T: PartialEq> Any for List<T> {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn type_of(&self) -> String {
+        format!("List<{}>", type_name::<T>())
+    }
+
+}
+
+impl<T> Default for List<T> {
+
+    fn default() -> Self {
+        Self::new()
+    }
+
+}
+
+impl<T> From<Vec<T>> for List<T> {
+
+    fn from(items: Vec<T>) -> Self {
+        List {
+            items
+        }
+    }
+
+}
+
+impl<T> FromIterator<T> for List<T> {
+
+    fn from_iter<I: IntoIterator<Item = T>>(items: I) -> Self {
+        List {
+            items: items.into_iter().collect(),
+        }
+    }
+
+}
+
+impl<T> IntoIterator for List<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+
+}
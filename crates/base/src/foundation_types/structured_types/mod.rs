@@ -0,0 +1,13 @@
+mod container;
+mod list;
+mod array;
+mod set;
+mod hash;
+
+pub use {
+    container::Container,
+    list::List,
+    array::Array,
+    set::Set,
+    hash::Hash,
+};
@@ -0,0 +1,104 @@
+use indexmap::IndexSet;
+use crate::foundation_types::{type_name, Any};
+use super::Container;
+
+/// Unordered container of items without duplicates. Items are hashed, so adding an item and
+/// looking one up take constant time; the items are iterated in the order they were added. See
+/// the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_set_class)
+#[derive(Clone, Debug)]
+pub struct Set<T> {
+    items: IndexSet<T>,
+}
+
+impl<T> Set<T> {
+
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Set {
+            items: IndexSet::new(),
+        }
+    }
+
+}
+
+impl<T: std::hash::Hash + Eq> Set<T> {
+
+    /// Adds an item to the set. Returns false if the set already had the item.
+    pub fn insert(&mut self, item: T) -> bool {
+        self.items.insert(item)
+    }
+
+    /// Removes an item from the set. Returns false if the set did not have the item.
+    pub fn remove(&mut self, item: &T) -> bool {
+        self.items.swap_remove(item)
+    }
+
+}
+
+impl<T: std::hash::Hash + Eq> Container<T> for Set<T> {
+
+    fn items<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.items.iter()
+    }
+
+    fn has(&self, value: &T) -> bool {
+        self.items.contains(value)
+    }
+
+}
+
+impl<T: std::hash::Hash + Eq> Any for Set<T> {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn type_of(&self) -> String {
+        format!("Set<{}>", type_name::<T>())
+    }
+
+}
+
+/// Two sets are equal if they have the same items, regardless of the order they were added in.
+impl<T: std::hash::Hash + Eq> PartialEq for Set<T> {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items
+    }
+
+}
+
+impl<T: std::hash::Hash + Eq> Eq for Set<T> {
+}
+
+impl<T> Default for Set<T> {
+
+    fn default() -> Self {
+        Self::new()
+    }
+
+}
+
+impl<T: std::hash::Hash + Eq> FromIterator<T> for Set<T> {
+
+    fn from_iter<I: IntoIterator<Item = T>>(items: I) -> Self {
+        Set {
+            items: items.into_iter().collect(),
+        }
+    }
+
+}
+
+impl<T> IntoIterator for Set<T> {
+    type Item = T;
+    type IntoIter = indexmap::set::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.items.into_iter()
+    }
+
+}
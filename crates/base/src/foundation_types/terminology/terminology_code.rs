@@ -1,10 +1,20 @@
 
 use crate::foundation_types::{Any, String, Uri};
 
+/// A reference to a concept in a terminology. In the openEHR canonical JSON representation the
+/// optional `terminology_version` and `uri` are left out when empty.
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_::Serialize, serde_::Deserialize),
+    serde(crate = "serde_")
+)]
 pub struct TerminologyCode {
     pub terminology_id: String,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "String::is_empty"))]
     pub terminology_version: String,
     pub code_string: String,
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "str::is_empty"))]
     pub uri: Uri
 }
 
@@ -26,10 +36,6 @@ impl Any for TerminologyCode {
         self.uri == other.uri
     }
 
-    fn instance_of(&self, type_name: &str) -> bool {
-        "TerminologyCode" == type_name
-    }
-
     fn type_of(&self) -> std::string::String {
         "TerminologyCode".to_string()
     }
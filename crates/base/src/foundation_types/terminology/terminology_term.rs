@@ -1,6 +1,12 @@
 use crate::foundation_types::{Any, String};
 use super::terminology_code::TerminologyCode;
 
+#[derive(Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde_::Serialize, serde_::Deserialize),
+    serde(crate = "serde_")
+)]
 pub struct TerminologyTerm {
     pub concept: TerminologyCode,
     pub text: String,
@@ -22,10 +28,6 @@ impl Any for TerminologyTerm {
         self.text == other.text
     }
 
-    fn instance_of(&self, type_name: &str) -> bool {
-        "TerminologyTerm" == type_name
-    }
-
     fn type_of(&self) -> std::string::String {
         "TerminologyTerm".to_string()
     }
@@ -0,0 +1,36 @@
+//! Conversions between proleptic Gregorian calendar dates and day numbers, counted from
+//! 1970-01-01. See <http://howardhinnant.github.io/date_algorithms.html>.
+
+pub const NANOS_IN_SECOND: i128 = 1_000_000_000;
+pub const NANOS_IN_DAY: i128 = 86_400 * NANOS_IN_SECOND;
+
+/// Returns the number of days between 1970-01-01 and the given date.
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = month as i64;
+    let day_of_year = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the date that lies the given number of days after 1970-01-01.
+pub fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u32;
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
+
+/// Adds a number of months to a year and month, returning the resulting year and month.
+pub fn add_months(year: i64, month: u32, months: i64) -> (i64, u32) {
+    let index = year * 12 + month as i64 - 1 + months;
+    (index.div_euclid(12), (index.rem_euclid(12) + 1) as u32)
+}
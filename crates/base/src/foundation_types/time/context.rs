@@ -0,0 +1,57 @@
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Context {
+    /// This is a complete or partial calendar date, such as `2024-03-15` or `2024-03`.
+    Date,
+
+    /// This is a complete or partial time of day, such as `10:15:30` or `T10`.
+    Time,
+
+    /// This is a date and time combined with a `T`, such as `2024-03-15T10:15:30Z`.
+    DateTime,
+
+    /// This is a duration, such as `P1Y2M10DT2H30M`.
+    Duration,
+
+    /// This is a time zone offset, such as `Z` or `+01:00`.
+    Timezone,
+
+    /// This is the year part of a date, such as `2024` in `2024-03-15`.
+    Year,
+
+    /// This is the month part of a date, such as `03` in `2024-03-15`.
+    Month,
+
+    /// This is the day part of a date, such as `15` in `2024-03-15`.
+    Day,
+
+    /// This is the hour part of a time or time zone, such as `10` in `10:15:30`.
+    Hour,
+
+    /// This is the minute part of a time or time zone, such as `15` in `10:15:30`.
+    Minute,
+
+    /// This is the second part of a time, such as `30` in `10:15:30`.
+    Second,
+
+    /// This is the fractional second part of a time, such as `250` in `10:15:30.250`.
+    FractionalSecond,
+}
+
+impl std::fmt::Display for Context {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Context::Date => write!(f, "date"),
+            Context::Time => write!(f, "time"),
+            Context::DateTime => write!(f, "date/time"),
+            Context::Duration => write!(f, "duration"),
+            Context::Timezone => write!(f, "time zone"),
+            Context::Year => write!(f, "year"),
+            Context::Month => write!(f, "month"),
+            Context::Day => write!(f, "day"),
+            Context::Hour => write!(f, "hour"),
+            Context::Minute => write!(f, "minute"),
+            Context::Second => write!(f, "second"),
+            Context::FractionalSecond => write!(f, "fractional second"),
+        }
+    }
+}
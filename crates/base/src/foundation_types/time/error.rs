@@ -0,0 +1,38 @@
+use super::context::Context;
+
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum Error {
+    /// The string does not follow the ISO 8601 syntax of the value being parsed, such as
+    /// `2024/03/15` for a date or `10h15` for a time.
+    #[error("invalid ISO 8601 {0}")]
+    InvalidFormat(Context),
+
+    /// A component of the value lies outside its allowed range, such as the month in
+    /// `2024-13-01` or the day in `2023-02-29`.
+    #[error("{0} out of range")]
+    OutOfRange(Context),
+
+    /// The value mixes the basic and extended formats, such as `2024-03-15T101530`.
+    #[error("mixed basic and extended format")]
+    InconsistentFormat,
+
+    /// The operation requires a complete value, such as the difference between the partial
+    /// dates `2024-03` and `2024-05-01`.
+    #[error("{0} is partial")]
+    PartialValue(Context),
+
+    /// The duration is more precise than the value it is added to, such as `PT1H` added to the
+    /// date `2024-03-15` or `P10D` added to the partial date `2024-03`.
+    #[error("duration is more precise than the {0}")]
+    ImpreciseDuration(Context),
+
+    /// The duration has years, months, weeks or days, which cannot be added to a value without a
+    /// date, such as `P1DT2H` added to the time `10:00`.
+    #[error("duration has years, months, weeks or days, which a {0} cannot take")]
+    UnsupportedDuration(Context),
+
+    /// The operation requires a time zone, such as converting `2024-03-15T10:00` to another
+    /// time zone or the difference between `2024-03-15T10:00` and `2024-03-15T10:00Z`.
+    #[error("time zone expected but missing")]
+    MissingTimezone,
+}
@@ -0,0 +1,345 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Sub};
+use crate::foundation_types::{Any, Integer, Ordered};
+use super::{
+    context::Context,
+    error::Error,
+    parser,
+    calendar,
+    span::Span,
+    Iso8601Duration,
+    Iso8601Type,
+    Temporal,
+    TimeDefinitions,
+};
+
+/// An ISO 8601 calendar date. The date may be partial, i.e. only contain a year (`2024`) or a
+/// year and a month (`2024-03`). Both the extended (`2024-03-15`) and basic (`20240315`) formats
+/// are supported. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_iso8601_date_class)
+#[derive(Clone, Debug)]
+pub struct Iso8601Date {
+    year: i32,
+    month: Option<u32>,
+    day: Option<u32>,
+    extended: bool,
+}
+
+impl Iso8601Date {
+
+    /// Creates a complete date from its year, month and day.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfRange`] if the month or day is out of range.
+    pub fn new(year: i32, month: u32, day: u32) -> Result<Self, Error> {
+        Self::from_parts(year, Some(month), Some(day), true)
+    }
+
+    /// Interpret the given string as an ISO 8601 date. Accepted forms are `YYYY`, `YYYY-MM`,
+    /// `YYYY-MM-DD` and `YYYYMMDD`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidFormat`] if the string is not a date in one of the accepted forms
+    /// and [`Error::OutOfRange`] if the month or day is out of range.
+    pub fn parse<T>(date_string: T) -> Result<Self, Error>
+    where
+        T: AsRef<str>,
+    {
+        let s = date_string.as_ref();
+        if !s.is_ascii() {
+            return Err(Error::InvalidFormat(Context::Date));
+        }
+        let bytes = s.as_bytes();
+        let (year, month, day, extended) = match s.len() {
+            4 => (s, None, None, true),
+            7 if bytes[4] == b'-' => (&s[0..4], Some(&s[5..7]), None, true),
+            8 => (&s[0..4], Some(&s[4..6]), Some(&s[6..8]), false),
+            10 if bytes[4] == b'-' && bytes[7] == b'-' => {
+                (&s[0..4], Some(&s[5..7]), Some(&s[8..10]), true)
+            }
+            _ => return Err(Error::InvalidFormat(Context::Date)),
+        };
+        Self::from_parts(
+            parser::number(year, Context::Year)? as i32,
+            month.map(|month| parser::number(month, Context::Month)).transpose()?,
+            day.map(|day| parser::number(day, Context::Day)).transpose()?,
+            extended,
+        )
+    }
+
+    /// Returns the year part of the date.
+    pub fn year(&self) -> Integer {
+        Integer::new(self.year)
+    }
+
+    /// Returns the month part of the date, if present.
+    pub fn month(&self) -> Option<Integer> {
+        self.month.map(|month| Integer::new(month as i32))
+    }
+
+    /// Returns the day part of the date, if present.
+    pub fn day(&self) -> Option<Integer> {
+        self.day.map(|day| Integer::new(day as i32))
+    }
+
+    /// Returns true if the month, and therefore also the day, is unknown.
+    pub fn month_unknown(&self) -> bool {
+        self.month.is_none()
+    }
+
+    /// Returns true if the day is unknown.
+    pub fn day_unknown(&self) -> bool {
+        self.day.is_none()
+    }
+
+    /// Adds a duration to the date. The years and months of the duration are added first and
+    /// the day is clamped to the last day of the resulting month, so `2024-01-31` plus `P1M` is
+    /// `2024-02-29`. The weeks and days are then added. A partial date keeps its precision, so
+    /// `2024-03` plus `P1M` is `2024-04`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfRange`] if the resulting year lies outside 0000 - 9999, and
+    /// [`Error::ImpreciseDuration`] if the duration has hours, minutes or seconds that do not add
+    /// up to whole days, has days while the day of the date is unknown, or has months that do
+    /// not add up to whole years while the month of the date is unknown.
+    pub fn add_duration(&self, duration: &Iso8601Duration) -> Result<Self, Error> {
+        let nanoseconds = duration.exact_nanoseconds();
+        if nanoseconds % calendar::NANOS_IN_DAY != 0 || (self.day.is_none() && nanoseconds != 0) {
+            return Err(Error::ImpreciseDuration(Context::Date));
+        }
+        let date = self.add_months(duration.nominal_months())?;
+        match date.day {
+            Some(_) => {
+                let days = (nanoseconds / calendar::NANOS_IN_DAY) as i64;
+                Self::from_days(date.days().0 + days, self.extended)
+            }
+            None => Ok(date),
+        }
+    }
+
+    /// Subtracts a duration from the date. See [`add_duration`](#method.add_duration).
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`add_duration`](#method.add_duration).
+    pub fn subtract_duration(&self, duration: &Iso8601Duration) -> Result<Self, Error> {
+        self.add_duration(&-duration.clone())
+    }
+
+    /// Returns the calendar difference between this date and `other` in years, months and days,
+    /// such as the age of a patient on this date given the date of birth. Adding the result to
+    /// `other` gives this date again. The result is negative if `other` lies after this date.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PartialValue`] if either date is partial.
+    pub fn diff(&self, other: &Iso8601Date) -> Result<Iso8601Duration, Error> {
+        if self.is_partial() || other.is_partial() {
+            return Err(Error::PartialValue(Context::Date));
+        }
+        let negative = self.days().0 < other.days().0;
+        let (later, earlier) = if negative { (other, self) } else { (self, other) };
+        let mut months = (later.year as i64 - earlier.year as i64) * 12
+            + later.month.unwrap_or(1) as i64
+            - earlier.month.unwrap_or(1) as i64;
+        if earlier.add_months(months)?.days().0 > later.days().0 {
+            months -= 1;
+        }
+        let days = later.days().0 - earlier.add_months(months)?.days().0;
+        let duration = Iso8601Duration::new(
+            (months / 12) as u32,
+            (months % 12) as u32,
+            0,
+            days as u32,
+            0,
+            0,
+            0,
+        );
+        Ok(if negative { -duration } else { duration })
+    }
+
+    // ----------------------------------------------------------------------------------------------
+    //                                         private methods
+    // ----------------------------------------------------------------------------------------------
+    pub(super) fn from_parts(
+        year: i32,
+        month: Option<u32>,
+        day: Option<u32>,
+        extended: bool,
+    ) -> Result<Self, Error> {
+        if !TimeDefinitions::valid_year(year) || year > 9999 {
+            return Err(Error::OutOfRange(Context::Year));
+        }
+        if let Some(month) = month {
+            if !TimeDefinitions::valid_month(month) {
+                return Err(Error::OutOfRange(Context::Month));
+            }
+        }
+        match (month, day) {
+            (Some(month), Some(day)) if !TimeDefinitions::valid_day(year, month, day) => {
+                return Err(Error::OutOfRange(Context::Day));
+            }
+            (None, Some(_)) => return Err(Error::InvalidFormat(Context::Date)),
+            _ => (),
+        }
+        Ok(Iso8601Date {
+            year,
+            month,
+            day,
+            extended,
+        })
+    }
+
+    pub(super) fn parts(&self) -> (i32, Option<u32>, Option<u32>) {
+        (self.year, self.month, self.day)
+    }
+
+    pub(super) fn from_days(days: i64, extended: bool) -> Result<Self, Error> {
+        let (year, month, day) = calendar::civil_from_days(days);
+        let year = i32::try_from(year).map_err(|_| Error::OutOfRange(Context::Year))?;
+        Self::from_parts(year, Some(month), Some(day), extended)
+    }
+
+    /// Adds a number of months to the date, clamping the day to the last day of the resulting
+    /// month. A date with only a year can only be moved by whole years.
+    pub(super) fn add_months(&self, months: i64) -> Result<Self, Error> {
+        let (year, month) = match self.month {
+            Some(month) => calendar::add_months(self.year as i64, month, months),
+            None if months % 12 == 0 => (self.year as i64 + months / 12, 1),
+            None => return Err(Error::ImpreciseDuration(Context::Date)),
+        };
+        let year = i32::try_from(year)
+            .ok()
+            .filter(|year| (0..=9999).contains(year))
+            .ok_or(Error::OutOfRange(Context::Year))?;
+        Self::from_parts(
+            year,
+            self.month.map(|_| month),
+            self.day.map(|day| day.min(TimeDefinitions::days_in_month(month, year))),
+            self.extended,
+        )
+    }
+
+    /// Returns the number of days between 1970-01-01 and the first day covered by this date,
+    /// together with the number of days covered.
+    pub(super) fn days(&self) -> (i64, i64) {
+        let year = self.year as i64;
+        let first = calendar::days_from_civil(year, self.month.unwrap_or(1), self.day.unwrap_or(1));
+        let last = match (self.month, self.day) {
+            (Some(_), Some(_)) => first + 1,
+            (Some(12), None) => calendar::days_from_civil(year + 1, 1, 1),
+            (Some(month), None) => calendar::days_from_civil(year, month + 1, 1),
+            (None, _) => calendar::days_from_civil(year + 1, 1, 1),
+        };
+        (first, last - first)
+    }
+
+    pub(super) fn span(&self) -> Span {
+        let (first, days) = self.days();
+        Span::new(
+            first as i128 * calendar::NANOS_IN_DAY,
+            days as i128 * calendar::NANOS_IN_DAY,
+            false,
+        )
+    }
+
+}
+
+impl Any for Iso8601Date {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn type_of(&self) -> String {
+        "Iso8601Date".to_string()
+    }
+
+}
+
+impl Temporal for Iso8601Date {
+}
+
+impl Iso8601Type for Iso8601Date {
+
+    fn value(&self) -> String {
+        self.to_string()
+    }
+
+    fn is_partial(&self) -> bool {
+        self.day.is_none()
+    }
+
+    fn is_extended(&self) -> bool {
+        self.extended
+    }
+
+}
+
+impl Ordered for Iso8601Date {
+}
+
+impl PartialEq for Iso8601Date {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.parts() == other.parts()
+    }
+
+}
+
+impl Eq for Iso8601Date {
+}
+
+/// Dates are compared by the days they cover. A partial date covers several days and is only
+/// ordered with respect to another date if they do not overlap: `2023` is before `2024-03`, but
+/// `2024` and `2024-03` are not ordered.
+impl PartialOrd for Iso8601Date {
+
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.span().compare(&other.span())
+    }
+
+}
+
+impl Add<Iso8601Duration> for Iso8601Date {
+    type Output = Result<Iso8601Date, Error>;
+
+    fn add(self, duration: Iso8601Duration) -> Result<Iso8601Date, Error> {
+        self.add_duration(&duration)
+    }
+
+}
+
+impl Sub<Iso8601Duration> for Iso8601Date {
+    type Output = Result<Iso8601Date, Error>;
+
+    fn sub(self, duration: Iso8601Duration) -> Result<Iso8601Date, Error> {
+        self.subtract_duration(&duration)
+    }
+
+}
+
+impl std::fmt::Display for Iso8601Date {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let separator = if self.extended { "-" } else { "" };
+        write!(f, "{:04}", self.year)?;
+        if let Some(month) = self.month {
+            write!(f, "{}{:02}", separator, month)?;
+        }
+        if let Some(day) = self.day {
+            write!(f, "{}{:02}", separator, day)?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<&'_ str> for Iso8601Date {
+    type Error = Error;
+
+    fn try_from(date_string: &'_ str) -> Result<Self, Self::Error> {
+        Iso8601Date::parse(date_string)
+    }
+}
@@ -0,0 +1,363 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Sub};
+use crate::foundation_types::{Any, Double, Integer, Ordered};
+use super::{
+    context::Context,
+    error::Error,
+    calendar,
+    span::Span,
+    Iso8601Date,
+    Iso8601Duration,
+    Iso8601Time,
+    Iso8601Timezone,
+    Iso8601Type,
+    Temporal,
+};
+
+/// An ISO 8601 date and time, such as `2024-03-15T10:15:30Z` or `20240315T101530Z`. The value
+/// may be partial: the time may be truncated (`2024-03-15T10`) or left out entirely, in which
+/// case the date itself may be partial (`2024-03`). See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_iso8601_date_time_class)
+#[derive(Clone, Debug)]
+pub struct Iso8601DateTime {
+    date: Iso8601Date,
+    time: Option<Iso8601Time>,
+}
+
+impl Iso8601DateTime {
+
+    /// Creates a date/time from a date and an optional time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidFormat`] if a time is given together with a partial date.
+    pub fn new(date: Iso8601Date, time: Option<Iso8601Time>) -> Result<Self, Error> {
+        if time.is_some() && date.day().is_none() {
+            return Err(Error::InvalidFormat(Context::DateTime));
+        }
+        Ok(Iso8601DateTime { date, time })
+    }
+
+    /// Interpret the given string as an ISO 8601 date/time, i.e. a date optionally followed by
+    /// `T` and a time.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidFormat`] if the string is not a date/time, [`Error::OutOfRange`]
+    /// if a component is out of range and [`Error::InconsistentFormat`] if the date and time use
+    /// different formats.
+    pub fn parse<T>(date_time_string: T) -> Result<Self, Error>
+    where
+        T: AsRef<str>,
+    {
+        let s = date_time_string.as_ref();
+        let (date, time) = match s.find('T') {
+            Some(time_start) => (
+                Iso8601Date::parse(&s[..time_start])?,
+                Some(Iso8601Time::parse(&s[time_start..])?),
+            ),
+            None => (Iso8601Date::parse(s)?, None),
+        };
+        if let Some(time) = &time {
+            if time.minute().is_some() && date.is_extended() != time.is_extended() {
+                return Err(Error::InconsistentFormat);
+            }
+        }
+        Self::new(date, time)
+    }
+
+    /// Returns the date part of the date/time.
+    pub fn date(&self) -> &Iso8601Date {
+        &self.date
+    }
+
+    /// Returns the time part of the date/time, if present.
+    pub fn time(&self) -> Option<&Iso8601Time> {
+        self.time.as_ref()
+    }
+
+    /// Returns the year part of the date/time.
+    pub fn year(&self) -> Integer {
+        self.date.year()
+    }
+
+    /// Returns the month part of the date/time, if present.
+    pub fn month(&self) -> Option<Integer> {
+        self.date.month()
+    }
+
+    /// Returns the day part of the date/time, if present.
+    pub fn day(&self) -> Option<Integer> {
+        self.date.day()
+    }
+
+    /// Returns the hour part of the date/time, if present.
+    pub fn hour(&self) -> Option<Integer> {
+        self.time.as_ref().map(Iso8601Time::hour)
+    }
+
+    /// Returns the minute part of the date/time, if present.
+    pub fn minute(&self) -> Option<Integer> {
+        self.time.as_ref().and_then(Iso8601Time::minute)
+    }
+
+    /// Returns the second part of the date/time, if present.
+    pub fn second(&self) -> Option<Integer> {
+        self.time.as_ref().and_then(Iso8601Time::second)
+    }
+
+    /// Returns the fractional second part of the date/time, if present.
+    pub fn fractional_second(&self) -> Option<Double> {
+        self.time.as_ref().and_then(Iso8601Time::fractional_second)
+    }
+
+    /// Returns the time zone of the date/time, if present.
+    pub fn timezone(&self) -> Option<&Iso8601Timezone> {
+        self.time.as_ref().and_then(Iso8601Time::timezone)
+    }
+
+    /// Returns true if the month, and therefore also all smaller components, is unknown.
+    pub fn month_unknown(&self) -> bool {
+        self.date.month_unknown()
+    }
+
+    /// Returns true if the day, and therefore also the time, is unknown.
+    pub fn day_unknown(&self) -> bool {
+        self.date.day_unknown()
+    }
+
+    /// Returns true if the time is unknown.
+    pub fn hour_unknown(&self) -> bool {
+        self.time.is_none()
+    }
+
+    /// Returns true if the minute, and therefore also the second, is unknown.
+    pub fn minute_unknown(&self) -> bool {
+        self.time.as_ref().is_none_or(Iso8601Time::minute_unknown)
+    }
+
+    /// Returns true if the second is unknown.
+    pub fn second_unknown(&self) -> bool {
+        self.time.as_ref().is_none_or(Iso8601Time::second_unknown)
+    }
+
+    /// Returns true if the date/time has a fractional second part.
+    pub fn has_fractional_second(&self) -> bool {
+        self.time.as_ref().is_some_and(Iso8601Time::has_fractional_second)
+    }
+
+    /// Adds a duration to the date/time. The years and months of the duration are added first
+    /// and the day is clamped to the last day of the resulting month, so `2024-01-31T10:00` plus
+    /// `P1M` is `2024-02-29T10:00`. The remaining components are then added as an exact amount
+    /// of time, carrying over into the date. The time zone is kept, and a partial date/time
+    /// keeps its precision, so `2024-03-15T10` plus `PT2H` is `2024-03-15T12`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfRange`] if the resulting year lies outside 0000 - 9999,
+    /// [`Error::ImpreciseDuration`] if the duration is more precise than a partial time, such as
+    /// `PT30M` added to `2024-03-15T10`, and the errors of [`Iso8601Date::add_duration`] if the
+    /// date/time has no time.
+    pub fn add_duration(&self, duration: &Iso8601Duration) -> Result<Self, Error> {
+        match &self.time {
+            Some(time) => {
+                if duration.exact_nanoseconds() % time.precision_nanoseconds() != 0 {
+                    return Err(Error::ImpreciseDuration(Context::DateTime));
+                }
+                let date = self.date.add_months(duration.nominal_months())?;
+                Self::from_local_nanoseconds(
+                    date.days().0 as i128 * calendar::NANOS_IN_DAY
+                        + time.nanosecond_of_day()
+                        + duration.exact_nanoseconds(),
+                    self,
+                    duration.fraction_digits(),
+                )
+            }
+            None => Ok(Iso8601DateTime {
+                date: self.date.add_duration(duration)?,
+                time: None,
+            }),
+        }
+    }
+
+    /// Subtracts a duration from the date/time. See [`add_duration`](#method.add_duration).
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`add_duration`](#method.add_duration).
+    pub fn subtract_duration(&self, duration: &Iso8601Duration) -> Result<Self, Error> {
+        self.add_duration(&-duration.clone())
+    }
+
+    /// Returns the exact amount of time between `other` and this date/time, in days, hours,
+    /// minutes and seconds. The result is negative if `other` lies after this date/time. If both
+    /// date/times have a time zone, they are converted to UTC first. Unknown time components are
+    /// taken to be zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PartialValue`] if the day of either date/time is unknown and
+    /// [`Error::MissingTimezone`] if only one of the date/times has a time zone.
+    pub fn diff(&self, other: &Iso8601DateTime) -> Result<Iso8601Duration, Error> {
+        if self.day_unknown() || other.day_unknown() {
+            return Err(Error::PartialValue(Context::DateTime));
+        }
+        if self.timezone().is_some() != other.timezone().is_some() {
+            return Err(Error::MissingTimezone);
+        }
+        Ok(Iso8601Duration::from_nanoseconds(
+            self.span().start() - other.span().start(),
+        ))
+    }
+
+    /// Converts the date/time to the given time zone, so `2024-03-15T00:30+01:00` converted to
+    /// `Z` is `2024-03-14T23:30Z`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::PartialValue`] if the date/time has no time, [`Error::MissingTimezone`]
+    /// if it has no time zone and [`Error::OutOfRange`] if the resulting year lies outside
+    /// 0000 - 9999.
+    pub fn to_timezone(&self, timezone: &Iso8601Timezone) -> Result<Self, Error> {
+        let time = self.time.as_ref().ok_or(Error::PartialValue(Context::DateTime))?;
+        let current = time.timezone().ok_or(Error::MissingTimezone)?;
+        let shift = (timezone.offset_minutes() - current.offset_minutes()) as i128 * 60;
+        let date_time = Iso8601DateTime {
+            date: self.date.clone(),
+            time: Some(time.with_timezone(timezone.clone())),
+        };
+        Self::from_local_nanoseconds(
+            self.date.days().0 as i128 * calendar::NANOS_IN_DAY
+                + time.nanosecond_of_day()
+                + shift * calendar::NANOS_IN_SECOND,
+            &date_time,
+            0,
+        )
+    }
+
+    // ----------------------------------------------------------------------------------------------
+    //                                         private methods
+    // ----------------------------------------------------------------------------------------------
+    /// Returns a date/time with the same precision, format and time zone as `template`, set to
+    /// the given number of nanoseconds since 1970-01-01T00:00 local time.
+    fn from_local_nanoseconds(
+        nanoseconds: i128,
+        template: &Iso8601DateTime,
+        fraction_digits: usize,
+    ) -> Result<Self, Error> {
+        let days = i64::try_from(nanoseconds.div_euclid(calendar::NANOS_IN_DAY))
+            .map_err(|_| Error::OutOfRange(Context::Year))?;
+        let date = Iso8601Date::from_days(days, template.date.is_extended())?;
+        let time = template.time.as_ref().map(|time| {
+            time.with_nanosecond_of_day(
+                nanoseconds.rem_euclid(calendar::NANOS_IN_DAY),
+                fraction_digits,
+            )
+        });
+        Ok(Iso8601DateTime { date, time })
+    }
+
+    fn span(&self) -> Span {
+        match &self.time {
+            Some(time) => time.span().shift(self.date.days().0 as i128 * calendar::NANOS_IN_DAY),
+            None => self.date.span(),
+        }
+    }
+
+}
+
+impl Any for Iso8601DateTime {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn type_of(&self) -> String {
+        "Iso8601DateTime".to_string()
+    }
+
+}
+
+impl Temporal for Iso8601DateTime {
+}
+
+impl Iso8601Type for Iso8601DateTime {
+
+    fn value(&self) -> String {
+        self.to_string()
+    }
+
+    fn is_partial(&self) -> bool {
+        self.second_unknown()
+    }
+
+    fn is_extended(&self) -> bool {
+        self.date.is_extended()
+    }
+
+}
+
+impl Ordered for Iso8601DateTime {
+}
+
+/// Date/times are equal if they denote the same instant with the same precision, so
+/// `2024-03-15T00:30+01:00` equals `2024-03-14T23:30Z`.
+impl PartialEq for Iso8601DateTime {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.span() == other.span()
+    }
+
+}
+
+impl Eq for Iso8601DateTime {
+}
+
+/// Date/times are compared by the instants they cover, taking time zones into account. A
+/// partial date/time is only ordered with respect to another date/time if they do not overlap:
+/// `2024-03-14` is before `2024-03-15T10`, but `2024-03-15` and `2024-03-15T10` are not ordered.
+/// A date/time without a time zone is only ordered with respect to a date/time with a time zone
+/// if they are more than 14 hours apart.
+impl PartialOrd for Iso8601DateTime {
+
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.span().compare(&other.span())
+    }
+
+}
+
+impl Add<Iso8601Duration> for Iso8601DateTime {
+    type Output = Result<Iso8601DateTime, Error>;
+
+    fn add(self, duration: Iso8601Duration) -> Result<Iso8601DateTime, Error> {
+        self.add_duration(&duration)
+    }
+
+}
+
+impl Sub<Iso8601Duration> for Iso8601DateTime {
+    type Output = Result<Iso8601DateTime, Error>;
+
+    fn sub(self, duration: Iso8601Duration) -> Result<Iso8601DateTime, Error> {
+        self.subtract_duration(&duration)
+    }
+
+}
+
+impl std::fmt::Display for Iso8601DateTime {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.date)?;
+        if let Some(time) = &self.time {
+            write!(f, "T{}", time)?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<&'_ str> for Iso8601DateTime {
+    type Error = Error;
+
+    fn try_from(date_time_string: &'_ str) -> Result<Self, Self::Error> {
+        Iso8601DateTime::parse(date_time_string)
+    }
+}
@@ -0,0 +1,397 @@
+use std::cmp::Ordering;
+use std::ops::Neg;
+use crate::foundation_types::{Any, Double, Integer, Ordered};
+use super::{
+    context::Context,
+    error::Error,
+    parser,
+    calendar,
+    Iso8601Type,
+    Temporal,
+    TimeDefinitions,
+};
+
+/// An ISO 8601 duration in the designator format `PnYnMnWnDTnHnMnS`, such as `P1Y2M10DT2H30M`
+/// or `PT0.5S`. A leading minus sign (`-P1D`) denotes a negative duration. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_iso8601_duration_class)
+#[derive(Clone, Debug)]
+pub struct Iso8601Duration {
+    negative: bool,
+    years: u32,
+    months: u32,
+    weeks: u32,
+    days: u32,
+    hours: u32,
+    minutes: u32,
+    seconds: u32,
+    nanosecond: Option<u32>,
+    fraction_digits: usize,
+}
+
+impl Iso8601Duration {
+
+    /// Creates a positive duration from its components.
+    pub fn new(
+        years: u32,
+        months: u32,
+        weeks: u32,
+        days: u32,
+        hours: u32,
+        minutes: u32,
+        seconds: u32,
+    ) -> Self {
+        Iso8601Duration {
+            negative: false,
+            years,
+            months,
+            weeks,
+            days,
+            hours,
+            minutes,
+            seconds,
+            nanosecond: None,
+            fraction_digits: 0,
+        }
+    }
+
+    /// Interpret the given string as an ISO 8601 duration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidFormat`] if the string is not a duration in the designator format
+    /// and [`Error::OutOfRange`] if a component does not fit in 32 bits.
+    pub fn parse<T>(duration_string: T) -> Result<Self, Error>
+    where
+        T: AsRef<str>,
+    {
+        let s = duration_string.as_ref();
+        let (negative, s) = match s.strip_prefix('-') {
+            Some(s) => (true, s),
+            None => (false, s),
+        };
+        let s = s.strip_prefix('P').ok_or(Error::InvalidFormat(Context::Duration))?;
+        let (date_part, time_part) = match s.find('T') {
+            Some(time_start) => (&s[..time_start], Some(&s[time_start + 1..])),
+            None => (s, None),
+        };
+        let mut duration = Self::new(0, 0, 0, 0, 0, 0, 0);
+        duration.negative = negative;
+        let mut components = 0;
+        let mut remaining = date_part;
+        for designator in ['Y', 'M', 'W', 'D'] {
+            if let Some((number, rest)) = Self::component(remaining, designator)? {
+                match designator {
+                    'Y' => duration.years = number,
+                    'M' => duration.months = number,
+                    'W' => duration.weeks = number,
+                    _ => duration.days = number,
+                }
+                components += 1;
+                remaining = rest;
+            }
+        }
+        if !remaining.is_empty() {
+            return Err(Error::InvalidFormat(Context::Duration));
+        }
+        if let Some(time_part) = time_part {
+            let mut remaining = time_part;
+            let time_components = components;
+            for designator in ['H', 'M'] {
+                if let Some((number, rest)) = Self::component(remaining, designator)? {
+                    match designator {
+                        'H' => duration.hours = number,
+                        _ => duration.minutes = number,
+                    }
+                    components += 1;
+                    remaining = rest;
+                }
+            }
+            if let Some(seconds_end) = remaining.find('S') {
+                let seconds = &remaining[..seconds_end];
+                let (seconds, fraction) = match seconds.find(['.', ',']) {
+                    Some(fraction_start) => (
+                        &seconds[..fraction_start],
+                        Some(parser::fraction(&seconds[fraction_start + 1..])?),
+                    ),
+                    None => (seconds, None),
+                };
+                duration.seconds = parser::number(seconds, Context::Second)?;
+                duration.nanosecond = fraction.map(|(nanosecond, _)| nanosecond);
+                duration.fraction_digits = fraction.map_or(0, |(_, digits)| digits);
+                components += 1;
+                remaining = &remaining[seconds_end + 1..];
+            }
+            if !remaining.is_empty() || components == time_components {
+                return Err(Error::InvalidFormat(Context::Duration));
+            }
+        }
+        if components == 0 {
+            return Err(Error::InvalidFormat(Context::Duration));
+        }
+        duration.negative = duration.negative && !duration.is_zero();
+        Ok(duration)
+    }
+
+    /// Returns the number of years in the duration.
+    pub fn years(&self) -> Integer {
+        Integer::new(self.years as i32)
+    }
+
+    /// Returns the number of months in the duration.
+    pub fn months(&self) -> Integer {
+        Integer::new(self.months as i32)
+    }
+
+    /// Returns the number of weeks in the duration.
+    pub fn weeks(&self) -> Integer {
+        Integer::new(self.weeks as i32)
+    }
+
+    /// Returns the number of days in the duration.
+    pub fn days(&self) -> Integer {
+        Integer::new(self.days as i32)
+    }
+
+    /// Returns the number of hours in the duration.
+    pub fn hours(&self) -> Integer {
+        Integer::new(self.hours as i32)
+    }
+
+    /// Returns the number of minutes in the duration.
+    pub fn minutes(&self) -> Integer {
+        Integer::new(self.minutes as i32)
+    }
+
+    /// Returns the number of whole seconds in the duration.
+    pub fn seconds(&self) -> Integer {
+        Integer::new(self.seconds as i32)
+    }
+
+    /// Returns the fractional second part of the duration, if present.
+    pub fn fractional_second(&self) -> Option<Double> {
+        self.nanosecond.map(|nanosecond| Double::new(nanosecond as f64 / 1_000_000_000.0))
+    }
+
+    /// Returns true if the duration is negative.
+    pub fn is_negative(&self) -> bool {
+        self.negative
+    }
+
+    /// Returns the total number of seconds in the duration, using the average number of days in
+    /// a year and a month for the year and month components.
+    pub fn to_seconds(&self) -> Double {
+        let days = self.years as f64 * TimeDefinitions::AVERAGE_DAYS_IN_YEAR
+            + self.months as f64 * TimeDefinitions::AVERAGE_DAYS_IN_MONTH
+            + self.weeks as f64 * TimeDefinitions::DAYS_IN_WEEK as f64
+            + self.days as f64;
+        let seconds = days * 86_400.0
+            + self.hours as f64 * 3_600.0
+            + self.minutes as f64 * 60.0
+            + self.seconds as f64
+            + self.nanosecond.unwrap_or(0) as f64 / 1_000_000_000.0;
+        Double::new(if self.negative { -seconds } else { seconds })
+    }
+
+    // ----------------------------------------------------------------------------------------------
+    //                                         private methods
+    // ----------------------------------------------------------------------------------------------
+    /// Creates a duration of days, hours, minutes and (fractional) seconds from a signed number
+    /// of nanoseconds.
+    pub(super) fn from_nanoseconds(nanoseconds: i128) -> Self {
+        let magnitude = nanoseconds.unsigned_abs();
+        let seconds = magnitude / calendar::NANOS_IN_SECOND as u128;
+        let nanosecond = (magnitude % calendar::NANOS_IN_SECOND as u128) as u32;
+        let mut duration = Self::new(
+            0,
+            0,
+            0,
+            (seconds / 86_400) as u32,
+            (seconds % 86_400 / 3_600) as u32,
+            (seconds % 3_600 / 60) as u32,
+            (seconds % 60) as u32,
+        );
+        if nanosecond > 0 {
+            duration.nanosecond = Some(nanosecond);
+            duration.fraction_digits = parser::significant_fraction_digits(nanosecond);
+        }
+        duration.negative = nanoseconds < 0;
+        duration
+    }
+
+    /// Returns the signed number of months in the years and months components, whose length in
+    /// days depends on the date they are applied to.
+    pub(super) fn nominal_months(&self) -> i64 {
+        let months = self.years as i64 * 12 + self.months as i64;
+        if self.negative { -months } else { months }
+    }
+
+    /// Returns true if any of the years, months, weeks and days components is not zero.
+    pub(super) fn has_date_part(&self) -> bool {
+        self.years != 0 || self.months != 0 || self.weeks != 0 || self.days != 0
+    }
+
+    /// Returns the signed number of nanoseconds in the weeks, days, hours, minutes and seconds
+    /// components.
+    pub(super) fn exact_nanoseconds(&self) -> i128 {
+        let seconds = (self.weeks as i128 * 7 + self.days as i128) * 86_400
+            + self.hours as i128 * 3_600
+            + self.minutes as i128 * 60
+            + self.seconds as i128;
+        let nanoseconds = seconds * calendar::NANOS_IN_SECOND + self.nanosecond.unwrap_or(0) as i128;
+        if self.negative { -nanoseconds } else { nanoseconds }
+    }
+
+    pub(super) fn fraction_digits(&self) -> usize {
+        self.fraction_digits
+    }
+
+    fn component(s: &str, designator: char) -> Result<Option<(u32, &str)>, Error> {
+        let digits = s.bytes().take_while(u8::is_ascii_digit).count();
+        if digits == 0 || !s[digits..].starts_with(designator) {
+            return Ok(None);
+        }
+        let number = parser::number(&s[..digits], Context::Duration)?;
+        Ok(Some((number, &s[digits + 1..])))
+    }
+
+    fn is_zero(&self) -> bool {
+        self.parts()[1..].iter().all(|part| *part == 0)
+    }
+
+    fn parts(&self) -> [u32; 9] {
+        [
+            self.negative as u32,
+            self.years,
+            self.months,
+            self.weeks,
+            self.days,
+            self.hours,
+            self.minutes,
+            self.seconds,
+            self.nanosecond.unwrap_or(0),
+        ]
+    }
+
+}
+
+impl Any for Iso8601Duration {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn type_of(&self) -> String {
+        "Iso8601Duration".to_string()
+    }
+
+}
+
+impl Temporal for Iso8601Duration {
+}
+
+impl Iso8601Type for Iso8601Duration {
+
+    fn value(&self) -> String {
+        self.to_string()
+    }
+
+    /// Every component of a duration is optional, so a duration is never partial.
+    fn is_partial(&self) -> bool {
+        false
+    }
+
+    /// Durations only have the designator format, which is valid in both the basic and the
+    /// extended format.
+    fn is_extended(&self) -> bool {
+        true
+    }
+
+}
+
+impl Ordered for Iso8601Duration {
+}
+
+impl PartialEq for Iso8601Duration {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.parts() == other.parts()
+    }
+
+}
+
+impl Eq for Iso8601Duration {
+}
+
+impl PartialOrd for Iso8601Duration {
+
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+
+}
+
+impl Ord for Iso8601Duration {
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.to_seconds()
+            .cmp(&other.to_seconds())
+            .then_with(|| self.parts().cmp(&other.parts()))
+    }
+
+}
+
+impl Neg for Iso8601Duration {
+    type Output = Iso8601Duration;
+
+    fn neg(self) -> Iso8601Duration {
+        let mut duration = self;
+        duration.negative = !duration.negative && !duration.is_zero();
+        duration
+    }
+
+}
+
+impl std::fmt::Display for Iso8601Duration {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.negative {
+            write!(f, "-")?;
+        }
+        write!(f, "P")?;
+        for (number, designator) in [
+            (self.years, 'Y'),
+            (self.months, 'M'),
+            (self.weeks, 'W'),
+            (self.days, 'D'),
+        ] {
+            if number > 0 {
+                write!(f, "{}{}", number, designator)?;
+            }
+        }
+        let has_seconds = self.seconds > 0 || self.nanosecond.is_some();
+        if self.hours > 0 || self.minutes > 0 || has_seconds || self.is_zero() {
+            write!(f, "T")?;
+            if self.hours > 0 {
+                write!(f, "{}H", self.hours)?;
+            }
+            if self.minutes > 0 {
+                write!(f, "{}M", self.minutes)?;
+            }
+            if has_seconds || self.is_zero() {
+                write!(f, "{}", self.seconds)?;
+                if let Some(nanosecond) = self.nanosecond {
+                    write!(f, ".{}", parser::format_fraction(nanosecond, self.fraction_digits))?;
+                }
+                write!(f, "S")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<&'_ str> for Iso8601Duration {
+    type Error = Error;
+
+    fn try_from(duration_string: &'_ str) -> Result<Self, Self::Error> {
+        Iso8601Duration::parse(duration_string)
+    }
+}
@@ -0,0 +1,381 @@
+use std::cmp::Ordering;
+use std::ops::{Add, Sub};
+use crate::foundation_types::{Any, Double, Integer, Ordered};
+use super::{
+    context::Context,
+    error::Error,
+    parser,
+    calendar,
+    span::Span,
+    Iso8601Duration,
+    Iso8601Timezone,
+    Iso8601Type,
+    Temporal,
+    TimeDefinitions,
+};
+
+/// An ISO 8601 time of day, optionally followed by a time zone. The time may be partial, i.e.
+/// only contain an hour (`10`) or an hour and a minute (`10:15`), and may be prefixed by the `T`
+/// designator (`T10`). Both the extended (`10:15:30.250+01:00`) and basic (`101530.250+0100`)
+/// formats are supported. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_iso8601_time_class)
+#[derive(Clone, Debug)]
+pub struct Iso8601Time {
+    hour: u32,
+    minute: Option<u32>,
+    second: Option<u32>,
+    nanosecond: Option<u32>,
+    fraction_digits: usize,
+    timezone: Option<Iso8601Timezone>,
+    extended: bool,
+}
+
+impl Iso8601Time {
+
+    /// Creates a complete time without fractional seconds or time zone.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfRange`] if the hour, minute or second is out of range.
+    pub fn new(hour: u32, minute: u32, second: u32) -> Result<Self, Error> {
+        Self::from_parts(hour, Some(minute), Some(second), None, None)
+    }
+
+    /// Interpret the given string as an ISO 8601 time. Accepted forms are `hh`, `hh:mm`,
+    /// `hh:mm:ss`, `hhmm` and `hhmmss`, optionally prefixed by `T`, with an optional fractional
+    /// second (`.sss` or `,sss`) and an optional time zone.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidFormat`] if the string is not a time in one of the accepted forms,
+    /// [`Error::OutOfRange`] if a component is out of range and [`Error::InconsistentFormat`] if
+    /// the basic and extended formats are mixed.
+    pub fn parse<T>(time_string: T) -> Result<Self, Error>
+    where
+        T: AsRef<str>,
+    {
+        let s = time_string.as_ref();
+        if !s.is_ascii() {
+            return Err(Error::InvalidFormat(Context::Time));
+        }
+        let s = s.strip_prefix('T').unwrap_or(s);
+        let (body, timezone) = match s.find(['Z', '+', '-']) {
+            Some(timezone_start) => (
+                &s[..timezone_start],
+                Some(Iso8601Timezone::parse(&s[timezone_start..])?),
+            ),
+            None => (s, None),
+        };
+        let (body, fraction) = match body.find(['.', ',']) {
+            Some(fraction_start) => (
+                &body[..fraction_start],
+                Some(parser::fraction(&body[fraction_start + 1..])?),
+            ),
+            None => (body, None),
+        };
+        let extended = body.contains(':');
+        let parts: Vec<&str> = if extended {
+            body.split(':').collect()
+        } else {
+            match body.len() {
+                2 => vec![body],
+                4 => vec![&body[0..2], &body[2..4]],
+                6 => vec![&body[0..2], &body[2..4], &body[4..6]],
+                _ => return Err(Error::InvalidFormat(Context::Time)),
+            }
+        };
+        if parts.len() > 3 || (fraction.is_some() && parts.len() != 3) {
+            return Err(Error::InvalidFormat(Context::Time));
+        }
+        if let Some(timezone) = &timezone {
+            if parts.len() > 1 && timezone.minute().is_some() && timezone.is_extended() != extended {
+                return Err(Error::InconsistentFormat);
+            }
+        }
+        let mut time = Self::from_parts(
+            parser::two_digits(parts[0], 0, 23, Context::Hour)?,
+            parts.get(1).map(|minute| parser::two_digits(minute, 0, 59, Context::Minute)).transpose()?,
+            parts.get(2).map(|second| parser::two_digits(second, 0, 59, Context::Second)).transpose()?,
+            fraction,
+            timezone,
+        )?;
+        time.extended = extended || parts.len() == 1;
+        Ok(time)
+    }
+
+    /// Returns the hour part of the time.
+    pub fn hour(&self) -> Integer {
+        Integer::new(self.hour as i32)
+    }
+
+    /// Returns the minute part of the time, if present.
+    pub fn minute(&self) -> Option<Integer> {
+        self.minute.map(|minute| Integer::new(minute as i32))
+    }
+
+    /// Returns the second part of the time, if present.
+    pub fn second(&self) -> Option<Integer> {
+        self.second.map(|second| Integer::new(second as i32))
+    }
+
+    /// Returns the fractional second part of the time, such as 0.25 for `10:15:30.25`, if
+    /// present.
+    pub fn fractional_second(&self) -> Option<Double> {
+        self.nanosecond.map(|nanosecond| Double::new(nanosecond as f64 / 1_000_000_000.0))
+    }
+
+    /// Returns true if the minute, and therefore also the second, is unknown.
+    pub fn minute_unknown(&self) -> bool {
+        self.minute.is_none()
+    }
+
+    /// Returns true if the second is unknown.
+    pub fn second_unknown(&self) -> bool {
+        self.second.is_none()
+    }
+
+    /// Returns true if the time has a fractional second part.
+    pub fn has_fractional_second(&self) -> bool {
+        self.nanosecond.is_some()
+    }
+
+    /// Returns the time zone of the time, if present.
+    pub fn timezone(&self) -> Option<&Iso8601Timezone> {
+        self.timezone.as_ref()
+    }
+
+    /// Adds the hours, minutes and seconds of a duration to the time, wrapping around midnight.
+    /// A partial time keeps its precision, so `10` plus `PT2H` is `12`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::UnsupportedDuration`] if the duration has years, months, weeks or days,
+    /// since a time has no date to carry them into, and [`Error::ImpreciseDuration`] if the
+    /// duration is more precise than a partial time, such as `PT30M` added to `10`.
+    pub fn add_duration(&self, duration: &Iso8601Duration) -> Result<Self, Error> {
+        if duration.has_date_part() {
+            return Err(Error::UnsupportedDuration(Context::Time));
+        }
+        let nanoseconds = duration.exact_nanoseconds();
+        if nanoseconds % self.precision_nanoseconds() != 0 {
+            return Err(Error::ImpreciseDuration(Context::Time));
+        }
+        Ok(self.with_nanosecond_of_day(
+            (self.nanosecond_of_day() + nanoseconds).rem_euclid(calendar::NANOS_IN_DAY),
+            duration.fraction_digits(),
+        ))
+    }
+
+    /// Subtracts a duration from the time, wrapping around midnight. See
+    /// [`add_duration`](#method.add_duration).
+    ///
+    /// # Errors
+    ///
+    /// Returns the errors of [`add_duration`](#method.add_duration).
+    pub fn subtract_duration(&self, duration: &Iso8601Duration) -> Result<Self, Error> {
+        self.add_duration(&-duration.clone())
+    }
+
+    // ----------------------------------------------------------------------------------------------
+    //                                         private methods
+    // ----------------------------------------------------------------------------------------------
+    pub(super) fn from_parts(
+        hour: u32,
+        minute: Option<u32>,
+        second: Option<u32>,
+        fraction: Option<(u32, usize)>,
+        timezone: Option<Iso8601Timezone>,
+    ) -> Result<Self, Error> {
+        if !TimeDefinitions::valid_hour(hour) {
+            return Err(Error::OutOfRange(Context::Hour));
+        }
+        if minute.is_some_and(|minute| !TimeDefinitions::valid_minute(minute)) {
+            return Err(Error::OutOfRange(Context::Minute));
+        }
+        if second.is_some_and(|second| !TimeDefinitions::valid_second(second)) {
+            return Err(Error::OutOfRange(Context::Second));
+        }
+        if (minute.is_none() && second.is_some()) || (second.is_none() && fraction.is_some()) {
+            return Err(Error::InvalidFormat(Context::Time));
+        }
+        Ok(Iso8601Time {
+            hour,
+            minute,
+            second,
+            nanosecond: fraction.map(|(nanosecond, _)| nanosecond),
+            fraction_digits: fraction.map_or(0, |(_, digits)| digits),
+            timezone,
+            extended: true,
+        })
+    }
+
+    /// Returns the number of nanoseconds in the smallest unit of the time: an hour for `10`, a
+    /// minute for `10:15` and a nanosecond once the second is known, since a fraction can be
+    /// added to it.
+    pub(super) fn precision_nanoseconds(&self) -> i128 {
+        match (self.minute, self.second) {
+            (None, _) => 3_600 * calendar::NANOS_IN_SECOND,
+            (Some(_), None) => 60 * calendar::NANOS_IN_SECOND,
+            (Some(_), Some(_)) => 1,
+        }
+    }
+
+    /// Returns the number of nanoseconds since local midnight, ignoring the time zone.
+    pub(super) fn nanosecond_of_day(&self) -> i128 {
+        let seconds = self.hour * 3_600 + self.minute.unwrap_or(0) * 60 + self.second.unwrap_or(0);
+        seconds as i128 * calendar::NANOS_IN_SECOND + self.nanosecond.unwrap_or(0) as i128
+    }
+
+    /// Returns a time with the same precision, format and time zone as this time, set to the
+    /// given number of nanoseconds since local midnight.
+    pub(super) fn with_nanosecond_of_day(
+        &self,
+        nanosecond_of_day: i128,
+        fraction_digits: usize,
+    ) -> Self {
+        let seconds = (nanosecond_of_day / calendar::NANOS_IN_SECOND) as u32;
+        let nanosecond = (nanosecond_of_day % calendar::NANOS_IN_SECOND) as u32;
+        let has_fraction = self.second.is_some() && (self.nanosecond.is_some() || nanosecond > 0);
+        Iso8601Time {
+            hour: seconds / 3_600,
+            minute: self.minute.map(|_| seconds % 3_600 / 60),
+            second: self.second.map(|_| seconds % 60),
+            nanosecond: has_fraction.then_some(nanosecond),
+            fraction_digits: if has_fraction {
+                self.fraction_digits
+                    .max(fraction_digits)
+                    .max(parser::significant_fraction_digits(nanosecond))
+            } else {
+                0
+            },
+            timezone: self.timezone.clone(),
+            extended: self.extended,
+        }
+    }
+
+    pub(super) fn with_timezone(&self, timezone: Iso8601Timezone) -> Self {
+        Iso8601Time {
+            timezone: Some(timezone),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the instants, in nanoseconds since midnight UTC if the time has a time zone or
+    /// since local midnight otherwise, that this time covers.
+    pub(super) fn span(&self) -> Span {
+        let offset = self.timezone.as_ref().map_or(0, Iso8601Timezone::offset_minutes);
+        let start = self.nanosecond_of_day() - offset as i128 * 60 * calendar::NANOS_IN_SECOND;
+        let length = match (self.minute, self.second) {
+            (None, _) => 3_600 * calendar::NANOS_IN_SECOND,
+            (Some(_), None) => 60 * calendar::NANOS_IN_SECOND,
+            _ => 1,
+        };
+        Span::new(start, length, self.timezone.is_some())
+    }
+
+}
+
+impl Any for Iso8601Time {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn type_of(&self) -> String {
+        "Iso8601Time".to_string()
+    }
+
+}
+
+impl Temporal for Iso8601Time {
+}
+
+impl Iso8601Type for Iso8601Time {
+
+    fn value(&self) -> String {
+        self.to_string()
+    }
+
+    fn is_partial(&self) -> bool {
+        self.second.is_none()
+    }
+
+    fn is_extended(&self) -> bool {
+        self.extended
+    }
+
+}
+
+impl Ordered for Iso8601Time {
+}
+
+/// Times are equal if they denote the same instant with the same precision, so `10:00+01:00`
+/// equals `09:00Z`.
+impl PartialEq for Iso8601Time {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.span() == other.span()
+    }
+
+}
+
+impl Eq for Iso8601Time {
+}
+
+/// Times are compared by the instants they cover, taking time zones into account. A partial
+/// time is only ordered with respect to another time if they do not overlap: `09` is before
+/// `10:15`, but `10` and `10:15` are not ordered. A time without a time zone is only ordered
+/// with respect to a time with a time zone if they are more than 14 hours apart.
+impl PartialOrd for Iso8601Time {
+
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.span().compare(&other.span())
+    }
+
+}
+
+impl Add<Iso8601Duration> for Iso8601Time {
+    type Output = Result<Iso8601Time, Error>;
+
+    fn add(self, duration: Iso8601Duration) -> Result<Iso8601Time, Error> {
+        self.add_duration(&duration)
+    }
+
+}
+
+impl Sub<Iso8601Duration> for Iso8601Time {
+    type Output = Result<Iso8601Time, Error>;
+
+    fn sub(self, duration: Iso8601Duration) -> Result<Iso8601Time, Error> {
+        self.subtract_duration(&duration)
+    }
+
+}
+
+impl std::fmt::Display for Iso8601Time {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let separator = if self.extended { ":" } else { "" };
+        write!(f, "{:02}", self.hour)?;
+        if let Some(minute) = self.minute {
+            write!(f, "{}{:02}", separator, minute)?;
+        }
+        if let Some(second) = self.second {
+            write!(f, "{}{:02}", separator, second)?;
+        }
+        if let Some(nanosecond) = self.nanosecond {
+            write!(f, ".{}", parser::format_fraction(nanosecond, self.fraction_digits))?;
+        }
+        if let Some(timezone) = &self.timezone {
+            write!(f, "{}", timezone)?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<&'_ str> for Iso8601Time {
+    type Error = Error;
+
+    fn try_from(time_string: &'_ str) -> Result<Self, Self::Error> {
+        Iso8601Time::parse(time_string)
+    }
+}
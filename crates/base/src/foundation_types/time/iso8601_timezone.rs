@@ -0,0 +1,208 @@
+use std::cmp::Ordering;
+use crate::foundation_types::{Any, Integer, Ordered};
+use super::{
+    context::Context,
+    error::Error,
+    parser,
+    Iso8601Type,
+    Temporal,
+};
+
+/// An ISO 8601 time zone offset, such as `Z`, `+01:00`, `-0330` or `+05`. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_iso8601_timezone_class)
+#[derive(Clone, Debug)]
+pub struct Iso8601Timezone {
+    negative: bool,
+    hour: u32,
+    minute: Option<u32>,
+    gmt: bool,
+    extended: bool,
+}
+
+impl Iso8601Timezone {
+
+    /// Returns the `Z` (UTC) time zone.
+    pub fn utc() -> Self {
+        Iso8601Timezone {
+            negative: false,
+            hour: 0,
+            minute: None,
+            gmt: true,
+            extended: true,
+        }
+    }
+
+    /// Creates a time zone from an offset in minutes east of UTC.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfRange`] if the offset is 24 hours or more.
+    pub fn from_offset_minutes(offset: i32) -> Result<Self, Error> {
+        let magnitude = offset.unsigned_abs();
+        if magnitude >= 24 * 60 {
+            return Err(Error::OutOfRange(Context::Timezone));
+        }
+        Ok(Iso8601Timezone {
+            negative: offset < 0,
+            hour: magnitude / 60,
+            minute: Some(magnitude % 60),
+            gmt: false,
+            extended: true,
+        })
+    }
+
+    /// Interpret the given string as an ISO 8601 time zone.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidFormat`] if the string is not one of `Z`, `±hh`, `±hhmm` or
+    /// `±hh:mm`, and [`Error::OutOfRange`] if the hour or minute is out of range.
+    pub fn parse<T>(timezone_string: T) -> Result<Self, Error>
+    where
+        T: AsRef<str>,
+    {
+        let s = timezone_string.as_ref();
+        if s == "Z" {
+            return Ok(Self::utc());
+        }
+        let negative = match s.as_bytes().first() {
+            Some(b'+') => false,
+            Some(b'-') => true,
+            _ => return Err(Error::InvalidFormat(Context::Timezone)),
+        };
+        let rest = &s[1..];
+        if !rest.is_ascii() {
+            return Err(Error::InvalidFormat(Context::Timezone));
+        }
+        let (hour, minute, extended) = match rest.len() {
+            2 => (rest, None, true),
+            4 => (&rest[0..2], Some(&rest[2..4]), false),
+            5 if rest.as_bytes()[2] == b':' => (&rest[0..2], Some(&rest[3..5]), true),
+            _ => return Err(Error::InvalidFormat(Context::Timezone)),
+        };
+        Ok(Iso8601Timezone {
+            negative,
+            hour: parser::two_digits(hour, 0, 23, Context::Hour)?,
+            minute: minute
+                .map(|minute| parser::two_digits(minute, 0, 59, Context::Minute))
+                .transpose()?,
+            gmt: false,
+            extended,
+        })
+    }
+
+    /// Returns the hour part of the offset.
+    pub fn hour(&self) -> Integer {
+        Integer::new(self.hour as i32)
+    }
+
+    /// Returns the minute part of the offset, if present.
+    pub fn minute(&self) -> Option<Integer> {
+        self.minute.map(|minute| Integer::new(minute as i32))
+    }
+
+    /// Returns the direction of the offset: +1 for east of UTC, -1 for west of UTC.
+    pub fn sign(&self) -> Integer {
+        Integer::new(if self.negative { -1 } else { 1 })
+    }
+
+    /// Returns true if the minute part of the offset is unknown, as in `+05`.
+    pub fn minute_unknown(&self) -> bool {
+        !self.gmt && self.minute.is_none()
+    }
+
+    /// Returns true if this is the `Z` (UTC) time zone.
+    pub fn is_gmt(&self) -> bool {
+        self.gmt
+    }
+
+    /// Returns the offset from UTC in minutes; positive for east of UTC.
+    pub fn offset_minutes(&self) -> i32 {
+        let minutes = (self.hour * 60 + self.minute.unwrap_or(0)) as i32;
+        if self.negative { -minutes } else { minutes }
+    }
+
+}
+
+impl Any for Iso8601Timezone {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    fn type_of(&self) -> String {
+        "Iso8601Timezone".to_string()
+    }
+
+}
+
+impl Temporal for Iso8601Timezone {
+}
+
+impl Iso8601Type for Iso8601Timezone {
+
+    fn value(&self) -> String {
+        self.to_string()
+    }
+
+    fn is_partial(&self) -> bool {
+        self.minute_unknown()
+    }
+
+    fn is_extended(&self) -> bool {
+        self.extended
+    }
+
+}
+
+impl Ordered for Iso8601Timezone {
+}
+
+impl PartialEq for Iso8601Timezone {
+
+    fn eq(&self, other: &Self) -> bool {
+        self.offset_minutes() == other.offset_minutes()
+    }
+
+}
+
+impl Eq for Iso8601Timezone {
+}
+
+impl PartialOrd for Iso8601Timezone {
+
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+
+}
+
+impl Ord for Iso8601Timezone {
+
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.offset_minutes().cmp(&other.offset_minutes())
+    }
+
+}
+
+impl std::fmt::Display for Iso8601Timezone {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.gmt {
+            return write!(f, "Z");
+        }
+        write!(f, "{}{:02}", if self.negative { '-' } else { '+' }, self.hour)?;
+        match self.minute {
+            Some(minute) if self.extended => write!(f, ":{:02}", minute),
+            Some(minute) => write!(f, "{:02}", minute),
+            None => Ok(()),
+        }
+    }
+}
+
+impl TryFrom<&'_ str> for Iso8601Timezone {
+    type Error = Error;
+
+    fn try_from(timezone_string: &'_ str) -> Result<Self, Self::Error> {
+        Iso8601Timezone::parse(timezone_string)
+    }
+}
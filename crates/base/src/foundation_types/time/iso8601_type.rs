@@ -0,0 +1,18 @@
+use super::Temporal;
+
+/// This trait is the base of all the ISO 8601 date/time types. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_iso8601_type_class)
+pub trait Iso8601Type: Temporal {
+
+    /// Returns the ISO 8601 string representation of the value.
+    fn value(&self) -> String;
+
+    /// Returns true if the value is partial, i.e. if one or more of its smaller components, such
+    /// as the day of a date or the seconds of a time, are unknown.
+    fn is_partial(&self) -> bool;
+
+    /// Returns true if the value is written in the extended format, i.e. with the `-` and `:`
+    /// separators.
+    fn is_extended(&self) -> bool;
+
+}
@@ -0,0 +1,26 @@
+mod context;
+mod error;
+mod parser;
+mod calendar;
+mod span;
+mod time_definitions;
+mod temporal;
+mod iso8601_type;
+mod iso8601_timezone;
+mod iso8601_date;
+mod iso8601_time;
+mod iso8601_date_time;
+mod iso8601_duration;
+
+pub use {
+    context::Context,
+    error::Error,
+    time_definitions::TimeDefinitions,
+    temporal::Temporal,
+    iso8601_type::Iso8601Type,
+    iso8601_timezone::Iso8601Timezone,
+    iso8601_date::Iso8601Date,
+    iso8601_time::Iso8601Time,
+    iso8601_date_time::Iso8601DateTime,
+    iso8601_duration::Iso8601Duration,
+};
@@ -0,0 +1,50 @@
+use super::{context::Context, error::Error};
+
+/// Parses a string that must consist of ASCII digits only into a number.
+pub fn number(digits: &str, context: Context) -> Result<u32, Error> {
+    if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(Error::InvalidFormat(context));
+    }
+    digits.parse::<u32>().map_err(|_| Error::OutOfRange(context))
+}
+
+/// Parses a two digit component, such as a month, an hour or a minute, and checks that it lies
+/// in the given range.
+pub fn two_digits(digits: &str, min: u32, max: u32, context: Context) -> Result<u32, Error> {
+    if digits.len() != 2 {
+        return Err(Error::InvalidFormat(context));
+    }
+    let value = number(digits, context)?;
+    if value < min || value > max {
+        return Err(Error::OutOfRange(context));
+    }
+    Ok(value)
+}
+
+/// Parses the digits of a fractional second into nanoseconds, returning the nanoseconds together
+/// with the number of digits, so that the fraction can be written back with the same precision.
+pub fn fraction(digits: &str) -> Result<(u32, usize), Error> {
+    if digits.len() > 9 {
+        return Err(Error::OutOfRange(Context::FractionalSecond));
+    }
+    let value = number(digits, Context::FractionalSecond)?;
+    Ok((value * 10u32.pow((9 - digits.len()) as u32), digits.len()))
+}
+
+/// Writes nanoseconds as a fractional second with the given number of digits.
+pub fn format_fraction(nanosecond: u32, digits: usize) -> String {
+    let digits = digits.clamp(1, 9);
+    let fraction = format!("{:09}", nanosecond);
+    fraction[..digits].to_string()
+}
+
+/// Returns the smallest number of digits needed to write nanoseconds as a fractional second.
+pub fn significant_fraction_digits(nanosecond: u32) -> usize {
+    let mut digits = 9;
+    let mut nanosecond = nanosecond;
+    while digits > 1 && nanosecond.is_multiple_of(10) {
+        nanosecond /= 10;
+        digits -= 1;
+    }
+    digits
+}
@@ -0,0 +1,69 @@
+use std::cmp::Ordering;
+
+/// The largest time zone offset in use, which is the uncertainty of a value without a time zone
+/// when it is compared with a value that has one.
+const MAX_TIMEZONE_OFFSET: i128 = 14 * 3_600 * super::calendar::NANOS_IN_SECOND;
+
+/// The closed range of instants, in nanoseconds, that a possibly partial date/time value covers.
+/// A complete value covers a single instant, while a partial value such as `2024-03` covers all
+/// the instants it could stand for.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Span {
+    start: i128,
+    end: i128,
+    zoned: bool,
+}
+
+impl Span {
+
+    pub fn new(start: i128, length: i128, zoned: bool) -> Self {
+        Span {
+            start,
+            end: start + (length - 1).max(0),
+            zoned,
+        }
+    }
+
+    /// Returns the first instant covered by the span.
+    pub fn start(&self) -> i128 {
+        self.start
+    }
+
+    /// Moves the span by the given number of nanoseconds.
+    pub fn shift(&self, nanoseconds: i128) -> Self {
+        Span {
+            start: self.start + nanoseconds,
+            end: self.end + nanoseconds,
+            zoned: self.zoned,
+        }
+    }
+
+    /// Compares two spans. The result is only determined if the spans are identical or do not
+    /// overlap; otherwise the order depends on the unknown parts and `None` is returned.
+    pub fn compare(&self, other: &Span) -> Option<Ordering> {
+        if self == other {
+            return Some(Ordering::Equal);
+        }
+        let (this, other) = match (self.zoned, other.zoned) {
+            (false, true) => (self.widen(), *other),
+            (true, false) => (*self, other.widen()),
+            _ => (*self, *other),
+        };
+        if this.end < other.start {
+            Some(Ordering::Less)
+        } else if this.start > other.end {
+            Some(Ordering::Greater)
+        } else {
+            None
+        }
+    }
+
+    fn widen(&self) -> Self {
+        Span {
+            start: self.start - MAX_TIMEZONE_OFFSET,
+            end: self.end + MAX_TIMEZONE_OFFSET,
+            zoned: self.zoned,
+        }
+    }
+
+}
@@ -0,0 +1,6 @@
+use crate::foundation_types::Ordered;
+
+/// This is the abstract parent of all the date/time types. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_temporal_class)
+pub trait Temporal: Ordered {
+}
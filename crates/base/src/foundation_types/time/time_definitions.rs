@@ -0,0 +1,66 @@
+/// Definitions used by all the date/time types. See the
+/// [openEHR specification](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html#_time_definitions_class)
+pub struct TimeDefinitions;
+
+impl TimeDefinitions {
+
+    pub const SECONDS_IN_MINUTE: u32 = 60;
+    pub const MINUTES_IN_HOUR: u32 = 60;
+    pub const HOURS_IN_DAY: u32 = 24;
+    pub const DAYS_IN_WEEK: u32 = 7;
+    pub const MONTHS_IN_YEAR: u32 = 12;
+    pub const MAX_DAYS_IN_MONTH: u32 = 31;
+    pub const MAX_DAYS_IN_YEAR: u32 = 366;
+
+    /// Average number of days in a year, used to convert durations into seconds.
+    pub const AVERAGE_DAYS_IN_YEAR: f64 = 365.24;
+
+    /// Average number of days in a month, used to convert durations into seconds.
+    pub const AVERAGE_DAYS_IN_MONTH: f64 = 30.42;
+
+    /// Returns true if the given year is a leap year in the Gregorian calendar.
+    pub fn is_leap_year(year: i32) -> bool {
+        (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+    }
+
+    /// Returns the number of days in the given month (1 - 12) of the given year.
+    pub fn days_in_month(month: u32, year: i32) -> u32 {
+        match month {
+            2 if Self::is_leap_year(year) => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        }
+    }
+
+    /// Returns true if the year is valid, i.e. if it is not negative.
+    pub fn valid_year(year: i32) -> bool {
+        year >= 0
+    }
+
+    /// Returns true if the month is valid, i.e. if it lies between 1 and 12.
+    pub fn valid_month(month: u32) -> bool {
+        (1..=Self::MONTHS_IN_YEAR).contains(&month)
+    }
+
+    /// Returns true if the day is valid for the given month and year.
+    pub fn valid_day(year: i32, month: u32, day: u32) -> bool {
+        day >= 1 && day <= Self::days_in_month(month, year)
+    }
+
+    /// Returns true if the hour is valid, i.e. if it lies between 0 and 23.
+    pub fn valid_hour(hour: u32) -> bool {
+        hour < Self::HOURS_IN_DAY
+    }
+
+    /// Returns true if the minute is valid, i.e. if it lies between 0 and 59.
+    pub fn valid_minute(minute: u32) -> bool {
+        minute < Self::MINUTES_IN_HOUR
+    }
+
+    /// Returns true if the second is valid, i.e. if it lies between 0 and 59.
+    pub fn valid_second(second: u32) -> bool {
+        second < Self::SECONDS_IN_MINUTE
+    }
+
+}
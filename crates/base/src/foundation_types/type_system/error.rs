@@ -0,0 +1,23 @@
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum Error {
+    /// The type name is not well formed, such as `List<Integer` or `Hash<,String>`.
+    #[error("invalid type name {0}")]
+    InvalidTypeName(String),
+
+    /// A type with the same name is already registered, such as `Integer`.
+    #[error("type {0} is already registered")]
+    AlreadyRegistered(String),
+
+    /// A parent refers to a type that is not registered, such as `Quantified` when only the
+    /// foundation types are known.
+    #[error("unknown type {0}")]
+    UnknownType(String),
+
+    /// A generic type is used with the wrong number of arguments, such as `List<String, Integer>`.
+    #[error("type {type_name} expects {expected} arguments but found {found}")]
+    WrongArgumentCount {
+        type_name: String,
+        expected: usize,
+        found: usize,
+    },
+}
@@ -0,0 +1,10 @@
+mod error;
+mod type_expression;
+mod type_registry;
+
+pub use {
+    error::Error,
+    type_registry::TypeRegistry,
+};
+
+pub(crate) use type_expression::TypeExpression;
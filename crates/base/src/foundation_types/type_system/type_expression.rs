@@ -0,0 +1,109 @@
+use std::fmt::{Display, Formatter};
+
+/// A parsed type name, such as `Hash<String, List<Integer>>`. Tuples are written in the openEHR
+/// notation, so `<Integer, String>` is a tuple of an `Integer` and a `String`, and have an empty
+/// name.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct TypeExpression {
+    pub(crate) name: String,
+    pub(crate) arguments: Vec<TypeExpression>,
+}
+
+impl TypeExpression {
+
+    /// Parses a type name, ignoring whitespace. Returns `None` if the name is not well formed.
+    pub(crate) fn parse(type_name: &str) -> Option<Self> {
+        let chars: Vec<char> = type_name.chars().filter(|c| !c.is_whitespace()).collect();
+        let mut position = 0;
+        let expression = Self::parse_at(&chars, &mut position)?;
+        (position == chars.len()).then_some(expression)
+    }
+
+    /// Returns the expression for `Any`, used for the arguments of a generic type that is
+    /// named without them.
+    pub(crate) fn any() -> Self {
+        Self {
+            name: "Any".to_string(),
+            arguments: Vec::new(),
+        }
+    }
+
+    /// Returns true if the expression is a tuple, such as `<Integer, String>`.
+    pub(crate) fn is_tuple(&self) -> bool {
+        self.name.is_empty()
+    }
+
+    /// Returns the expression with each of the given type parameters replaced by the argument at
+    /// the same position, such as `Container<Integer>` for `Container<T>` with `T` and `Integer`.
+    /// Parameters without an argument are replaced by `Any`.
+    pub(crate) fn substitute(&self, parameters: &[String], arguments: &[TypeExpression]) -> Self {
+        if self.arguments.is_empty() {
+            if let Some(index) = parameters.iter().position(|parameter| *parameter == self.name) {
+                return arguments.get(index).cloned().unwrap_or_else(Self::any);
+            }
+        }
+        Self {
+            name: self.name.clone(),
+            arguments: self
+                .arguments
+                .iter()
+                .map(|argument| argument.substitute(parameters, arguments))
+                .collect(),
+        }
+    }
+
+    // ----------------------------------------------------------------------------------------------
+    //                                         private methods
+    // ----------------------------------------------------------------------------------------------
+    fn parse_at(chars: &[char], position: &mut usize) -> Option<Self> {
+        let start = *position;
+        while chars
+            .get(*position)
+            .is_some_and(|c| c.is_alphanumeric() || *c == '_')
+        {
+            *position += 1;
+        }
+        let name: String = chars[start..*position].iter().collect();
+        if chars.get(*position) != Some(&'<') {
+            return (!name.is_empty()).then_some(Self {
+                name,
+                arguments: Vec::new(),
+            });
+        }
+        *position += 1;
+        let mut arguments = Vec::new();
+        if name.is_empty() && chars.get(*position) == Some(&'>') {
+            *position += 1;
+            return Some(Self { name, arguments });
+        }
+        loop {
+            arguments.push(Self::parse_at(chars, position)?);
+            match chars.get(*position) {
+                Some(',') => *position += 1,
+                Some('>') => {
+                    *position += 1;
+                    return Some(Self { name, arguments });
+                }
+                _ => return None,
+            }
+        }
+    }
+
+}
+
+impl Display for TypeExpression {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name)?;
+        if self.arguments.is_empty() && !self.is_tuple() {
+            return Ok(());
+        }
+        write!(f, "<")?;
+        for (index, argument) in self.arguments.iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{argument}")?;
+        }
+        write!(f, ">")
+    }
+}
@@ -0,0 +1,274 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::RwLock;
+use once_cell::sync::Lazy;
+use super::{
+    error::Error,
+    type_expression::TypeExpression,
+};
+
+/// The registry that [`Any::instance_of`](crate::foundation_types::Any::instance_of) and
+/// [`Any::conforms_to`](crate::foundation_types::Any::conforms_to) consult.
+static GLOBAL: Lazy<RwLock<TypeRegistry>> = Lazy::new(|| RwLock::new(TypeRegistry::default()));
+
+/// The types of the foundation model with their parents, as declared in the
+/// [openEHR BMM](https://specifications.openehr.org/releases/BASE/Release-1.2.0/foundation_types.html).
+/// The types are named as [`Any::type_of`](crate::foundation_types::Any::type_of) reports them.
+/// Parents must be declared before their children.
+const FOUNDATION_TYPES: &[(&str, &[&str])] = &[
+    ("Ordered", &["Any"]),
+    ("Numeric", &["Any"]),
+    ("OrderNumeric", &["Ordered", "Numeric"]),
+    ("Integer", &["OrderNumeric"]),
+    ("Integer64", &["OrderNumeric"]),
+    ("Real", &["OrderNumeric"]),
+    ("Double", &["OrderNumeric"]),
+    ("Decimal", &["OrderNumeric"]),
+    ("Boolean", &["Any"]),
+    ("Character", &["Ordered"]),
+    ("Octet", &["Ordered"]),
+    ("Octets", &["Any"]),
+    ("String", &["Ordered"]),
+    ("Uri", &["String"]),
+    ("TerminologyCode", &["Any"]),
+    ("TerminologyTerm", &["Any"]),
+    ("TimeDefinitions", &["Any"]),
+    ("Temporal", &["Ordered"]),
+    ("Iso8601Type", &["Temporal", "TimeDefinitions"]),
+    ("Iso8601Date", &["Iso8601Type"]),
+    ("Iso8601Time", &["Iso8601Type"]),
+    ("Iso8601DateTime", &["Iso8601Type"]),
+    ("Iso8601Duration", &["Iso8601Type"]),
+    ("Iso8601Timezone", &["Iso8601Type"]),
+    ("Container<T>", &["Any"]),
+    ("List<T>", &["Container<T>"]),
+    ("Array<T>", &["Container<T>"]),
+    ("Set<T>", &["Container<T>"]),
+    ("Hash<K, V>", &["Container<V>"]),
+    ("Interval<T>", &["Any"]),
+    ("ProperInterval<T>", &["Interval<T>"]),
+    ("PointInterval<T>", &["Interval<T>"]),
+    ("MultiplicityInterval", &["ProperInterval<Integer>"]),
+    ("Cardinality", &["Any"]),
+    ("IntervalSet<T>", &["Any"]),
+    ("Tuple", &["Any"]),
+    ("Routine", &["Any"]),
+    ("Function<A, R>", &["Routine"]),
+    ("Procedure<A>", &["Routine"]),
+];
+
+/// A registered type: the names of its type parameters and its parents, which may refer to
+/// those parameters, such as `T` and `Container<T>` for `List<T>`.
+#[derive(Clone, Debug)]
+struct TypeDefinition {
+    parameters: Vec<String>,
+    parents: Vec<TypeExpression>,
+}
+
+/// Records the inheritance graph of the openEHR types, so that type names can be checked for
+/// conformance. A type conforms to itself and to all of its ancestors, so `Integer` conforms to
+/// `OrderNumeric`, `Ordered`, `Numeric` and `Any`. Generic types conform covariantly, so
+/// `List<Integer>` conforms to `Container<Numeric>`, and a generic type named without its
+/// arguments, such as `List`, matches it with any arguments. Tuples such as `<Integer, String>`
+/// conform to `Tuple` and element-wise to tuples of the same length.
+///
+/// The [global registry](TypeRegistry::global) holds the foundation types and can be extended
+/// with the types of other models, such as the reference model.
+#[derive(Clone, Debug)]
+pub struct TypeRegistry {
+    types: HashMap<String, TypeDefinition>,
+}
+
+impl TypeRegistry {
+
+    /// Creates a registry that only knows `Any`.
+    pub fn new() -> Self {
+        let mut types = HashMap::new();
+        types.insert("Any".to_string(), TypeDefinition {
+            parameters: Vec::new(),
+            parents: Vec::new(),
+        });
+        Self { types }
+    }
+
+    /// Returns the registry used by [`Any::instance_of`](crate::foundation_types::Any::instance_of)
+    /// and [`Any::conforms_to`](crate::foundation_types::Any::conforms_to). It starts out with
+    /// the foundation types.
+    pub fn global() -> &'static RwLock<TypeRegistry> {
+        &GLOBAL
+    }
+
+    /// Registers a type with the given parents, such as `List<T>` with `Container<T>`. The
+    /// parents may refer to the type parameters of the declaration.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidTypeName`] if the declaration or a parent is not well formed or the
+    /// declaration has arguments that are not plain, distinct parameter names,
+    /// [`Error::AlreadyRegistered`] if the type is already known, [`Error::UnknownType`] if a
+    /// parent refers to a type that is not, and [`Error::WrongArgumentCount`] if a parent has
+    /// another number of arguments than its type declares.
+    pub fn register(&mut self, declaration: &str, parents: &[&str]) -> Result<(), Error> {
+        let invalid = || Error::InvalidTypeName(declaration.to_string());
+        let declaration_expression = TypeExpression::parse(declaration).ok_or_else(invalid)?;
+        if declaration_expression.is_tuple() {
+            return Err(invalid());
+        }
+        let mut parameters: Vec<String> = Vec::new();
+        for argument in declaration_expression.arguments {
+            let plain = argument.arguments.is_empty() && !argument.is_tuple();
+            if !plain || parameters.contains(&argument.name) {
+                return Err(invalid());
+            }
+            parameters.push(argument.name);
+        }
+        if self.types.contains_key(&declaration_expression.name) {
+            return Err(Error::AlreadyRegistered(declaration_expression.name));
+        }
+        let parents = parents
+            .iter()
+            .map(|parent| {
+                let parent_expression = TypeExpression::parse(parent)
+                    .filter(|expression| !expression.is_tuple())
+                    .ok_or_else(|| Error::InvalidTypeName(parent.to_string()))?;
+                self.check_declared(&parent_expression, &parameters)?;
+                Ok(parent_expression)
+            })
+            .collect::<Result<Vec<_>, Error>>()?;
+        self.types.insert(declaration_expression.name, TypeDefinition { parameters, parents });
+        Ok(())
+    }
+
+    /// Returns true if the type name is well formed and only refers to registered types, such as
+    /// `List<Integer>`. Generic types may be named without their arguments, such as `List`.
+    pub fn is_known(&self, type_name: &str) -> bool {
+        TypeExpression::parse(type_name)
+            .is_some_and(|expression| self.check_declared(&expression, &[]).is_ok())
+    }
+
+    /// Returns true if the type `type_name` conforms to the type `ancestor`, such as `Integer` to
+    /// `Numeric` or `List<Integer>` to `Container<Ordered>`. Every type conforms to `Any`. A type
+    /// that is not registered only conforms to itself and to `Any`, and a malformed name to
+    /// nothing.
+    pub fn conforms_to(&self, type_name: &str, ancestor: &str) -> bool {
+        match (TypeExpression::parse(type_name), TypeExpression::parse(ancestor)) {
+            (Some(type_expression), Some(ancestor_expression)) => {
+                self.conforms(&type_expression, &ancestor_expression)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the ancestors of the type, nearest first, with the arguments of the type
+    /// substituted, such as `Container<Integer>` and `Any` for `List<Integer>`. Returns an empty
+    /// list for `Any` and for types that are not registered.
+    pub fn ancestors(&self, type_name: &str) -> Vec<String> {
+        let Some(expression) = TypeExpression::parse(type_name) else {
+            return Vec::new();
+        };
+        let mut ancestors: Vec<TypeExpression> = Vec::new();
+        let mut queue = VecDeque::from([expression]);
+        while let Some(current) = queue.pop_front() {
+            for parent in self.parents(&current) {
+                if !ancestors.contains(&parent) {
+                    ancestors.push(parent.clone());
+                    queue.push_back(parent);
+                }
+            }
+        }
+        ancestors.iter().map(ToString::to_string).collect()
+    }
+
+    // ----------------------------------------------------------------------------------------------
+    //                                         private methods
+    // ----------------------------------------------------------------------------------------------
+    /// Returns the parents of the type with its arguments substituted. Tuples have `Tuple` as
+    /// their parent.
+    fn parents(&self, expression: &TypeExpression) -> Vec<TypeExpression> {
+        if expression.is_tuple() {
+            return vec![TypeExpression {
+                name: "Tuple".to_string(),
+                arguments: Vec::new(),
+            }];
+        }
+        self.types
+            .get(&expression.name)
+            .map(|definition| {
+                definition
+                    .parents
+                    .iter()
+                    .map(|parent| parent.substitute(&definition.parameters, &expression.arguments))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn conforms(&self, expression: &TypeExpression, ancestor: &TypeExpression) -> bool {
+        if ancestor.name == "Any" && ancestor.arguments.is_empty() {
+            return true;
+        }
+        let same_type = expression.name == ancestor.name
+            && (!ancestor.is_tuple() || expression.arguments.len() == ancestor.arguments.len());
+        let bare = ancestor.arguments.is_empty() && !ancestor.is_tuple();
+        if same_type && (bare || self.conforms_all(&expression.arguments, &ancestor.arguments)) {
+            return true;
+        }
+        self.parents(expression)
+            .iter()
+            .any(|parent| self.conforms(parent, ancestor))
+    }
+
+    fn conforms_all(&self, arguments: &[TypeExpression], ancestors: &[TypeExpression]) -> bool {
+        arguments.len() == ancestors.len()
+            && arguments
+                .iter()
+                .zip(ancestors)
+                .all(|(argument, ancestor)| self.conforms(argument, ancestor))
+    }
+
+    /// Checks that the expression only refers to registered types or to the given parameters,
+    /// with the number of arguments their types declare.
+    fn check_declared(
+        &self,
+        expression: &TypeExpression,
+        parameters: &[String],
+    ) -> Result<(), Error> {
+        if !expression.is_tuple() {
+            if parameters.contains(&expression.name) && expression.arguments.is_empty() {
+                return Ok(());
+            }
+            let definition = self
+                .types
+                .get(&expression.name)
+                .ok_or_else(|| Error::UnknownType(expression.name.clone()))?;
+            let expected = definition.parameters.len();
+            let found = expression.arguments.len();
+            if found != 0 && found != expected {
+                return Err(Error::WrongArgumentCount {
+                    type_name: expression.name.clone(),
+                    expected,
+                    found,
+                });
+            }
+        }
+        expression
+            .arguments
+            .iter()
+            .try_for_each(|argument| self.check_declared(argument, parameters))
+    }
+
+}
+
+impl Default for TypeRegistry {
+
+    /// Creates a registry with the foundation types.
+    fn default() -> Self {
+        let mut registry = Self::new();
+        for (declaration, parents) in FOUNDATION_TYPES {
+            registry
+                .register(declaration, parents)
+                .expect("foundation types are well formed");
+        }
+        registry
+    }
+
+}
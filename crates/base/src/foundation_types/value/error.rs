@@ -0,0 +1,10 @@
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum Error {
+    /// The value holds another type than the one requested, such as a `Double` when an
+    /// `Integer` is expected.
+    #[error("expected a value of type {expected} but found {found}")]
+    TypeMismatch {
+        expected: String,
+        found: String,
+    },
+}
@@ -0,0 +1,7 @@
+mod error;
+mod openehr_value;
+
+pub use {
+    error::Error,
+    openehr_value::{OpenEhrValue, ValueType},
+};
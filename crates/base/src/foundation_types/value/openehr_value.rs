@@ -0,0 +1,837 @@
+use std::hash::Hasher;
+use std::sync::PoisonError;
+use crate::foundation_types::{
+    Any,
+    Array,
+    Boolean,
+    Character,
+    Container,
+    Decimal,
+    Double,
+    Hash,
+    Integer,
+    Integer64,
+    Iso8601Date,
+    Iso8601DateTime,
+    Iso8601Duration,
+    Iso8601Time,
+    Iso8601Timezone,
+    List,
+    Octet,
+    Octets,
+    Real,
+    Set,
+    String,
+    TerminologyCode,
+    TerminologyTerm,
+    TypeRegistry,
+    Uri,
+};
+use crate::foundation_types::type_system::TypeExpression;
+#[cfg(feature = "serde")]
+use serde_::{
+    de::{self, MapAccess, SeqAccess, Visitor},
+    Deserialize,
+};
+#[cfg(feature = "serde")]
+use serde_json::{Map, Value};
+#[cfg(feature = "serde")]
+use crate::serialisation::{openehr_type_name, rust_type_name, TYPE_MEMBER};
+use super::error::Error;
+
+/// A value whose type is only known at run time, such as data read by its `_type` or checked
+/// against an archetype constraint. A value holds one of the primitive, time, terminology or
+/// container types, and containers hold values in turn, so `List<Integer>` is a list of values
+/// that each hold an `Integer`.
+///
+/// The type of a container is derived from its items: a list of integers is a `List<Integer>`,
+/// while an empty list or a list with items of different types is a `List<Any>`. A container is
+/// an instance of `List<Integer>` if all its items are integers, so an empty list is an instance
+/// of any list type.
+///
+/// With the `serde` feature, primitive values are written bare and other values with a `_type`
+/// discriminator, and they are read by dispatching on it, so they can be read without knowing
+/// their type.
+///
+/// Values can be items of a `Set` and keys of a `Hash`. To make that sound, `Real` and `Double`
+/// values are compared by their total order instead of by `==`: `NaN` equals itself, while `0.0`
+/// and `-0.0` differ.
+#[derive(Clone, Debug)]
+pub enum OpenEhrValue {
+    Boolean(Boolean),
+    Character(Character),
+    Octet(Octet),
+    Octets(Octets),
+    Integer(Integer),
+    Integer64(Integer64),
+    Real(Real),
+    Double(Double),
+    Decimal(Decimal),
+    String(String),
+    Uri(Uri),
+    TerminologyCode(TerminologyCode),
+    TerminologyTerm(TerminologyTerm),
+    Date(Iso8601Date),
+    Time(Iso8601Time),
+    DateTime(Iso8601DateTime),
+    Duration(Iso8601Duration),
+    Timezone(Iso8601Timezone),
+    List(List<OpenEhrValue>),
+    Array(Array<OpenEhrValue>),
+    Set(Set<OpenEhrValue>),
+    Hash(Hash<OpenEhrValue, OpenEhrValue>),
+}
+
+impl OpenEhrValue {
+
+    /// Returns true if the value holds a `T`.
+    pub fn is<T: ValueType>(&self) -> bool {
+        T::from_value(self).is_some()
+    }
+
+    /// Returns a reference to the `T` the value holds, or `None` if it holds another type.
+    pub fn downcast_ref<T: ValueType>(&self) -> Option<&T> {
+        T::from_value(self)
+    }
+
+    /// Returns the `T` the value holds.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::TypeMismatch`] if the value holds another type.
+    pub fn downcast<T: ValueType>(self) -> Result<T, Error> {
+        if !self.is::<T>() {
+            return Err(Error::TypeMismatch {
+                expected: T::TYPE_NAME.to_string(),
+                found: self.type_of(),
+            });
+        }
+        Ok(T::from_owned_value(self).expect("value holds the requested type"))
+    }
+
+}
+
+impl Any for OpenEhrValue {
+
+    fn is_equal(&self, other: &Self) -> bool {
+        self == other
+    }
+
+    /// Returns true if the type of the value conforms to the given type, or if it is a container
+    /// whose items are all instances of the given item types, such as a list of integers and
+    /// doubles for `Container<Numeric>`.
+    fn instance_of(&self, type_name: &str) -> bool {
+        if self.conforms_to(type_name) {
+            return true;
+        }
+        let Some(expression) = TypeExpression::parse(type_name) else {
+            return false;
+        };
+        let container = expression.name.as_str();
+        let arguments: Vec<std::string::String> =
+            expression.arguments.iter().map(ToString::to_string).collect();
+        let conforms = |name: &str| {
+            TypeRegistry::global()
+                .read()
+                .unwrap_or_else(PoisonError::into_inner)
+                .conforms_to(name, container)
+        };
+        match (self, arguments.as_slice()) {
+            (OpenEhrValue::List(list), [item]) => {
+                conforms("List") && all_instances(list.items(), item)
+            }
+            (OpenEhrValue::Array(array), [item]) => {
+                conforms("Array") && all_instances(array.items(), item)
+            }
+            (OpenEhrValue::Set(set), [item]) => conforms("Set") && all_instances(set.items(), item),
+            (OpenEhrValue::Hash(hash), [item]) => {
+                conforms("Hash") && all_instances(hash.items(), item)
+            }
+            (OpenEhrValue::Hash(hash), [key, item]) => {
+                container == "Hash"
+                    && all_instances(hash.keys(), key)
+                    && all_instances(hash.items(), item)
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the type of the value it holds, such as `Integer` or `List<Integer>`.
+    fn type_of(&self) -> std::string::String {
+        match self {
+            OpenEhrValue::Boolean(value) => value.type_of(),
+            OpenEhrValue::Character(value) => value.type_of(),
+            OpenEhrValue::Octet(value) => value.type_of(),
+            OpenEhrValue::Octets(value) => value.type_of(),
+            OpenEhrValue::Integer(value) => value.type_of(),
+            OpenEhrValue::Integer64(value) => value.type_of(),
+            OpenEhrValue::Real(value) => value.type_of(),
+            OpenEhrValue::Double(value) => value.type_of(),
+            OpenEhrValue::Decimal(value) => value.type_of(),
+            OpenEhrValue::String(value) => value.type_of(),
+            OpenEhrValue::Uri(value) => value.type_of(),
+            OpenEhrValue::TerminologyCode(value) => value.type_of(),
+            OpenEhrValue::TerminologyTerm(value) => value.type_of(),
+            OpenEhrValue::Date(value) => value.type_of(),
+            OpenEhrValue::Time(value) => value.type_of(),
+            OpenEhrValue::DateTime(value) => value.type_of(),
+            OpenEhrValue::Duration(value) => value.type_of(),
+            OpenEhrValue::Timezone(value) => value.type_of(),
+            OpenEhrValue::List(list) => format!("List<{}>", common_type(list.items())),
+            OpenEhrValue::Array(array) => format!("Array<{}>", common_type(array.items())),
+            OpenEhrValue::Set(set) => format!("Set<{}>", common_type(set.items())),
+            OpenEhrValue::Hash(hash) => format!(
+                "Hash<{}, {}>",
+                common_type(hash.keys()),
+                common_type(hash.items())
+            ),
+        }
+    }
+
+}
+
+impl PartialEq for OpenEhrValue {
+
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (OpenEhrValue::Boolean(a), OpenEhrValue::Boolean(b)) => a == b,
+            (OpenEhrValue::Character(a), OpenEhrValue::Character(b)) => a == b,
+            (OpenEhrValue::Octet(a), OpenEhrValue::Octet(b)) => a == b,
+            (OpenEhrValue::Octets(a), OpenEhrValue::Octets(b)) => a == b,
+            (OpenEhrValue::Integer(a), OpenEhrValue::Integer(b)) => a == b,
+            (OpenEhrValue::Integer64(a), OpenEhrValue::Integer64(b)) => a == b,
+            (OpenEhrValue::Real(a), OpenEhrValue::Real(b)) => a.value.total_cmp(&b.value).is_eq(),
+            (OpenEhrValue::Double(a), OpenEhrValue::Double(b)) => {
+                a.value.total_cmp(&b.value).is_eq()
+            }
+            (OpenEhrValue::Decimal(a), OpenEhrValue::Decimal(b)) => a == b,
+            (OpenEhrValue::String(a), OpenEhrValue::String(b)) => a == b,
+            (OpenEhrValue::Uri(a), OpenEhrValue::Uri(b)) => a == b,
+            (OpenEhrValue::TerminologyCode(a), OpenEhrValue::TerminologyCode(b)) => a == b,
+            (OpenEhrValue::TerminologyTerm(a), OpenEhrValue::TerminologyTerm(b)) => a == b,
+            (OpenEhrValue::Date(a), OpenEhrValue::Date(b)) => a == b,
+            (OpenEhrValue::Time(a), OpenEhrValue::Time(b)) => a == b,
+            (OpenEhrValue::DateTime(a), OpenEhrValue::DateTime(b)) => a == b,
+            (OpenEhrValue::Duration(a), OpenEhrValue::Duration(b)) => a == b,
+            (OpenEhrValue::Timezone(a), OpenEhrValue::Timezone(b)) => a == b,
+            (OpenEhrValue::List(a), OpenEhrValue::List(b)) => a == b,
+            (OpenEhrValue::Array(a), OpenEhrValue::Array(b)) => a == b,
+            (OpenEhrValue::Set(a), OpenEhrValue::Set(b)) => a == b,
+            (OpenEhrValue::Hash(a), OpenEhrValue::Hash(b)) => a == b,
+            _ => false,
+        }
+    }
+
+}
+
+impl Eq for OpenEhrValue {
+}
+
+/// Hashes consistently with `==`. Of the types that do not implement `Hash`, such as the time
+/// types, and of sets and hashes, whose equality does not depend on the order of their items,
+/// only the kind and the number of items are hashed.
+impl std::hash::Hash for OpenEhrValue {
+
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+        match self {
+            OpenEhrValue::Boolean(value) => value.hash(state),
+            OpenEhrValue::Character(value) => value.hash(state),
+            OpenEhrValue::Octet(value) => value.hash(state),
+            OpenEhrValue::Octets(value) => value.hash(state),
+            OpenEhrValue::Integer(value) => value.hash(state),
+            OpenEhrValue::Integer64(value) => value.hash(state),
+            OpenEhrValue::Real(value) => value.value.to_bits().hash(state),
+            OpenEhrValue::Double(value) => value.value.to_bits().hash(state),
+            OpenEhrValue::Decimal(value) => value.hash(state),
+            OpenEhrValue::String(value) => value.hash(state),
+            OpenEhrValue::Uri(value) => value.hash(state),
+            OpenEhrValue::List(list) => list.items().for_each(|item| item.hash(state)),
+            OpenEhrValue::Array(array) => array.items().for_each(|item| item.hash(state)),
+            OpenEhrValue::Set(set) => set.items().count().hash(state),
+            OpenEhrValue::Hash(hash) => hash.keys().count().hash(state),
+            OpenEhrValue::TerminologyCode(_)
+            | OpenEhrValue::TerminologyTerm(_)
+            | OpenEhrValue::Date(_)
+            | OpenEhrValue::Time(_)
+            | OpenEhrValue::DateTime(_)
+            | OpenEhrValue::Duration(_)
+            | OpenEhrValue::Timezone(_) => (),
+        }
+    }
+
+}
+
+/// A type an [`OpenEhrValue`] can hold, used to downcast values.
+pub trait ValueType: Any + Sized {
+
+    /// The name of the type without its parameters, such as `Integer` or `List`.
+    const TYPE_NAME: &'static str;
+
+    /// Returns a reference to the value of this type that the value holds, if any.
+    fn from_value(value: &OpenEhrValue) -> Option<&Self>;
+
+    /// Returns the value of this type that the value holds, if any.
+    fn from_owned_value(value: OpenEhrValue) -> Option<Self>;
+
+}
+
+macro_rules! impl_value_type {
+    ($variant:ident, $type:ty, $name:literal) => {
+        impl ValueType for $type {
+
+            const TYPE_NAME: &'static str = $name;
+
+            fn from_value(value: &OpenEhrValue) -> Option<&Self> {
+                match value {
+                    OpenEhrValue::$variant(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+            fn from_owned_value(value: OpenEhrValue) -> Option<Self> {
+                match value {
+                    OpenEhrValue::$variant(value) => Some(value),
+                    _ => None,
+                }
+            }
+
+        }
+
+        impl From<$type> for OpenEhrValue {
+
+            fn from(value: $type) -> Self {
+                OpenEhrValue::$variant(value)
+            }
+
+        }
+
+        impl TryFrom<OpenEhrValue> for $type {
+            type Error = Error;
+
+            fn try_from(value: OpenEhrValue) -> Result<Self, Self::Error> {
+                value.downcast()
+            }
+
+        }
+    };
+}
+
+impl_value_type!(Boolean, Boolean, "Boolean");
+impl_value_type!(Character, Character, "Character");
+impl_value_type!(Octet, Octet, "Octet");
+impl_value_type!(Octets, Octets, "Octets");
+impl_value_type!(Integer, Integer, "Integer");
+impl_value_type!(Integer64, Integer64, "Integer64");
+impl_value_type!(Real, Real, "Real");
+impl_value_type!(Double, Double, "Double");
+impl_value_type!(Decimal, Decimal, "Decimal");
+impl_value_type!(String, String, "String");
+impl_value_type!(Uri, Uri, "Uri");
+impl_value_type!(TerminologyCode, TerminologyCode, "TerminologyCode");
+impl_value_type!(TerminologyTerm, TerminologyTerm, "TerminologyTerm");
+impl_value_type!(Date, Iso8601Date, "Iso8601Date");
+impl_value_type!(Time, Iso8601Time, "Iso8601Time");
+impl_value_type!(DateTime, Iso8601DateTime, "Iso8601DateTime");
+impl_value_type!(Duration, Iso8601Duration, "Iso8601Duration");
+impl_value_type!(Timezone, Iso8601Timezone, "Iso8601Timezone");
+impl_value_type!(List, List<OpenEhrValue>, "List");
+impl_value_type!(Array, Array<OpenEhrValue>, "Array");
+impl_value_type!(Set, Set<OpenEhrValue>, "Set");
+impl_value_type!(Hash, Hash<OpenEhrValue, OpenEhrValue>, "Hash");
+
+#[cfg(feature = "serde")]
+impl serde_::Serialize for OpenEhrValue {
+
+    /// Writes the value in canonical openEHR form. Primitive and time values are written bare, as
+    /// their own types write them, such as `42` or `"2024-03-15"`. Terminology codes and terms get
+    /// a `_type` discriminator as an extra member, and containers are written as
+    /// `{"_type": "LIST<INTEGER>", "items": [...]}` or
+    /// `{"_type": "HASH<STRING,INTEGER>", "entries": [{"key": ..., "value": ...}]}`, whose type
+    /// tells how to read the bare items, keys and values back.
+    fn serialize<S: serde_::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            OpenEhrValue::Boolean(value) => value.serialize(serializer),
+            OpenEhrValue::Character(value) => value.serialize(serializer),
+            OpenEhrValue::Octet(value) => value.serialize(serializer),
+            OpenEhrValue::Octets(value) => value.serialize(serializer),
+            OpenEhrValue::Integer(value) => value.serialize(serializer),
+            OpenEhrValue::Integer64(value) => value.serialize(serializer),
+            OpenEhrValue::Real(value) => value.serialize(serializer),
+            OpenEhrValue::Double(value) => value.serialize(serializer),
+            OpenEhrValue::Decimal(value) => value.serialize(serializer),
+            OpenEhrValue::String(value) => value.serialize(serializer),
+            OpenEhrValue::Uri(value) => value.serialize(serializer),
+            OpenEhrValue::Date(value) => serializer.collect_str(value),
+            OpenEhrValue::Time(value) => serializer.collect_str(value),
+            OpenEhrValue::DateTime(value) => serializer.collect_str(value),
+            OpenEhrValue::Duration(value) => serializer.collect_str(value),
+            OpenEhrValue::Timezone(value) => serializer.collect_str(value),
+            OpenEhrValue::TerminologyCode(code) => {
+                Typed { type_name: openehr_type_name(&self.type_of()), value: code }
+                    .serialize(serializer)
+            }
+            OpenEhrValue::TerminologyTerm(term) => {
+                Typed { type_name: openehr_type_name(&self.type_of()), value: term }
+                    .serialize(serializer)
+            }
+            OpenEhrValue::List(list) => {
+                write_container(serializer, self, ITEMS_MEMBER, &list.items().collect::<Vec<_>>())
+            }
+            OpenEhrValue::Array(array) => {
+                write_container(serializer, self, ITEMS_MEMBER, &array.items().collect::<Vec<_>>())
+            }
+            OpenEhrValue::Set(set) => {
+                write_container(serializer, self, ITEMS_MEMBER, &set.items().collect::<Vec<_>>())
+            }
+            OpenEhrValue::Hash(hash) => {
+                let entries: Vec<Entry<&OpenEhrValue>> = hash
+                    .keys()
+                    .zip(hash.items())
+                    .map(|(key, value)| Entry { key, value })
+                    .collect();
+                write_container(serializer, self, ENTRIES_MEMBER, &entries)
+            }
+        }
+    }
+
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde_::Deserialize<'de> for OpenEhrValue {
+
+    /// Reads a value written by [`Serialize`](serde_::Serialize). An object is read by
+    /// dispatching on its `_type`, wherever it appears among the members; in XML the
+    /// discriminator is the `xsi:type` attribute. A bare value is read as the item, key or value
+    /// type of its container, such as an `Iso8601Date` in a `List<Iso8601Date>`. Where that type
+    /// is `Any`, booleans, numbers, strings and arrays are read as a `Boolean`, an `Integer`,
+    /// `Integer64` or `Double`, a `String` and a `List`, so a `Character` or an `Iso8601Date` in
+    /// a `List<Any>` is read back as a `String`.
+    fn deserialize<D: serde_::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let content = Content::deserialize(deserializer)?;
+        read_content(content, &TypeExpression::any()).map_err(de::Error::custom)
+    }
+
+}
+
+#[cfg(feature = "serde")]
+impl OpenEhrValue {
+
+    /// Reads a value from JSON as [`Serialize`](serde_::Serialize) writes it, reading a bare value
+    /// as the given type, such as `42` as an `Integer64`. Used to check the discriminators of
+    /// members in documents against the values read.
+    pub(crate) fn from_json_as(json: Value, type_name: &str) -> Result<Self, std::string::String> {
+        let expected = TypeExpression::parse(type_name).unwrap_or_else(TypeExpression::any);
+        let content = Content::deserialize(json).map_err(|error| error.to_string())?;
+        read_content(content, &expected)
+    }
+
+}
+
+// ----------------------------------------------------------------------------------------------
+//                                         private functions
+// ----------------------------------------------------------------------------------------------
+/// Returns the type shared by all values, or `Any` if there are none or their types differ.
+fn common_type<'a>(mut values: impl Iterator<Item = &'a OpenEhrValue>) -> std::string::String {
+    let Some(first) = values.next().map(|value| value.type_of()) else {
+        return "Any".to_string();
+    };
+    if values.all(|value| value.type_of() == first) {
+        first
+    } else {
+        "Any".to_string()
+    }
+}
+
+/// Returns true if all values are instances of the given type.
+fn all_instances<'a>(mut values: impl Iterator<Item = &'a OpenEhrValue>, type_name: &str) -> bool {
+    values.all(|value| value.instance_of(type_name))
+}
+
+// ----------------------------------------------------------------------------------------------
+//                                         serde support
+// ----------------------------------------------------------------------------------------------
+/// Name of the member holding the items of a list, array or set.
+#[cfg(feature = "serde")]
+const ITEMS_MEMBER: &str = "items";
+
+/// Name of the member holding the entries of a hash.
+#[cfg(feature = "serde")]
+const ENTRIES_MEMBER: &str = "entries";
+
+/// Name of the member holding the key of a hash entry.
+#[cfg(feature = "serde")]
+const KEY_MEMBER: &str = "key";
+
+/// Name of the member holding the value of a hash entry.
+#[cfg(feature = "serde")]
+const VALUE_MEMBER: &str = "value";
+
+/// Name under which XML readers report the `xsi:type` attribute.
+#[cfg(feature = "serde")]
+const TYPE_ATTRIBUTE: &str = "@type";
+
+/// Name under which XML readers report the text of an element that has attributes, such as the
+/// `42` in `<items xsi:type="INTEGER">42</items>`.
+#[cfg(feature = "serde")]
+const TEXT_MEMBER: &str = "$text";
+
+/// A terminology code or term with a `_type` discriminator as extra member.
+#[cfg(feature = "serde")]
+#[derive(serde_::Serialize)]
+#[serde(crate = "serde_")]
+struct Typed<'a, T> {
+    #[serde(rename = "_type")]
+    type_name: std::string::String,
+    #[serde(flatten)]
+    value: &'a T,
+}
+
+/// An entry of a hash, whose keys are values and so cannot be member names.
+#[cfg(feature = "serde")]
+#[derive(serde_::Serialize)]
+#[serde(crate = "serde_")]
+struct Entry<V> {
+    key: V,
+    value: V,
+}
+
+/// Writes a container as its `_type` and the member holding its items or entries.
+#[cfg(feature = "serde")]
+fn write_container<S, T>(serializer: S, container: &OpenEhrValue, member: &str, content: &T)
+    -> Result<S::Ok, S::Error>
+where
+    S: serde_::Serializer,
+    T: serde_::Serialize,
+{
+    use serde_::ser::SerializeMap;
+    let mut map = serializer.serialize_map(Some(2))?;
+    map.serialize_entry(TYPE_MEMBER, &openehr_type_name(&container.type_of()))?;
+    map.serialize_entry(member, content)?;
+    map.end()
+}
+
+/// A value as read from the document, buffered so that the `_type` of an object can be found
+/// among its members in any order. The members of an object keep their order and repeated
+/// names, as XML readers report the items of a container as repeated child elements.
+#[cfg(feature = "serde")]
+enum Content {
+    Null,
+    Bool(bool),
+    I64(i64),
+    U64(u64),
+    F64(f64),
+    String(std::string::String),
+    Seq(Vec<Content>),
+    Map(Vec<(std::string::String, Content)>),
+}
+
+#[cfg(feature = "serde")]
+impl Content {
+
+    /// Converts the content to a JSON value. Of repeated members only the first is kept, and an
+    /// XML element that has only text becomes that text.
+    fn into_json(self) -> Value {
+        match self {
+            Content::Null => Value::Null,
+            Content::Bool(value) => value.into(),
+            Content::I64(value) => value.into(),
+            Content::U64(value) => value.into(),
+            Content::F64(value) => value.into(),
+            Content::String(value) => value.into(),
+            Content::Seq(items) => items.into_iter().map(Content::into_json).collect(),
+            Content::Map(mut members) if members.len() == 1 && members[0].0 == TEXT_MEMBER => {
+                members.remove(0).1.into_json()
+            }
+            Content::Map(members) => {
+                let mut object = Map::new();
+                for (name, value) in members {
+                    object.entry(name).or_insert_with(|| value.into_json());
+                }
+                Value::Object(object)
+            }
+        }
+    }
+
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Content {
+
+    fn deserialize<D: serde_::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ContentVisitor)
+    }
+
+}
+
+#[cfg(feature = "serde")]
+struct ContentVisitor;
+
+#[cfg(feature = "serde")]
+impl<'de> Visitor<'de> for ContentVisitor {
+    type Value = Content;
+
+    fn expecting(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        formatter.write_str("an openEHR value")
+    }
+
+    fn visit_bool<E: de::Error>(self, value: bool) -> Result<Self::Value, E> {
+        Ok(Content::Bool(value))
+    }
+
+    fn visit_i64<E: de::Error>(self, value: i64) -> Result<Self::Value, E> {
+        Ok(Content::I64(value))
+    }
+
+    fn visit_u64<E: de::Error>(self, value: u64) -> Result<Self::Value, E> {
+        Ok(Content::U64(value))
+    }
+
+    fn visit_f64<E: de::Error>(self, value: f64) -> Result<Self::Value, E> {
+        Ok(Content::F64(value))
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<Self::Value, E> {
+        Ok(Content::String(value.to_string()))
+    }
+
+    fn visit_string<E: de::Error>(self, value: std::string::String) -> Result<Self::Value, E> {
+        Ok(Content::String(value))
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Content::Null)
+    }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> {
+        Ok(Content::Null)
+    }
+
+    fn visit_some<D: serde_::Deserializer<'de>>(self, deserializer: D)
+        -> Result<Self::Value, D::Error>
+    {
+        Content::deserialize(deserializer)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut items = Vec::new();
+        while let Some(item) = seq.next_element()? {
+            items.push(item);
+        }
+        Ok(Content::Seq(items))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut members = Vec::new();
+        while let Some(member) = map.next_entry()? {
+            members.push(member);
+        }
+        Ok(Content::Map(members))
+    }
+}
+
+/// Reads a value from its content. An object is read by its discriminator, a bare value as the
+/// expected type.
+#[cfg(feature = "serde")]
+fn read_content(content: Content, expected: &TypeExpression)
+    -> Result<OpenEhrValue, std::string::String>
+{
+    match content {
+        Content::Map(members) => read_object(members, expected),
+        Content::Seq(items) => {
+            let item_type = argument(expected, 0);
+            let items = items
+                .into_iter()
+                .map(|item| read_content(item, &item_type))
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(OpenEhrValue::List(items.into()))
+        }
+        content => read_primitive(&expected.name, content),
+    }
+}
+
+/// Reads an object by dispatching on its `_type` or `xsi:type`, and checks that the value read
+/// is an instance of that type. XML namespace declarations are skipped, and an XML element that
+/// has only text is read as a bare value of the expected type.
+#[cfg(feature = "serde")]
+fn read_object(mut members: Vec<(std::string::String, Content)>, expected: &TypeExpression)
+    -> Result<OpenEhrValue, std::string::String>
+{
+    members.retain(|(name, _)| !name.starts_with("@xmlns"));
+    let Some(position) = members
+        .iter()
+        .position(|(name, _)| name == TYPE_MEMBER || name == TYPE_ATTRIBUTE)
+    else {
+        return match members.pop() {
+            Some((name, text)) if name == TEXT_MEMBER && members.is_empty() => {
+                read_content(text, expected)
+            }
+            _ => Err("_type expected".to_string()),
+        };
+    };
+    let Content::String(found) = members.remove(position).1 else {
+        return Err("_type must be a string".to_string());
+    };
+    let type_name = rust_type_name(&found);
+    let expression =
+        TypeExpression::parse(&type_name).ok_or_else(|| format!("invalid type {found}"))?;
+    let value = match expression.name.as_str() {
+        "TerminologyCode" => read_json::<TerminologyCode>(Content::Map(members))?.into(),
+        "TerminologyTerm" => read_json::<TerminologyTerm>(Content::Map(members))?.into(),
+        "List" => OpenEhrValue::List(read_items(members, &expression)?.into()),
+        "Array" => OpenEhrValue::Array(read_items(members, &expression)?.into_iter().collect()),
+        "Set" => OpenEhrValue::Set(read_items(members, &expression)?.into_iter().collect()),
+        "Hash" => OpenEhrValue::Hash(read_entries(members, &expression)?.into_iter().collect()),
+        name if is_primitive(name) => {
+            let text = members
+                .into_iter()
+                .find(|(name, _)| name == TEXT_MEMBER)
+                .map_or(Content::String(std::string::String::new()), |(_, text)| text);
+            read_primitive(name, text)?
+        }
+        _ => return Err(format!("unknown type {found}")),
+    };
+    if !value.instance_of(&type_name) {
+        return Err(format!("content does not match type {found}"));
+    }
+    Ok(value)
+}
+
+/// Returns the type argument at the given position, or `Any` if the type has none.
+#[cfg(feature = "serde")]
+fn argument(expression: &TypeExpression, position: usize) -> TypeExpression {
+    expression.arguments.get(position).cloned().unwrap_or_else(TypeExpression::any)
+}
+
+/// Reads the items of a list, array or set, which may have none. JSON holds them in one array,
+/// XML in repeated members.
+#[cfg(feature = "serde")]
+fn read_items(members: Vec<(std::string::String, Content)>, expression: &TypeExpression)
+    -> Result<Vec<OpenEhrValue>, std::string::String>
+{
+    let item_type = argument(expression, 0);
+    repeated(members, ITEMS_MEMBER)
+        .map(|item| read_content(item, &item_type))
+        .collect()
+}
+
+/// Reads the entries of a hash, which may have none.
+#[cfg(feature = "serde")]
+fn read_entries(members: Vec<(std::string::String, Content)>, expression: &TypeExpression)
+    -> Result<Vec<(OpenEhrValue, OpenEhrValue)>, std::string::String>
+{
+    let key_type = argument(expression, 0);
+    let value_type = argument(expression, 1);
+    repeated(members, ENTRIES_MEMBER)
+        .map(|entry| {
+            let Content::Map(members) = entry else {
+                return Err("hash entry expected".to_string());
+            };
+            let mut key = None;
+            let mut value = None;
+            for (name, content) in members {
+                if name == KEY_MEMBER {
+                    key = Some(content);
+                } else if name == VALUE_MEMBER {
+                    value = Some(content);
+                }
+            }
+            let key = key.ok_or_else(|| "key of hash entry expected".to_string())?;
+            let value = value.ok_or_else(|| "value of hash entry expected".to_string())?;
+            Ok((read_content(key, &key_type)?, read_content(value, &value_type)?))
+        })
+        .collect()
+}
+
+/// Returns the items of the given member, whether they are held in one array or in repeated
+/// members.
+#[cfg(feature = "serde")]
+fn repeated(members: Vec<(std::string::String, Content)>, member: &str)
+    -> impl Iterator<Item = Content> + '_
+{
+    members
+        .into_iter()
+        .filter(move |(name, _)| name == member)
+        .flat_map(|(_, content)| match content {
+            Content::Seq(items) => items,
+            item => vec![item],
+        })
+}
+
+/// Returns true if the type is a primitive or time type, which is written bare.
+#[cfg(feature = "serde")]
+fn is_primitive(type_name: &str) -> bool {
+    matches!(
+        type_name,
+        "Boolean" | "Character" | "Octet" | "Octets" | "Integer" | "Integer64" | "Real"
+            | "Double" | "Decimal" | "String" | "Uri" | "Iso8601Date" | "Iso8601Time"
+            | "Iso8601DateTime" | "Iso8601Duration" | "Iso8601Timezone"
+    )
+}
+
+/// Reads a bare value as the given type. For `Any` the type follows from the content.
+#[cfg(feature = "serde")]
+fn read_primitive(type_name: &str, content: Content)
+    -> Result<OpenEhrValue, std::string::String>
+{
+    Ok(match type_name {
+        "Boolean" => read_json::<Boolean>(content)?.into(),
+        "Character" => read_json::<Character>(content)?.into(),
+        "Octet" => read_json::<Octet>(content)?.into(),
+        "Octets" => read_json::<Octets>(content)?.into(),
+        "Integer" => read_json::<Integer>(content)?.into(),
+        "Integer64" => read_json::<Integer64>(content)?.into(),
+        "Real" => read_json::<Real>(content)?.into(),
+        "Double" => read_json::<Double>(content)?.into(),
+        "Decimal" => read_json::<Decimal>(content)?.into(),
+        "String" => read_json::<String>(content)?.into(),
+        "Uri" => read_json::<Uri>(content)?.into(),
+        "Iso8601Date" => parse_text(content, Iso8601Date::parse)?,
+        "Iso8601Time" => parse_text(content, Iso8601Time::parse)?,
+        "Iso8601DateTime" => parse_text(content, Iso8601DateTime::parse)?,
+        "Iso8601Duration" => parse_text(content, Iso8601Duration::parse)?,
+        "Iso8601Timezone" => parse_text(content, Iso8601Timezone::parse)?,
+        "Any" => match content {
+            Content::Bool(value) => Boolean::new(value).into(),
+            Content::I64(value) => match i32::try_from(value) {
+                Ok(value) => Integer::new(value).into(),
+                Err(_) => Integer64::new(value).into(),
+            },
+            Content::U64(value) => {
+                let value = i64::try_from(value).map_err(|_| "integer out of range")?;
+                read_primitive(type_name, Content::I64(value))?
+            }
+            Content::F64(value) => Double::new(value).into(),
+            Content::String(value) => String::from(value).into(),
+            _ => return Err("value expected".to_string()),
+        },
+        _ => return Err(format!("{} expected", openehr_type_name(type_name))),
+    })
+}
+
+/// Reads a value of type `T` from its content. XML readers report all text as strings, so a
+/// string that does not read as `T` is read again as the JSON it holds, such as `42`.
+#[cfg(feature = "serde")]
+fn read_json<T: serde_::de::DeserializeOwned>(content: Content)
+    -> Result<T, std::string::String>
+{
+    let value = content.into_json();
+    serde_json::from_value(value.clone()).or_else(|error| match &value {
+        Value::String(text) => serde_json::from_str(text).map_err(|_| error.to_string()),
+        _ => Err(error.to_string()),
+    })
+}
+
+/// Reads a time type from its ISO 8601 form.
+#[cfg(feature = "serde")]
+fn parse_text<T, E>(content: Content, parse: fn(std::string::String) -> Result<T, E>)
+    -> Result<OpenEhrValue, std::string::String>
+where
+    T: Into<OpenEhrValue>,
+    E: std::fmt::Display,
+{
+    let Content::String(text) = content else {
+        return Err("ISO 8601 string expected".to_string());
+    };
+    parse(text).map(Into::into).map_err(|error| error.to_string())
+}
@@ -1,3 +1,6 @@
 pub mod foundation_types;
 
 
+
+#[cfg(feature = "serde")]
+pub mod serialisation;
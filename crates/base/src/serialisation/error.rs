@@ -0,0 +1,29 @@
+#[derive(Debug, Clone, thiserror::Error, PartialEq, Eq)]
+pub enum Error {
+    /// The document is not valid JSON or XML, or its content does not match the type it is
+    /// read as, such as `"ten"` read as an `INTEGER`.
+    #[error("invalid document: {0}")]
+    InvalidDocument(String),
+
+    /// The root element of an XML document is not in the openEHR namespace, such as
+    /// `<value xsi:type="INTEGER">42</value>` without `xmlns="http://schemas.openehr.org/v1"`.
+    #[error("element not in the openEHR namespace")]
+    MissingNamespace,
+
+    /// The document has no type discriminator, such as a JSON object without `_type` or an XML
+    /// root element without `xsi:type`.
+    #[error("type discriminator expected but missing")]
+    MissingType,
+
+    /// The type discriminator names a type that does not exist, such as `DV_TXET`.
+    #[error("unknown type {0}")]
+    UnknownType(String),
+
+    /// The type discriminator names an existing type that the value read is not an instance of,
+    /// such as `TERMINOLOGY_TERM` on a document read as a `TERMINOLOGY_CODE`.
+    #[error("incompatible type {found}, expected {expected}")]
+    IncompatibleType {
+        expected: String,
+        found: String,
+    },
+}
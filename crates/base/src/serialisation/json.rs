@@ -0,0 +1,97 @@
+use serde_::{de::DeserializeOwned, Serialize};
+use serde_json::{Map, Value};
+use crate::foundation_types::Any;
+use super::{
+    error::Error,
+    type_names::{check_known, check_type, openehr_type_name},
+};
+
+/// Name of the member holding the type discriminator.
+pub(crate) const TYPE_MEMBER: &str = "_type";
+
+/// Writes the value as canonical openEHR JSON. Values that are objects get a `_type`
+/// discriminator derived from [`Any::type_of`] as their first member, such as
+/// `{"_type": "TERMINOLOGY_CODE", "terminology_id": "openehr", ...}`, and polymorphic members
+/// carry their own. Primitive values are written bare, such as `42`.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidDocument`] if the value cannot be serialised.
+pub fn to_json<T>(value: &T) -> Result<String, Error>
+where
+    T: Any + Serialize,
+{
+    serde_json::to_string(&to_json_value(value)?)
+        .map_err(|error| Error::InvalidDocument(error.to_string()))
+}
+
+/// Converts the value to a JSON value with `_type` discriminators. See [`to_json`].
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidDocument`] if the value cannot be serialised.
+pub fn to_json_value<T>(value: &T) -> Result<Value, Error>
+where
+    T: Any + Serialize,
+{
+    let json = serde_json::to_value(value)
+        .map_err(|error| Error::InvalidDocument(error.to_string()))?;
+    let Value::Object(members) = json else {
+        return Ok(json);
+    };
+    let mut object = Map::new();
+    let type_name = openehr_type_name(&value.type_of());
+    object.insert(TYPE_MEMBER.to_string(), Value::String(type_name));
+    object.extend(members.into_iter().filter(|(name, _)| name != TYPE_MEMBER));
+    Ok(Value::Object(object))
+}
+
+/// Reads a value of type `T` from canonical openEHR JSON. An object must have a `_type`
+/// discriminator, which is resolved through the
+/// [type registry](crate::foundation_types::TypeRegistry) and must name the type of the value
+/// read, as [`Any::instance_of`] decides. A primitive value is read bare. See [`to_json`].
+///
+/// # Errors
+///
+/// Returns [`Error::MissingType`] if an object has no discriminator,
+/// [`Error::UnknownType`] if the discriminator names a type that does not exist,
+/// [`Error::IncompatibleType`] if it names a type the value read is not an instance of and
+/// [`Error::InvalidDocument`] if the document is not valid JSON or its content does not match
+/// `T`.
+pub fn from_json<T>(json: &str) -> Result<T, Error>
+where
+    T: Any + DeserializeOwned,
+{
+    let value = serde_json::from_str(json)
+        .map_err(|error| Error::InvalidDocument(error.to_string()))?;
+    from_json_value(value)
+}
+
+/// Converts a JSON value with `_type` discriminators to a value of type `T`. See
+/// [`from_json`].
+///
+/// # Errors
+///
+/// See [`from_json`].
+pub fn from_json_value<T>(value: Value) -> Result<T, Error>
+where
+    T: Any + DeserializeOwned,
+{
+    let found = match &value {
+        Value::Object(object) => match object.get(TYPE_MEMBER) {
+            Some(Value::String(found)) => Some(found.clone()),
+            Some(_) => return Err(Error::InvalidDocument("_type is not a string".to_string())),
+            None => return Err(Error::MissingType),
+        },
+        _ => None,
+    };
+    if let Some(found) = &found {
+        check_known(found)?;
+    }
+    let read: T = serde_json::from_value(value)
+        .map_err(|error| Error::InvalidDocument(error.to_string()))?;
+    if let Some(found) = &found {
+        check_type(&read, found)?;
+    }
+    Ok(read)
+}
@@ -0,0 +1,16 @@
+mod error;
+mod type_names;
+mod json;
+#[cfg(feature = "xml")]
+mod xml;
+
+pub use {
+    error::Error,
+    type_names::{is_known_type, openehr_type_name, rust_type_name},
+    json::{from_json, from_json_value, to_json, to_json_value},
+};
+
+pub(crate) use json::TYPE_MEMBER;
+
+#[cfg(feature = "xml")]
+pub use xml::{from_xml, to_xml, OPENEHR_NAMESPACE};
@@ -0,0 +1,87 @@
+use std::sync::PoisonError;
+use crate::foundation_types::{Any, TypeRegistry};
+use super::error::Error;
+
+/// Converts a type name as returned by [`Any::type_of`](crate::foundation_types::Any::type_of)
+/// into the openEHR notation used in type discriminators, such as `TERMINOLOGY_CODE` for
+/// `TerminologyCode`, `ISO8601_DATE_TIME` for `Iso8601DateTime` and `LIST<INTEGER>` for
+/// `List<Integer>`.
+pub fn openehr_type_name(type_name: &str) -> String {
+    let mut name = String::with_capacity(type_name.len() + 4);
+    let mut previous: Option<char> = None;
+    for c in type_name.chars() {
+        if c == ' ' {
+            continue;
+        }
+        let word_end = previous.is_some_and(|previous| {
+            previous.is_ascii_lowercase() || previous.is_ascii_digit()
+        });
+        if c.is_ascii_uppercase() && word_end {
+            name.push('_');
+        }
+        name.push(c.to_ascii_uppercase());
+        previous = Some(c);
+    }
+    name
+}
+
+/// Converts a type name in openEHR notation into the notation of
+/// [`Any::type_of`](crate::foundation_types::Any::type_of), such as `TerminologyCode` for
+/// `TERMINOLOGY_CODE` and `List<Integer>` for `LIST<INTEGER>`. This is the inverse of
+/// [`openehr_type_name`].
+pub fn rust_type_name(type_name: &str) -> String {
+    let mut name = String::with_capacity(type_name.len());
+    let mut word_start = true;
+    for c in type_name.chars() {
+        match c {
+            '_' => word_start = true,
+            ' ' => (),
+            c if c.is_ascii_alphanumeric() => {
+                if word_start {
+                    name.push(c.to_ascii_uppercase());
+                } else {
+                    name.push(c.to_ascii_lowercase());
+                }
+                word_start = false;
+            }
+            c => {
+                name.push(c);
+                if c == ',' {
+                    name.push(' ');
+                }
+                word_start = true;
+            }
+        }
+    }
+    name
+}
+
+/// Returns true if the type name, in openEHR notation, names a type of the
+/// [global type registry](TypeRegistry::global). The arguments of a generic type must be known
+/// as well, so `LIST<INTEGER>` is known but `LIST<INTEGRE>` is not.
+pub fn is_known_type(type_name: &str) -> bool {
+    TypeRegistry::global()
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .is_known(&rust_type_name(type_name))
+}
+
+/// Checks that the type discriminator `found` of a document names a known type.
+pub(super) fn check_known(found: &str) -> Result<(), Error> {
+    if !is_known_type(found) {
+        return Err(Error::UnknownType(found.to_string()));
+    }
+    Ok(())
+}
+
+/// Checks that the value read from a document is an instance of the type its discriminator
+/// `found` names, comparing the name against [`Any::type_of`] as the writer does.
+pub(super) fn check_type<T: Any>(value: &T, found: &str) -> Result<(), Error> {
+    if !value.instance_of(&rust_type_name(found)) {
+        return Err(Error::IncompatibleType {
+            expected: openehr_type_name(&value.type_of()),
+            found: found.to_string(),
+        });
+    }
+    Ok(())
+}
@@ -0,0 +1,278 @@
+use quick_xml::{
+    events::{BytesEnd, BytesStart, BytesText, Event},
+    name::{Namespace, ResolveResult},
+    NsReader,
+    Writer,
+};
+use std::collections::HashMap;
+use serde_::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use crate::foundation_types::{Any, OpenEhrValue};
+use super::{
+    error::Error,
+    json::{to_json_value, TYPE_MEMBER},
+    type_names::{check_known, check_type, openehr_type_name, rust_type_name},
+};
+
+/// Namespace of the openEHR XML schemas.
+pub const OPENEHR_NAMESPACE: &str = "http://schemas.openehr.org/v1";
+
+/// Namespace of the `xsi:type` attribute.
+const XSI_NAMESPACE: &str = "http://www.w3.org/2001/XMLSchema-instance";
+
+/// Name of the attribute holding the type discriminator, the counterpart of `_type` in JSON.
+const XSI_TYPE: &str = "xsi:type";
+
+/// Name of the elements holding the items of an array that is itself an item of an array.
+const ITEM_ELEMENT: &str = "item";
+
+/// Writes the value as canonical openEHR XML in an element with the given name. The element is
+/// in the openEHR namespace and has an `xsi:type` attribute derived from [`Any::type_of`], such
+/// as `<value xmlns="http://schemas.openehr.org/v1" xmlns:xsi="..." xsi:type="INTEGER">42</value>`.
+/// The members of an object become child elements, the items of an array repeated child
+/// elements, and polymorphic members get an `xsi:type` attribute of their own.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidDocument`] if the value cannot be serialised or the element name is
+/// not a valid XML name.
+pub fn to_xml<T>(element_name: &str, value: &T) -> Result<String, Error>
+where
+    T: Any + Serialize,
+{
+    let type_name = openehr_type_name(&value.type_of());
+    let mut root = BytesStart::new(element_name);
+    root.push_attribute(("xmlns", OPENEHR_NAMESPACE));
+    root.push_attribute(("xmlns:xsi", XSI_NAMESPACE));
+    root.push_attribute((XSI_TYPE, type_name.as_str()));
+    let mut writer = Writer::new(Vec::new());
+    write_element(&mut writer, root, &to_json_value(value)?)?;
+    String::from_utf8(writer.into_inner())
+        .map_err(|error| Error::InvalidDocument(error.to_string()))
+}
+
+/// Reads a value of type `T` from canonical openEHR XML. The root element must be in the
+/// openEHR namespace and have an `xsi:type` attribute naming `T`. The `xsi:type` attributes of
+/// nested elements are resolved through the
+/// [type registry](crate::foundation_types::TypeRegistry) and must name the type of the
+/// polymorphic member read from the element. See [`to_xml`].
+///
+/// # Errors
+///
+/// Returns [`Error::MissingNamespace`] if the root element is not in the openEHR namespace,
+/// [`Error::MissingType`] if it has no `xsi:type` attribute, [`Error::UnknownType`] if an
+/// `xsi:type` attribute names a type that does not exist, [`Error::IncompatibleType`] if it
+/// names a type the value read is not an instance of and [`Error::InvalidDocument`] if the
+/// document is not valid XML, its content does not match `T` or an `xsi:type` attribute is on
+/// an element that is not a polymorphic member.
+pub fn from_xml<T>(xml: &str) -> Result<T, Error>
+where
+    T: Any + Serialize + DeserializeOwned,
+{
+    let (root, root_start) = read_elements(xml)?;
+    let found = root.type_name.clone().ok_or(Error::MissingType)?;
+    check_known_types(&root)?;
+    let read: T = quick_xml::de::from_str(xml[root_start..].trim_start())
+        .map_err(|error| Error::InvalidDocument(error.to_string()))?;
+    check_type(&read, &found)?;
+    check_member_types(&root, &to_json_value(&read)?)?;
+    Ok(read)
+}
+
+// ----------------------------------------------------------------------------------------------
+//                                         private functions
+// ----------------------------------------------------------------------------------------------
+/// An element of a document, reduced to its local name, its `xsi:type` and its child elements.
+struct Element {
+    name: String,
+    type_name: Option<String>,
+    children: Vec<Element>,
+}
+
+/// Reads the elements of a document, returning the root element and its position in the
+/// document. The root element must be in the openEHR namespace.
+fn read_elements(xml: &str) -> Result<(Element, usize), Error> {
+    let mut reader = NsReader::from_str(xml);
+    let mut root_start = 0;
+    let mut open: Vec<Element> = Vec::new();
+    loop {
+        let position = reader.buffer_position() as usize;
+        let (namespace, event) = reader
+            .read_resolved_event()
+            .map_err(|error| Error::InvalidDocument(error.to_string()))?;
+        let is_start = matches!(event, Event::Start(_));
+        let closed = match event {
+            Event::Start(start) | Event::Empty(start) => {
+                if open.is_empty() {
+                    if namespace != ResolveResult::Bound(Namespace(OPENEHR_NAMESPACE.as_bytes())) {
+                        return Err(Error::MissingNamespace);
+                    }
+                    root_start = position;
+                }
+                let element = Element {
+                    name: String::from_utf8_lossy(start.local_name().as_ref()).into_owned(),
+                    type_name: type_attribute(&reader, &start)?,
+                    children: Vec::new(),
+                };
+                if is_start {
+                    open.push(element);
+                    continue;
+                }
+                element
+            }
+            Event::End(_) => open.pop().expect("end tag matches a start tag"),
+            Event::Eof => return Err(Error::InvalidDocument("root element expected".to_string())),
+            _ => continue,
+        };
+        match open.last_mut() {
+            Some(parent) => parent.children.push(closed),
+            None => return Ok((closed, root_start)),
+        }
+    }
+}
+
+/// Returns the value of the `xsi:type` attribute of the element, without a namespace prefix.
+fn type_attribute(reader: &NsReader<&[u8]>, element: &BytesStart)
+    -> Result<Option<String>, Error>
+{
+    for attribute in element.attributes() {
+        let attribute = attribute.map_err(|error| Error::InvalidDocument(error.to_string()))?;
+        let (namespace, local_name) = reader.resolve_attribute(attribute.key);
+        if namespace == ResolveResult::Bound(Namespace(XSI_NAMESPACE.as_bytes()))
+            && local_name.as_ref() == b"type"
+        {
+            let value = attribute
+                .unescape_value()
+                .map_err(|error| Error::InvalidDocument(error.to_string()))?;
+            let type_name = value.rsplit(':').next().unwrap_or(&value);
+            return Ok(Some(type_name.to_string()));
+        }
+    }
+    Ok(None)
+}
+
+/// Checks that the `xsi:type` attributes of the element and its descendants name known types.
+fn check_known_types(element: &Element) -> Result<(), Error> {
+    if let Some(type_name) = &element.type_name {
+        check_known(type_name)?;
+    }
+    element.children.iter().try_for_each(check_known_types)
+}
+
+/// Checks the `xsi:type` attributes of the descendants of an element against the value read
+/// from it, given as [`to_json_value`] writes it. The value of each typed element is read back
+/// as the type it names, so that a polymorphic member must be an instance of that type.
+fn check_member_types(element: &Element, value: &Value) -> Result<(), Error> {
+    let mut positions: HashMap<&str, usize> = HashMap::new();
+    for child in &element.children {
+        let position = positions.entry(child.name.as_str()).or_default();
+        let Some(member) = member_value(value, &child.name, *position) else {
+            check_untyped(child)?;
+            continue;
+        };
+        *position += 1;
+        if let Some(found) = &child.type_name {
+            let read = OpenEhrValue::from_json_as(member.clone(), &rust_type_name(found))
+                .map_err(|error| {
+                    Error::InvalidDocument(format!(
+                        "element {} does not hold a {found}: {error}",
+                        child.name
+                    ))
+                })?;
+            check_type(&read, found)?;
+        }
+        check_member_types(child, member)?;
+    }
+    Ok(())
+}
+
+/// Returns the value of the child element with the given name and position among the children
+/// of that name, which is a member of an object or, for `item` elements, an item of an array.
+fn member_value<'a>(value: &'a Value, name: &str, position: usize) -> Option<&'a Value> {
+    match value {
+        Value::Object(members) => match members.get(name)? {
+            Value::Array(items) => items.get(position),
+            member => (position == 0).then_some(member),
+        },
+        Value::Array(items) if name == ITEM_ELEMENT => items.get(position),
+        _ => None,
+    }
+}
+
+/// Checks that neither the element, whose content was not read into the value, nor its
+/// descendants have an `xsi:type` attribute.
+fn check_untyped(element: &Element) -> Result<(), Error> {
+    if let Some(found) = &element.type_name {
+        return Err(Error::InvalidDocument(format!(
+            "xsi:type {found} on element {}, which is not a polymorphic member",
+            element.name
+        )));
+    }
+    element.children.iter().try_for_each(check_untyped)
+}
+
+/// Writes a JSON value as an element, starting with the given start tag. A `_type` member
+/// becomes an `xsi:type` attribute, the other members of an object become child elements and
+/// the items of an array member become repeated child elements. Primitive values become text.
+fn write_element(
+    writer: &mut Writer<Vec<u8>>,
+    mut start: BytesStart,
+    value: &Value,
+) -> Result<(), Error> {
+    let name = String::from_utf8_lossy(start.name().as_ref()).into_owned();
+    if !is_xml_name(&name) {
+        return Err(Error::InvalidDocument(format!("invalid element name {name}")));
+    }
+    if let Value::Object(members) = value {
+        if let Some(Value::String(type_name)) = members.get(TYPE_MEMBER) {
+            if start.try_get_attribute(XSI_TYPE).ok().flatten().is_none() {
+                start.push_attribute((XSI_TYPE, type_name.as_str()));
+            }
+        }
+    }
+    let write_error = |error: std::io::Error| Error::InvalidDocument(error.to_string());
+    writer.write_event(Event::Start(start)).map_err(write_error)?;
+    let text = match value {
+        Value::Null => None,
+        Value::Bool(value) => Some(value.to_string()),
+        Value::Number(value) => Some(value.to_string()),
+        Value::String(value) => Some(value.clone()),
+        Value::Array(items) => {
+            for item in items {
+                write_element(writer, BytesStart::new(ITEM_ELEMENT), item)?;
+            }
+            None
+        }
+        Value::Object(members) => {
+            for (member, value) in members.iter().filter(|(member, _)| *member != TYPE_MEMBER) {
+                write_member(writer, member, value)?;
+            }
+            None
+        }
+    };
+    if let Some(text) = text {
+        writer.write_event(Event::Text(BytesText::new(&text))).map_err(write_error)?;
+    }
+    writer.write_event(Event::End(BytesEnd::new(name))).map_err(write_error)
+}
+
+/// Writes a member of an object as a child element, or as one child element per item if it is
+/// an array. Members without a value are left out.
+fn write_member(writer: &mut Writer<Vec<u8>>, member: &str, value: &Value) -> Result<(), Error> {
+    match value {
+        Value::Null => Ok(()),
+        Value::Array(items) => items
+            .iter()
+            .try_for_each(|item| write_element(writer, BytesStart::new(member), item)),
+        value => write_element(writer, BytesStart::new(member), value),
+    }
+}
+
+/// Returns true if the name is a valid XML element name, such as `value` or `v1:value`.
+fn is_xml_name(name: &str) -> bool {
+    let mut chars = name.chars();
+    chars
+        .next()
+        .is_some_and(|first| first.is_alphabetic() || first == '_' || first == ':')
+        && chars.all(|c| c.is_alphanumeric() || matches!(c, '_' | ':' | '-' | '.'))
+}
@@ -5,7 +5,7 @@ mod tests {
     #[test]
     fn boolean_new_creates_instance() {
         let b = Boolean::new(true);
-        assert_eq!(b.value, true);
+        assert!(b.value);
     }
 
     #[test]
@@ -0,0 +1,47 @@
+#[cfg(test)]
+mod tests {
+    use base::foundation_types::{Cardinality, Integer, Interval, IntervalError, MultiplicityInterval};
+
+    #[test]
+    fn multiplicity_queries() {
+        assert!(MultiplicityInterval::new(0, None).unwrap().is_open());
+        assert!(MultiplicityInterval::new(0, Some(1)).unwrap().is_optional());
+        assert!(MultiplicityInterval::new(1, Some(1)).unwrap().is_mandatory());
+        assert!(MultiplicityInterval::new(0, Some(0)).unwrap().is_prohibited());
+        assert!(!MultiplicityInterval::new(0, Some(1)).unwrap().is_mandatory());
+    }
+
+    #[test]
+    fn multiplicity_is_interval() {
+        let multiplicity = MultiplicityInterval::new(1, None).unwrap();
+        assert!(multiplicity.has(&Integer::new(1)));
+        assert!(multiplicity.has(&Integer::new(100)));
+        assert!(!multiplicity.has(&Integer::new(0)));
+        assert!(multiplicity.contains(&MultiplicityInterval::new(2, Some(3)).unwrap()));
+    }
+
+    #[test]
+    fn multiplicity_to_string() {
+        assert_eq!(MultiplicityInterval::new(0, None).unwrap().to_string(), "0..*");
+        assert_eq!(MultiplicityInterval::new(1, Some(3)).unwrap().to_string(), "1..3");
+    }
+
+    #[test]
+    fn reject_invalid_multiplicity() {
+        let multiplicity = MultiplicityInterval::new(-1, Some(1));
+        assert!(multiplicity.err() == Some(IntervalError::NegativeMultiplicity));
+        let multiplicity = MultiplicityInterval::new(2, Some(1));
+        assert!(multiplicity.err() == Some(IntervalError::ReversedLimits));
+    }
+
+    #[test]
+    fn cardinality_kinds() {
+        let bag = Cardinality::new(MultiplicityInterval::new(0, None).unwrap(), false, false);
+        let list = Cardinality::new(MultiplicityInterval::new(0, None).unwrap(), true, false);
+        let set = Cardinality::new(MultiplicityInterval::new(1, None).unwrap(), false, true);
+        assert!(bag.is_bag() && !bag.is_list() && !bag.is_set());
+        assert!(list.is_list() && !list.is_bag());
+        assert!(set.is_set() && set.interval.is_mandatory());
+    }
+
+}
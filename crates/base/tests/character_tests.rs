@@ -61,4 +61,23 @@ mod tests {
         let c2 = Character::new('b');
         assert_eq!(c1.partial_cmp(&c2).unwrap(), Ordering::Less);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn classify_characters() {
+        assert!(Character::new('ж').is_letter());
+        assert!(!Character::new('7').is_letter());
+        assert!(Character::new('٣').is_digit());
+        assert!(!Character::new('½').is_digit());
+        assert!(Character::new('\u{a0}').is_whitespace());
+        assert!(Character::new('Ж').is_uppercase());
+        assert!(Character::new('ß').is_lowercase());
+    }
+
+    #[test]
+    fn map_case() {
+        assert_eq!(Character::new('ß').to_uppercase(), "SS");
+        assert_eq!(Character::new('Ж').to_lowercase(), "ж");
+        assert_eq!(Character::new('1').to_uppercase(), "1");
+    }
+
+}
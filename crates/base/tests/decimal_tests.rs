@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use base::foundation_types::{Any, Decimal, Double, Literal, NumericError, ParseError};
+
+    fn decimal(s: &str) -> Decimal {
+        Decimal::parse(s).unwrap()
+    }
+
+    #[test]
+    fn parse_keeps_scale() {
+        let value = decimal("5.10");
+        assert_eq!(value.scale().value, 2);
+        assert_eq!(value.to_string(), "5.10");
+        assert_eq!(decimal("-0.003").to_string(), "-0.003");
+        assert_eq!(decimal("+42").to_string(), "42");
+    }
+
+    #[test]
+    fn parse_rejects_invalid_text() {
+        assert_eq!(Decimal::parse("").err(), Some(ParseError::Empty(Literal::Decimal)));
+        for text in ["5,10", "1e5", ".5", "5.", "1.2.3", "--1", "abc"] {
+            let invalid = ParseError::InvalidFormat(Literal::Decimal);
+            assert_eq!(Decimal::parse(text).err(), Some(invalid), "{}", text);
+        }
+    }
+
+    #[test]
+    fn new_from_unscaled_digits() {
+        assert_eq!(Decimal::new(510, 2).to_string(), "5.10");
+        assert_eq!(Decimal::new(-7, 0).to_string(), "-7");
+    }
+
+    #[test]
+    fn add_is_exact() {
+        let result = decimal("0.1") + decimal("0.2");
+        assert!(result == decimal("0.3"));
+        assert_eq!(result.to_string(), "0.3");
+    }
+
+    #[test]
+    fn arithmetic_scales() {
+        assert_eq!((decimal("5.10") + decimal("1.2")).to_string(), "6.30");
+        assert_eq!((decimal("5.10") - decimal("0.1")).to_string(), "5.00");
+        assert_eq!((decimal("1.5") * decimal("0.25")).to_string(), "0.375");
+        assert_eq!((-decimal("2.50")).to_string(), "-2.50");
+    }
+
+    #[test]
+    fn divide() {
+        assert_eq!((decimal("1") / decimal("4")).to_string(), "0.25");
+        assert_eq!((decimal("1") / decimal("3")).scale().value, 32);
+        let zero = decimal("0.0");
+        assert_eq!(decimal("1").try_div(&zero).err(), Some(NumericError::DivisionByZero));
+    }
+
+    #[test]
+    fn round_half_away_from_zero() {
+        assert_eq!(decimal("2.345").round(2).to_string(), "2.35");
+        assert_eq!(decimal("-2.345").round(2).to_string(), "-2.35");
+        assert_eq!(decimal("2.3").round(3).to_string(), "2.300");
+    }
+
+    #[test]
+    fn equality_and_order_ignore_scale() {
+        assert!(decimal("5.10") == decimal("5.1"));
+        assert!(decimal("5.10").is_equal(&decimal("5.100")));
+        assert!(decimal("-1.5") < decimal("0.01"));
+        assert!(decimal("10") > decimal("9.999"));
+    }
+
+    #[test]
+    fn convert_to_and_from_double() {
+        assert_eq!(Decimal::try_from(Double::new(0.1)).unwrap().to_string(), "0.1");
+        assert_eq!(decimal("5.10").to_double().value, 5.1);
+        assert_eq!(Double::from(decimal("-0.25")).value, -0.25);
+        let nan = Double::new(f64::NAN);
+        assert_eq!(Decimal::try_from(nan).err(), Some(NumericError::NotANumber));
+        let infinity = Double::new(f64::INFINITY);
+        assert_eq!(Decimal::try_from(infinity).err(), Some(NumericError::Overflow));
+    }
+
+    #[test]
+    fn decimal_type_of() {
+        assert_eq!(decimal("1.0").type_of(), "Decimal");
+        assert!(decimal("1.0").instance_of("Decimal"));
+    }
+
+    #[test]
+    fn sum_and_product_keep_scale() {
+        let doses = vec![decimal("0.10"), decimal("0.20"), decimal("1.5")];
+        assert_eq!(doses.iter().sum::<Decimal>().to_string(), "1.80");
+        assert_eq!(doses.into_iter().product::<Decimal>().to_string(), "0.03000");
+    }
+
+    #[test]
+    fn operators_on_references() {
+        let a = decimal("2.50");
+        let b = decimal("0.5");
+        assert_eq!((&a + &b).to_string(), "3.00");
+        assert_eq!((&a - &b).to_string(), "2.00");
+        assert_eq!((&a * &b).to_string(), "1.250");
+        assert_eq!((&a / &b).to_string(), "5");
+        assert_eq!((-&a).to_string(), "-2.50");
+    }
+
+}
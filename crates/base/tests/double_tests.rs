@@ -170,4 +170,37 @@ mod tests {
         assert_eq!(result.value, 1.0);
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn average_of_borrowed_values() {
+        let values = [Double::new(1.5), Double::new(2.5), Double::new(5.0)];
+        let total: Double = values.iter().sum();
+        let average = total / Double::new(values.len() as f64);
+        assert_eq!(average.value, 3.0);
+        assert_eq!(values.len(), 3);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn operators_on_references() {
+        let a = Double::new(3.0);
+        let b = Double::new(1.5);
+        assert_eq!((&a + &b).value, 4.5);
+        assert_eq!((&a - b).value, 1.5);
+        assert_eq!((a * &b).value, 4.5);
+        assert_eq!((&a / &b).value, 2.0);
+        assert_eq!((-&a).value, -3.0);
+        assert_eq!(a.value, 3.0);
+    }
+
+    #[test]
+    fn product_of_values() {
+        let values = [Double::new(2.0), Double::new(0.5), Double::new(3.0)];
+        assert_eq!(values.into_iter().product::<Double>().value, 3.0);
+    }
+
+    #[test]
+    fn debug_format() {
+        assert_eq!(format!("{:?}", Double::new(1.5)), "Double { value: 1.5 }");
+    }
+
+}
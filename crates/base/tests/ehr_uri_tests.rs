@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+    use base::foundation_types::{EhrUri, EhrUriError, Uri, UriError, UriErrorContext};
+
+    const EHR_ID: &str = "347a5490-55ee-4da9-b91a-9bba710f730e";
+    const VERSION_ID: &str = "87284370-2d4b-4e3d-a3f3-f303d2f4f34b::rmh.nhs.net::2";
+
+    #[test]
+    fn parse_full_uri() {
+        let text = format!(
+            "ehr://rmh.nhs.net/{EHR_ID}/{VERSION_ID}#/content[openEHR-EHR-SECTION.vital_signs.v1]"
+        );
+        let ehr_uri = EhrUri::parse(text).unwrap();
+        assert_eq!(ehr_uri.system_id(), "rmh.nhs.net");
+        assert_eq!(ehr_uri.ehr_id(), EHR_ID);
+        assert_eq!(
+            ehr_uri.versioned_object_uid(),
+            Some("87284370-2d4b-4e3d-a3f3-f303d2f4f34b")
+        );
+        assert_eq!(ehr_uri.creating_system_id(), Some("rmh.nhs.net"));
+        assert_eq!(ehr_uri.version(), Some("2"));
+        assert_eq!(
+            ehr_uri.archetype_path(),
+            Some("/content[openEHR-EHR-SECTION.vital_signs.v1]")
+        );
+        assert_eq!(ehr_uri.uri().scheme(), Some("ehr"));
+    }
+
+    #[test]
+    fn parse_ehr_only() {
+        let ehr_uri = EhrUri::parse(format!("ehr://1.2.840.113554/{EHR_ID}/")).unwrap();
+        assert_eq!(ehr_uri.system_id(), "1.2.840.113554");
+        assert_eq!(ehr_uri.ehr_id(), EHR_ID);
+        assert_eq!(ehr_uri.versioned_object_uid(), None);
+        assert_eq!(ehr_uri.version(), None);
+        assert_eq!(ehr_uri.archetype_path(), None);
+    }
+
+    #[test]
+    fn parse_versioned_object() {
+        let ehr_uri = EhrUri::parse(format!("ehr://rmh.nhs.net/{EHR_ID}/{EHR_ID}")).unwrap();
+        assert_eq!(ehr_uri.versioned_object_uid(), Some(EHR_ID));
+        assert_eq!(ehr_uri.creating_system_id(), None);
+        assert_eq!(ehr_uri.version(), None);
+    }
+
+    #[test]
+    fn parse_archetype_path_with_predicate() {
+        let archetype_path = "/data/events[at0006]/data/items[at0004 and name/value='BP']";
+        let ehr_uri = EhrUri::parse(format!("ehr://rmh.nhs.net/{EHR_ID}#{archetype_path}")).unwrap();
+        assert_eq!(ehr_uri.archetype_path(), Some(archetype_path));
+        assert_eq!(EhrUri::parse(ehr_uri.to_string()).unwrap(), ehr_uri);
+        let ehr_uri = EhrUri::parse(format!("ehr://rmh.nhs.net/{EHR_ID}#/items%5Bat0001%5D")).unwrap();
+        assert_eq!(ehr_uri.archetype_path(), Some("/items[at0001]"));
+    }
+
+    #[test]
+    fn convert_from_uri() {
+        let uri = Uri::parse(format!("ehr://rmh.nhs.net/{EHR_ID}#/items%5Bat0001%5D")).unwrap();
+        let ehr_uri = EhrUri::try_from(uri.clone()).unwrap();
+        assert_eq!(ehr_uri.archetype_path(), Some("/items[at0001]"));
+        assert_eq!(ehr_uri.to_string(), uri.to_string());
+        assert_eq!(Uri::from(ehr_uri), uri);
+    }
+
+    #[test]
+    fn invalid_uris() {
+        let invalid = |text: String| EhrUri::parse(text).err();
+        assert_eq!(
+            invalid(format!("http://rmh.nhs.net/{EHR_ID}")),
+            Some(EhrUriError::WrongScheme("http".to_string()))
+        );
+        assert_eq!(
+            invalid(format!("ehr:///{EHR_ID}")),
+            Some(EhrUriError::InvalidSystemId)
+        );
+        assert_eq!(
+            invalid(format!("ehr://rmh.nhs.net:8080/{EHR_ID}")),
+            Some(EhrUriError::InvalidSystemId)
+        );
+        assert_eq!(
+            invalid("ehr://rmh.nhs.net/".to_string()),
+            Some(EhrUriError::InvalidEhrId)
+        );
+        assert_eq!(
+            invalid("ehr://rmh.nhs.net/not-an-id!".to_string()),
+            Some(EhrUriError::InvalidEhrId)
+        );
+        assert_eq!(
+            invalid(format!("ehr://rmh.nhs.net/{EHR_ID}/{EHR_ID}::rmh.nhs.net::two")),
+            Some(EhrUriError::InvalidVersionId(format!("{EHR_ID}::rmh.nhs.net::two")))
+        );
+        assert_eq!(
+            invalid(format!("ehr://rmh.nhs.net/{EHR_ID}/{VERSION_ID}/extra")),
+            Some(EhrUriError::UnexpectedPathSegment("extra".to_string()))
+        );
+        assert_eq!(
+            invalid(format!("ehr://rmh.nhs.net/{EHR_ID}#/items[at0001")),
+            Some(EhrUriError::InvalidArchetypePath("/items[at0001".to_string()))
+        );
+        assert_eq!(
+            invalid(format!("ehr://rmh.nhs.net/{EHR_ID}/%GG")),
+            Some(EhrUriError::InvalidUri(UriError::IllegalPercentEncoding))
+        );
+        assert_eq!(
+            invalid(format!("ehr://rmh.nhs.net/{EHR_ID}/a^b")),
+            Some(EhrUriError::InvalidUri(UriError::IllegalCharacter(UriErrorContext::Path)))
+        );
+    }
+
+}
@@ -0,0 +1,79 @@
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+    use base::foundation_types::{
+        Any,
+        Boolean,
+        Double,
+        Function,
+        Integer,
+        List,
+        Procedure,
+        Routine,
+        String,
+    };
+
+    #[test]
+    fn call_function() {
+        let greater = Function::new(|(a, b): (Integer, Integer)| Boolean::new(a.value > b.value));
+        assert!(greater.call((Integer::new(2), Integer::new(1))).value);
+        assert!(!greater.call((Integer::new(1), Integer::new(2))).value);
+    }
+
+    #[test]
+    fn function_signature() {
+        let ratio = Function::new(|(a, b): (Integer, Integer)| a / b);
+        assert_eq!(ratio.argument_types(), vec!["Integer", "Integer"]);
+        assert_eq!(ratio.result_type(), "Double");
+        assert_eq!(ratio.arity(), 2);
+        assert_eq!(ratio.type_of(), "Function<<Integer, Integer>, Double>");
+        assert!(ratio.instance_of("Function<<Integer, Integer>, Double>"));
+        assert!(ratio.call((Integer::new(1), Integer::new(4))) == Double::new(0.25));
+    }
+
+    #[test]
+    fn function_without_arguments() {
+        let answer = Function::new(|()| Integer::new(42));
+        assert_eq!(answer.arity(), 0);
+        assert_eq!(answer.type_of(), "Function<<>, Integer>");
+        assert!(answer.call(()) == Integer::new(42));
+    }
+
+    #[test]
+    fn function_equality() {
+        let a = Function::new(|(value,): (Integer,)| value);
+        let b = a.clone();
+        let c = Function::new(|(value,): (Integer,)| value);
+        assert!(a == b);
+        assert!(a.is_equal(&b));
+        assert!(a != c);
+    }
+
+    #[test]
+    fn call_procedure() {
+        let log = Rc::new(RefCell::new(Vec::new()));
+        let sink = Rc::clone(&log);
+        let record = Procedure::new(move |(message,): (String,)| sink.borrow_mut().push(message));
+        record.call(("first".to_string(),));
+        record.call(("second".to_string(),));
+        assert_eq!(*log.borrow(), vec!["first", "second"]);
+        assert_eq!(record.argument_types(), vec!["String"]);
+        assert_eq!(record.type_of(), "Procedure<<String>>");
+    }
+
+    #[test]
+    fn evaluate_rules() {
+        let rules: List<Function<(Integer,), Boolean>> = vec![
+            Function::new(|(value,): (Integer,)| Boolean::new(value.value >= 0)),
+            Function::new(|(value,): (Integer,)| Boolean::new(value.value <= 150)),
+        ]
+        .into();
+        let valid = |age: i32| rules.clone().into_iter().all(|rule| rule.call((Integer::new(age),)).value);
+        assert!(valid(42));
+        assert!(!valid(-1));
+        assert!(!valid(200));
+        assert_eq!(rules.type_of(), "List<Function<<Integer>, Boolean>>");
+    }
+
+}
@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use base::foundation_types::{Any, Container, Hash, Integer, String};
+
+    fn codes() -> Hash<String, Integer> {
+        [("at0001", 1), ("at0002", 2), ("at0003", 3)]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), Integer::new(value)))
+            .collect()
+    }
+
+    #[test]
+    fn lookup_by_key() {
+        let hash = codes();
+        assert!(hash.has_key(&"at0002".to_string()));
+        assert!(!hash.has_key(&"at0004".to_string()));
+        assert!(hash.item(&"at0003".to_string()) == Some(&Integer::new(3)));
+        assert!(hash.item(&"at0004".to_string()).is_none());
+    }
+
+    #[test]
+    fn insert_and_remove() {
+        let mut hash = codes();
+        assert!(hash.insert("at0001".to_string(), Integer::new(10)) == Some(Integer::new(1)));
+        assert!(hash.insert("at0004".to_string(), Integer::new(4)).is_none());
+        assert!(hash.count() == Integer::new(4));
+        assert!(hash.remove(&"at0002".to_string()) == Some(Integer::new(2)));
+        assert!(hash.remove(&"at0002".to_string()).is_none());
+        assert_eq!(hash.keys().count(), 3);
+    }
+
+    #[test]
+    fn hash_contains_items() {
+        let hash = codes();
+        assert!(hash.has(&Integer::new(2)));
+        assert!(!hash.has(&Integer::new(4)));
+        assert!(hash.for_all(|item| item.value > 0));
+        assert!(hash.matching(|item| item.value >= 2).count() == Integer::new(2));
+        assert!(Hash::<String, Integer>::new().is_empty());
+    }
+
+    #[test]
+    fn hash_equality_and_type() {
+        let reversed: Hash<String, Integer> = [("at0003", 3), ("at0002", 2), ("at0001", 1)]
+            .into_iter()
+            .map(|(key, value)| (key.to_string(), Integer::new(value)))
+            .collect();
+        assert!(codes() == reversed);
+        assert_eq!(codes().type_of(), "Hash<String, Integer>");
+    }
+
+}
@@ -1,6 +1,6 @@
 #[cfg(test)]
 mod tests {
-    use base::foundation_types::{Any, Double, Integer64, Numeric};
+    use base::foundation_types::{Any, Double, Integer64, Numeric, NumericError};
 
     #[test]
     fn add_two_positive_integers() {
@@ -172,4 +172,77 @@ mod tests {
         assert_eq!(result.value, 1.0);
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn checked_add_overflows() {
+        let max = Integer64::new(i64::MAX);
+        assert!(max.checked_add(&Integer64::new(1)).is_none());
+        assert_eq!(max.checked_add(&Integer64::new(-1)).unwrap().value, i64::MAX - 1);
+    }
+
+    #[test]
+    fn checked_div_truncates_and_rejects_zero() {
+        let a = Integer64::new(-7);
+        assert_eq!(a.checked_div(&Integer64::new(2)).unwrap().value, -3);
+        assert!(a.checked_div(&Integer64::new(0)).is_none());
+        assert!(Integer64::new(i64::MIN).checked_div(&Integer64::new(-1)).is_none());
+    }
+
+    #[test]
+    fn checked_neg_abs_and_pow() {
+        let min = Integer64::new(i64::MIN);
+        assert!(min.checked_neg().is_none());
+        assert!(min.checked_abs().is_none());
+        assert_eq!(Integer64::new(-3).checked_abs().unwrap().value, 3);
+        assert_eq!(Integer64::new(3).checked_pow(4).unwrap().value, 81);
+        assert!(Integer64::new(2).checked_pow(64).is_none());
+    }
+
+    #[test]
+    fn saturating_arithmetic_clamps_to_range() {
+        let max = Integer64::new(i64::MAX);
+        let min = Integer64::new(i64::MIN);
+        assert_eq!(max.saturating_add(&Integer64::new(1)).value, i64::MAX);
+        assert_eq!(min.saturating_sub(&Integer64::new(1)).value, i64::MIN);
+        assert_eq!(max.saturating_mul(&Integer64::new(-2)).value, i64::MIN);
+        assert_eq!(min.saturating_neg().value, i64::MAX);
+        assert_eq!(min.saturating_abs().value, i64::MAX);
+        assert_eq!(Integer64::new(10).saturating_pow(40).value, i64::MAX);
+    }
+
+    #[test]
+    fn try_arithmetic_reports_errors() {
+        let max = Integer64::new(i64::MAX);
+        assert_eq!(max.try_add(&Integer64::new(1)).err(), Some(NumericError::Overflow));
+        assert_eq!(max.try_mul(&Integer64::new(2)).err(), Some(NumericError::Overflow));
+        assert_eq!(Integer64::new(i64::MIN).try_neg().err(), Some(NumericError::Overflow));
+        let zero = Integer64::new(0);
+        assert_eq!(Integer64::new(1).try_div(&zero).err(), Some(NumericError::DivisionByZero));
+        assert_eq!(Integer64::new(1).try_rem(&zero).err(), Some(NumericError::DivisionByZero));
+        assert_eq!(Integer64::new(7).try_rem(&Integer64::new(3)).unwrap().value, 1);
+        assert_eq!(Integer64::new(7).try_sub(&Integer64::new(10)).unwrap().value, -3);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn add_operator_panics_on_overflow() {
+        let _ = Integer64::new(i64::MAX) + Integer64::new(1);
+    }
+
+    #[test]
+    fn try_from_double() {
+        assert_eq!(Integer64::try_from(Double::new(-2.9)).unwrap().value, -2);
+        let nan = Double::new(f64::NAN);
+        assert_eq!(Integer64::try_from(nan).err(), Some(NumericError::NotANumber));
+        assert_eq!(Integer64::try_from(Double::new(1e30)).err(), Some(NumericError::Overflow));
+        assert_eq!(Integer64::try_from(Double::new(i64::MIN as f64)).unwrap().value, i64::MIN);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn sum_of_borrowed_values() {
+        let values = [Integer64::new(i64::from(i32::MAX)), Integer64::new(1)];
+        assert_eq!(values.iter().sum::<Integer64>(), Integer64::new(1 << 31));
+        assert_eq!((&values[0] - &values[1]).value, i64::from(i32::MAX) - 1);
+    }
+
+}
@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
-    use base::foundation_types::{Any, Double, Integer, Numeric};
+    use std::collections::HashSet;
+    use base::foundation_types::{Any, Double, Integer, Numeric, NumericError};
 
     #[test]
     fn add_two_positive_integers() {
@@ -172,4 +173,97 @@ mod tests {
         assert_eq!(result.value, 1.0);
     }
 
-}
\ No newline at end of file
+    #[test]
+    fn checked_add_overflows() {
+        let max = Integer::new(i32::MAX);
+        assert!(max.checked_add(&Integer::new(1)).is_none());
+        assert_eq!(max.checked_add(&Integer::new(-1)).unwrap().value, i32::MAX - 1);
+    }
+
+    #[test]
+    fn checked_div_truncates_and_rejects_zero() {
+        let a = Integer::new(-7);
+        assert_eq!(a.checked_div(&Integer::new(2)).unwrap().value, -3);
+        assert!(a.checked_div(&Integer::new(0)).is_none());
+        assert!(Integer::new(i32::MIN).checked_div(&Integer::new(-1)).is_none());
+    }
+
+    #[test]
+    fn checked_neg_abs_and_pow() {
+        let min = Integer::new(i32::MIN);
+        assert!(min.checked_neg().is_none());
+        assert!(min.checked_abs().is_none());
+        assert_eq!(Integer::new(-3).checked_abs().unwrap().value, 3);
+        assert_eq!(Integer::new(3).checked_pow(4).unwrap().value, 81);
+        assert!(Integer::new(2).checked_pow(64).is_none());
+    }
+
+    #[test]
+    fn saturating_arithmetic_clamps_to_range() {
+        let max = Integer::new(i32::MAX);
+        let min = Integer::new(i32::MIN);
+        assert_eq!(max.saturating_add(&Integer::new(1)).value, i32::MAX);
+        assert_eq!(min.saturating_sub(&Integer::new(1)).value, i32::MIN);
+        assert_eq!(max.saturating_mul(&Integer::new(-2)).value, i32::MIN);
+        assert_eq!(min.saturating_neg().value, i32::MAX);
+        assert_eq!(min.saturating_abs().value, i32::MAX);
+        assert_eq!(Integer::new(10).saturating_pow(40).value, i32::MAX);
+    }
+
+    #[test]
+    fn try_arithmetic_reports_errors() {
+        let max = Integer::new(i32::MAX);
+        assert_eq!(max.try_add(&Integer::new(1)).err(), Some(NumericError::Overflow));
+        assert_eq!(max.try_mul(&Integer::new(2)).err(), Some(NumericError::Overflow));
+        assert_eq!(Integer::new(i32::MIN).try_neg().err(), Some(NumericError::Overflow));
+        let zero = Integer::new(0);
+        assert_eq!(Integer::new(1).try_div(&zero).err(), Some(NumericError::DivisionByZero));
+        assert_eq!(Integer::new(1).try_rem(&zero).err(), Some(NumericError::DivisionByZero));
+        assert_eq!(Integer::new(7).try_rem(&Integer::new(3)).unwrap().value, 1);
+        assert_eq!(Integer::new(7).try_sub(&Integer::new(10)).unwrap().value, -3);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn add_operator_panics_on_overflow() {
+        let _ = Integer::new(i32::MAX) + Integer::new(1);
+    }
+
+    #[test]
+    fn try_from_double() {
+        assert_eq!(Integer::try_from(Double::new(-2.9)).unwrap().value, -2);
+        let nan = Double::new(f64::NAN);
+        assert_eq!(Integer::try_from(nan).err(), Some(NumericError::NotANumber));
+        assert_eq!(Integer::try_from(Double::new(1e30)).err(), Some(NumericError::Overflow));
+        assert_eq!(Integer::try_from(Double::new(i32::MIN as f64)).unwrap().value, i32::MIN);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn operators_on_references() {
+        let a = Integer::new(7);
+        let b = Integer::new(2);
+        assert_eq!((&a + &b).value, 9);
+        assert_eq!((&a - &b).value, 5);
+        assert_eq!((&a * &b).value, 14);
+        assert_eq!((&a / &b).value, 3.5);
+        assert_eq!((&a % &b).value, 1);
+        assert_eq!((-&a).value, -7);
+        assert_eq!(a + b, Integer::new(9));
+    }
+
+    #[test]
+    fn sum_and_product() {
+        let values: Vec<Integer> = (1..=5).map(Integer::new).collect();
+        assert_eq!(values.iter().sum::<Integer>(), Integer::new(15));
+        assert_eq!(values.into_iter().product::<Integer>(), Integer::new(120));
+    }
+
+    #[test]
+    fn integers_are_hashable() {
+        let set: HashSet<Integer> = [1, 2, 2, 3].into_iter().map(Integer::new).collect();
+        assert_eq!(set.len(), 3);
+        assert!(set.contains(&Integer::new(2)));
+    }
+
+}
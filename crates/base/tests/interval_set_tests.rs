@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod tests {
+    use base::foundation_types::{
+        Any, Double, Integer, Interval, IntervalError, IntervalSet, Iso8601Date, ProperInterval,
+    };
+
+    fn closed(lower: i32, upper: i32) -> ProperInterval<Integer> {
+        ProperInterval::closed(Integer::new(lower), Integer::new(upper)).unwrap()
+    }
+
+    fn half_open(lower: i32, upper: i32) -> ProperInterval<Integer> {
+        ProperInterval::new(Some(Integer::new(lower)), Some(Integer::new(upper)), true, false).unwrap()
+    }
+
+    #[test]
+    fn intersection_of_intervals() {
+        let common = closed(0, 10).intersection(&half_open(5, 15)).unwrap().unwrap();
+        assert!(common == closed(5, 10));
+        assert!(closed(0, 4).intersection(&closed(5, 10)).unwrap().is_none());
+        assert!(half_open(0, 5).intersection(&closed(5, 10)).unwrap().is_none());
+    }
+
+    #[test]
+    fn union_of_intervals() {
+        let union = half_open(0, 5).union(&closed(5, 10)).unwrap();
+        assert_eq!(union.len(), 1);
+        assert!(union.intervals()[0] == closed(0, 10));
+        let union = closed(0, 4).union(&closed(6, 10)).unwrap();
+        assert_eq!(union.len(), 2);
+        assert!(!union.has(&Integer::new(5)));
+    }
+
+    #[test]
+    fn difference_of_intervals() {
+        let difference = closed(0, 10).difference(&closed(3, 5)).unwrap();
+        assert_eq!(difference.len(), 2);
+        assert!(difference.intervals()[0] == half_open(0, 3));
+        assert!(!difference.has(&Integer::new(5)));
+        assert!(difference.has(&Integer::new(6)));
+        assert!(closed(3, 5).difference(&closed(0, 10)).unwrap().is_empty());
+        assert!(closed(0, 2).difference(&closed(3, 5)).unwrap().intervals()[0] == closed(0, 2));
+    }
+
+    #[test]
+    fn merge_overlapping_and_touching_intervals() {
+        let set: IntervalSet<Integer> =
+            vec![closed(8, 12), half_open(0, 5), closed(5, 6), closed(20, 30), closed(9, 10)]
+                .into_iter()
+                .collect::<Result<_, _>>()
+                .unwrap();
+        assert_eq!(set.len(), 3);
+        assert!(set.intervals()[0] == closed(0, 6));
+        assert!(set.intervals()[1] == closed(8, 12));
+        assert!(set.intervals()[2] == closed(20, 30));
+    }
+
+    #[test]
+    fn unbounded_intervals_in_set() {
+        let mut set = IntervalSet::new();
+        set.insert(ProperInterval::new(Some(Double::new(10.0)), None, false, false).unwrap()).unwrap();
+        set.insert(ProperInterval::new(None, Some(Double::new(2.5)), false, false).unwrap()).unwrap();
+        assert_eq!(set.len(), 2);
+        assert!(set.intervals()[0].lower_unbounded());
+        set.insert(ProperInterval::closed(Double::new(2.5), Double::new(10.0)).unwrap()).unwrap();
+        assert_eq!(set.len(), 1);
+        assert!(set.intervals()[0] == ProperInterval::unbounded());
+    }
+
+    #[test]
+    fn set_algebra() {
+        let a: IntervalSet<Integer> =
+            vec![closed(0, 10), closed(20, 30)].into_iter().collect::<Result<_, _>>().unwrap();
+        let b: IntervalSet<Integer> = vec![closed(5, 25)].into_iter().collect::<Result<_, _>>().unwrap();
+        let intersection = a.intersection(&b).unwrap();
+        assert!(intersection.intervals() == [closed(5, 10), closed(20, 25)]);
+        let union = a.union(&b).unwrap();
+        assert!(union.intervals() == [closed(0, 30)]);
+        let difference = a.difference(&b).unwrap();
+        assert_eq!(difference.len(), 2);
+        assert!(difference.intervals()[0] == half_open(0, 5));
+        assert!(difference.has(&Integer::new(30)) && !difference.has(&Integer::new(25)));
+    }
+
+    #[test]
+    fn gaps_between_intervals() {
+        let set: IntervalSet<Integer> =
+            vec![closed(0, 4), closed(6, 10), half_open(12, 15), closed(15, 20)]
+                .into_iter()
+                .collect::<Result<_, _>>()
+                .unwrap();
+        let gaps = set.gaps();
+        assert_eq!(gaps.len(), 2);
+        assert!(gaps.has(&Integer::new(5)) && !gaps.has(&Integer::new(4)));
+        assert!(gaps.has(&Integer::new(11)) && !gaps.has(&Integer::new(12)));
+        assert!(IntervalSet::<Integer>::new().gaps().is_empty());
+    }
+
+    #[test]
+    fn reject_incomparable_partial_dates() {
+        let date = |value: &str| Iso8601Date::parse(value).unwrap();
+        let march = ProperInterval::closed(date("2024-03-01"), date("2024-03-31")).unwrap();
+        let year = ProperInterval::closed(date("2024"), date("2024")).unwrap();
+        let mut set = IntervalSet::new();
+        set.insert(march.clone()).unwrap();
+        assert!(set.insert(year.clone()).err() == Some(IntervalError::IncomparableLimits));
+        assert_eq!(set.len(), 1);
+        assert!(set.intervals()[0] == march);
+        assert!(march.union(&year).err() == Some(IntervalError::IncomparableLimits));
+        assert!(march.intersection(&year).err() == Some(IntervalError::IncomparableLimits));
+        assert!(march.difference(&year).err() == Some(IntervalError::IncomparableLimits));
+        let collected = vec![march.clone(), year].into_iter().collect::<Result<IntervalSet<_>, _>>();
+        assert!(collected.err() == Some(IntervalError::IncomparableLimits));
+
+        let earlier_years = ProperInterval::closed(date("2020"), date("2023")).unwrap();
+        let union = march.union(&earlier_years).unwrap();
+        assert_eq!(union.len(), 2);
+        assert!(union.intervals()[0] == earlier_years);
+    }
+
+    #[test]
+    fn interval_set_type() {
+        let set: IntervalSet<Integer> = vec![closed(0, 4)].into_iter().collect::<Result<_, _>>().unwrap();
+        assert_eq!(set.type_of(), "IntervalSet<Integer>");
+        assert!(set.contains(&closed(1, 2)));
+        assert!(!set.contains(&closed(3, 5)));
+    }
+
+}
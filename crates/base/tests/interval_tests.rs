@@ -0,0 +1,117 @@
+#[cfg(test)]
+mod tests {
+    use base::foundation_types::{
+        Any,
+        Double,
+        Integer,
+        Interval,
+        IntervalError,
+        Iso8601Date,
+        PointInterval,
+        ProperInterval,
+    };
+
+    #[test]
+    fn closed_interval_has_limits() {
+        let interval = ProperInterval::closed(Integer::new(0), Integer::new(5)).unwrap();
+        assert!(interval.has(&Integer::new(0)));
+        assert!(interval.has(&Integer::new(3)));
+        assert!(interval.has(&Integer::new(5)));
+        assert!(!interval.has(&Integer::new(6)));
+        assert!(!interval.lower_unbounded());
+        assert!(interval.lower_included() && interval.upper_included());
+    }
+
+    #[test]
+    fn open_interval_excludes_limits() {
+        let interval = ProperInterval::new(Some(Double::new(2.5)), Some(Double::new(7.0)), false, false).unwrap();
+        assert!(!interval.has(&Double::new(2.5)));
+        assert!(interval.has(&Double::new(2.6)));
+        assert!(!interval.has(&Double::new(7.0)));
+    }
+
+    #[test]
+    fn unbounded_interval() {
+        let interval = ProperInterval::new(Some(Integer::new(10)), None, true, true).unwrap();
+        assert!(interval.upper_unbounded());
+        assert!(!interval.upper_included());
+        assert!(interval.has(&Integer::new(i32::MAX)));
+        assert!(!interval.has(&Integer::new(9)));
+        assert!(ProperInterval::<Integer>::unbounded().has(&Integer::new(i32::MIN)));
+    }
+
+    #[test]
+    fn reject_reversed_limits() {
+        let interval = ProperInterval::closed(Integer::new(5), Integer::new(0));
+        assert!(interval.err() == Some(IntervalError::ReversedLimits));
+        let interval = ProperInterval::new(Some(Integer::new(5)), Some(Integer::new(5)), true, false);
+        assert!(interval.unwrap().is_empty());
+    }
+
+    #[test]
+    fn contains_interval() {
+        let outer = ProperInterval::closed(Integer::new(0), Integer::new(10)).unwrap();
+        assert!(outer.contains(&ProperInterval::closed(Integer::new(0), Integer::new(10)).unwrap()));
+        assert!(outer.contains(&PointInterval::new(Integer::new(10))));
+        let open = ProperInterval::new(Some(Integer::new(0)), Some(Integer::new(10)), false, true).unwrap();
+        assert!(!open.contains(&outer));
+        assert!(outer.contains(&open));
+        assert!(!outer.contains(&ProperInterval::new(Some(Integer::new(5)), None, true, false).unwrap()));
+        assert!(ProperInterval::<Integer>::unbounded().contains(&outer));
+    }
+
+    #[test]
+    fn intersects_interval() {
+        let a = ProperInterval::closed(Integer::new(0), Integer::new(5)).unwrap();
+        let b = ProperInterval::closed(Integer::new(5), Integer::new(10)).unwrap();
+        let c = ProperInterval::new(Some(Integer::new(5)), Some(Integer::new(10)), false, true).unwrap();
+        assert!(a.intersects(&b));
+        assert!(!a.intersects(&c));
+        assert!(!c.intersects(&a));
+        assert!(c.intersects(&ProperInterval::new(None, Some(Integer::new(6)), false, false).unwrap()));
+    }
+
+    #[test]
+    fn point_interval() {
+        let point = PointInterval::new(Integer::new(5));
+        assert!(point.has(&Integer::new(5)));
+        assert!(!point.has(&Integer::new(4)));
+        assert!(point.lower() == point.upper());
+        assert!(point.lower_included() && point.upper_included());
+    }
+
+    #[test]
+    fn interval_of_dates() {
+        let interval = ProperInterval::closed(
+            Iso8601Date::parse("2024-01-01").unwrap(),
+            Iso8601Date::parse("2024-12-31").unwrap(),
+        ).unwrap();
+        assert!(interval.has(&Iso8601Date::parse("2024-03-15").unwrap()));
+        assert!(interval.has(&Iso8601Date::parse("2024-03").unwrap()));
+        assert!(!interval.has(&Iso8601Date::parse("2024").unwrap()));
+    }
+
+    #[test]
+    fn reject_incomparable_limits() {
+        let interval = ProperInterval::closed(
+            Iso8601Date::parse("2024").unwrap(),
+            Iso8601Date::parse("2024-03-15").unwrap(),
+        );
+        assert!(interval.err() == Some(IntervalError::IncomparableLimits));
+        let interval = ProperInterval::closed(
+            Iso8601Date::parse("2023").unwrap(),
+            Iso8601Date::parse("2024-03-15").unwrap(),
+        );
+        assert!(interval.unwrap().has(&Iso8601Date::parse("2024-01-10").unwrap()));
+    }
+
+    #[test]
+    fn interval_type() {
+        let interval = ProperInterval::closed(Integer::new(0), Integer::new(5)).unwrap();
+        assert_eq!(interval.type_of(), "ProperInterval<Integer>");
+        assert!(interval.instance_of("ProperInterval<Integer>"));
+        assert_eq!(PointInterval::new(Double::new(1.0)).type_of(), "PointInterval<Double>");
+        assert!(interval.is_equal(&ProperInterval::closed(Integer::new(0), Integer::new(5)).unwrap()));
+    }
+
+}
@@ -0,0 +1,72 @@
+#[cfg(all(test, feature = "iri"))]
+mod tests {
+    use base::foundation_types::{Uri, UriError, UriErrorContext};
+
+    #[test]
+    fn parse_iri() {
+        let uri = Uri::parse_iri("http://bücher.example/straße?q=café#façade").unwrap();
+        assert_eq!(
+            uri.to_string(),
+            "http://xn--bcher-kva.example/stra%C3%9Fe?q=caf%C3%A9#fa%C3%A7ade"
+        );
+        assert_eq!(uri.host_to_string().unwrap(), Some("xn--bcher-kva.example".to_string()));
+        assert_eq!(uri.path_to_string().unwrap(), "/straße");
+        assert_eq!(uri.query_parameter("q"), Some("café".to_string()));
+    }
+
+    #[test]
+    fn parse_iri_with_user_info_and_port() {
+        let uri = Uri::parse_iri("https://jürgen@例え.テスト:8443/").unwrap();
+        assert_eq!(uri.to_string(), "https://j%C3%BCrgen@xn--r8jz45g.xn--zckzah:8443/");
+        assert_eq!(uri.port(), Some(8443));
+        assert_eq!(uri.user_info_to_string().unwrap(), Some("jürgen".to_string()));
+    }
+
+    #[test]
+    fn parse_ascii_iri() {
+        let text = "http://[::1]:8080/a%20b?x=1#top";
+        assert_eq!(Uri::parse_iri(text).unwrap(), Uri::parse(text).unwrap());
+        assert_eq!(
+            Uri::parse_iri("urn:isbn:0451450523").unwrap().to_string(),
+            "urn:isbn:0451450523"
+        );
+    }
+
+    #[test]
+    fn private_use_characters() {
+        assert_eq!(
+            Uri::parse_iri("http://example.com/?q=\u{E000}").unwrap().to_string(),
+            "http://example.com/?q=%EE%80%80"
+        );
+        assert_eq!(
+            Uri::parse_iri("http://example.com/\u{E000}").err(),
+            Some(UriError::IllegalCharacter(UriErrorContext::Path))
+        );
+    }
+
+    #[test]
+    fn host_to_unicode_string() {
+        let uri = Uri::parse("http://xn--bcher-kva.example/").unwrap();
+        assert_eq!(uri.host_to_unicode_string().unwrap(), Some("bücher.example".to_string()));
+        let uri = Uri::parse("http://[2001:db8::1]/").unwrap();
+        assert_eq!(uri.host_to_unicode_string().unwrap(), Some("2001:db8::1".to_string()));
+        assert_eq!(Uri::parse("/ehr").unwrap().host_to_unicode_string().unwrap(), None);
+    }
+
+    #[test]
+    fn invalid_iris() {
+        assert_eq!(
+            Uri::parse_iri("http://\u{0301}b.example/").err(),
+            Some(UriError::InvalidDomainName)
+        );
+        assert!(matches!(
+            Uri::parse_iri("http://example.com/a\u{FFFF}"),
+            Err(UriError::IllegalCharacter(_))
+        ));
+        assert_eq!(
+            Uri::parse_iri("http://[::ü]/").err(),
+            Some(UriError::IllegalCharacter(UriErrorContext::Host))
+        );
+    }
+
+}
@@ -0,0 +1,214 @@
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+    use base::foundation_types::{Any, Integer, Iso8601Date, Iso8601Duration, Iso8601Type, TimeContext, TimeError};
+
+    #[test]
+    fn parse_extended_date() {
+        let date = Iso8601Date::parse("2024-03-15").unwrap();
+        assert!(date.year() == Integer::new(2024));
+        assert!(date.month() == Some(Integer::new(3)));
+        assert!(date.day() == Some(Integer::new(15)));
+        assert!(date.is_extended());
+    }
+
+    #[test]
+    fn parse_basic_date() {
+        let date = Iso8601Date::parse("20240315").unwrap();
+        assert!(date.day() == Some(Integer::new(15)));
+        assert!(!date.is_extended());
+        assert_eq!(date.to_string(), "20240315");
+    }
+
+    #[test]
+    fn parse_year_and_month() {
+        let date = Iso8601Date::parse("2024-03").unwrap();
+        assert!(date.month() == Some(Integer::new(3)));
+        assert!(date.day().is_none());
+        assert_eq!(date.to_string(), "2024-03");
+    }
+
+    #[test]
+    fn parse_year_only() {
+        let date = Iso8601Date::parse("2024").unwrap();
+        assert!(date.month().is_none());
+        assert_eq!(date.value(), "2024");
+    }
+
+    #[test]
+    fn parse_leap_day() {
+        assert!(Iso8601Date::parse("2024-02-29").is_ok());
+        assert_eq!(
+            Iso8601Date::parse("2023-02-29"),
+            Err(TimeError::OutOfRange(TimeContext::Day))
+        );
+    }
+
+    #[test]
+    fn parse_invalid_month() {
+        assert_eq!(
+            Iso8601Date::parse("2024-13-01"),
+            Err(TimeError::OutOfRange(TimeContext::Month))
+        );
+    }
+
+    #[test]
+    fn parse_invalid_format() {
+        assert_eq!(
+            Iso8601Date::parse("2024/03/15"),
+            Err(TimeError::InvalidFormat(TimeContext::Date))
+        );
+        assert!(Iso8601Date::parse("202403").is_err());
+        assert!(Iso8601Date::parse("24-03-15").is_err());
+    }
+
+    #[test]
+    fn basic_and_extended_dates_are_equal() {
+        let a = Iso8601Date::parse("2024-03-15").unwrap();
+        let b = Iso8601Date::parse("20240315").unwrap();
+        assert!(a.is_equal(&b));
+    }
+
+    #[test]
+    fn dates_are_ordered() {
+        let a = Iso8601Date::parse("2024-03-15").unwrap();
+        let b = Iso8601Date::parse("2024-04-01").unwrap();
+        assert!(a < b);
+    }
+
+    #[test]
+    fn date_instance_of() {
+        let date = Iso8601Date::new(2024, 3, 15).unwrap();
+        assert!(date.instance_of("Iso8601Date"));
+    }
+
+    #[test]
+    fn date_type_of() {
+        let date = Iso8601Date::new(2024, 3, 15).unwrap();
+        assert_eq!(date.type_of(), "Iso8601Date");
+    }
+
+    #[test]
+    fn partial_date_queries() {
+        let date = Iso8601Date::parse("2024-03").unwrap();
+        assert!(date.is_partial());
+        assert!(!date.month_unknown());
+        assert!(date.day_unknown());
+
+        let date = Iso8601Date::parse("2024").unwrap();
+        assert!(date.month_unknown());
+        assert!(date.day_unknown());
+
+        let date = Iso8601Date::parse("2024-03-15").unwrap();
+        assert!(!date.is_partial());
+    }
+
+    #[test]
+    fn partial_dates_that_do_not_overlap_are_ordered() {
+        let a = Iso8601Date::parse("2023").unwrap();
+        let b = Iso8601Date::parse("2024-03").unwrap();
+        let c = Iso8601Date::parse("2024-04-01").unwrap();
+        assert!(a < b);
+        assert!(b < c);
+        assert_eq!(c.partial_cmp(&a), Some(Ordering::Greater));
+    }
+
+    #[test]
+    fn overlapping_partial_dates_are_undetermined() {
+        let a = Iso8601Date::parse("2024").unwrap();
+        let b = Iso8601Date::parse("2024-03").unwrap();
+        let c = Iso8601Date::parse("2024-03-15").unwrap();
+        assert_eq!(a.partial_cmp(&b), None);
+        assert_eq!(b.partial_cmp(&c), None);
+        assert!(a != b);
+        assert_eq!(b.partial_cmp(&a), None);
+    }
+
+    #[test]
+    fn add_month_clamps_to_month_end() {
+        let date = Iso8601Date::parse("2024-01-31").unwrap();
+        let month = Iso8601Duration::parse("P1M").unwrap();
+        assert_eq!(date.add_duration(&month).unwrap().to_string(), "2024-02-29");
+        let date = Iso8601Date::parse("2023-01-31").unwrap();
+        assert_eq!(date.add_duration(&month).unwrap().to_string(), "2023-02-28");
+    }
+
+    #[test]
+    fn add_and_subtract_operators() {
+        let date = Iso8601Date::parse("2024-01-31").unwrap();
+        let result = (date.clone() + Iso8601Duration::parse("P1M").unwrap()).unwrap();
+        assert_eq!(result.to_string(), "2024-02-29");
+        let result = (date - Iso8601Duration::parse("P2M").unwrap()).unwrap();
+        assert_eq!(result.to_string(), "2023-11-30");
+        let leap_day = Iso8601Date::parse("2024-02-29").unwrap();
+        let result = (leap_day.clone() + Iso8601Duration::parse("P1Y").unwrap()).unwrap();
+        assert_eq!(result.to_string(), "2025-02-28");
+        let result = (leap_day.clone() - Iso8601Duration::parse("P4Y").unwrap()).unwrap();
+        assert_eq!(result.to_string(), "2020-02-29");
+        let result = (leap_day.clone() + Iso8601Duration::parse("P1D").unwrap()).unwrap();
+        assert_eq!(result.to_string(), "2024-03-01");
+        let result = leap_day + Iso8601Duration::parse("PT1H").unwrap();
+        assert!(result.err() == Some(TimeError::ImpreciseDuration(TimeContext::Date)));
+    }
+
+    #[test]
+    fn add_and_subtract_days_across_leap_day() {
+        let date = Iso8601Date::parse("2024-02-28").unwrap();
+        let add = |duration: &str| {
+            date.add_duration(&Iso8601Duration::parse(duration).unwrap()).unwrap().to_string()
+        };
+        assert_eq!(add("P2D"), "2024-03-01");
+        assert_eq!(add("-P1W"), "2024-02-21");
+        assert_eq!(add("PT48H"), "2024-03-01");
+        let year = Iso8601Duration::parse("P1Y").unwrap();
+        assert_eq!(date.subtract_duration(&year).unwrap().to_string(), "2023-02-28");
+    }
+
+    #[test]
+    fn partial_date_keeps_precision() {
+        let date = Iso8601Date::parse("2024-12").unwrap();
+        let result = date.add_duration(&Iso8601Duration::parse("P1M").unwrap()).unwrap();
+        assert_eq!(result.to_string(), "2025-01");
+        let result = date.add_duration(&Iso8601Duration::parse("P1M10D").unwrap());
+        assert!(result.err() == Some(TimeError::ImpreciseDuration(TimeContext::Date)));
+        let year = Iso8601Date::parse("2024").unwrap();
+        let result = year.add_duration(&Iso8601Duration::parse("P2Y").unwrap()).unwrap();
+        assert_eq!(result.to_string(), "2026");
+        let result = year.add_duration(&Iso8601Duration::parse("P11M").unwrap());
+        assert!(result.err() == Some(TimeError::ImpreciseDuration(TimeContext::Date)));
+        let result = year.subtract_duration(&Iso8601Duration::parse("P1Y6M").unwrap());
+        assert!(result.err() == Some(TimeError::ImpreciseDuration(TimeContext::Date)));
+    }
+
+    #[test]
+    fn add_out_of_range() {
+        let date = Iso8601Date::parse("9999-12-31").unwrap();
+        let result = date.add_duration(&Iso8601Duration::parse("P1D").unwrap());
+        assert!(result.err() == Some(TimeError::OutOfRange(TimeContext::Year)));
+        let date = Iso8601Date::parse("9999").unwrap();
+        let result = date.add_duration(&Iso8601Duration::parse("P1Y").unwrap());
+        assert!(result.err() == Some(TimeError::OutOfRange(TimeContext::Year)));
+    }
+
+    #[test]
+    fn add_time_of_day() {
+        let date = Iso8601Date::parse("2024-03-15").unwrap();
+        let result = date.add_duration(&Iso8601Duration::parse("P1DT1H").unwrap());
+        assert!(result.err() == Some(TimeError::ImpreciseDuration(TimeContext::Date)));
+        let result = date.subtract_duration(&Iso8601Duration::parse("PT30M").unwrap());
+        assert!(result.err() == Some(TimeError::ImpreciseDuration(TimeContext::Date)));
+    }
+
+    #[test]
+    fn diff_dates() {
+        let birth = Iso8601Date::parse("1990-08-31").unwrap();
+        let date = Iso8601Date::parse("2024-03-15").unwrap();
+        let age = date.diff(&birth).unwrap();
+        assert_eq!(age.to_string(), "P33Y6M15D");
+        assert!(birth.add_duration(&age).unwrap() == date);
+        assert_eq!(birth.diff(&date).unwrap().to_string(), "-P33Y6M15D");
+        let partial = Iso8601Date::parse("2024-03").unwrap();
+        assert!(partial.diff(&birth).err() == Some(TimeError::PartialValue(TimeContext::Date)));
+    }
+
+}
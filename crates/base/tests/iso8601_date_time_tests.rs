@@ -0,0 +1,192 @@
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+    use base::foundation_types::{Any, Integer, Iso8601DateTime, Iso8601Duration, Iso8601Timezone, Iso8601Type, TimeContext, TimeError};
+
+    #[test]
+    fn parse_extended_date_time() {
+        let date_time = Iso8601DateTime::parse("2024-03-15T10:15:30Z").unwrap();
+        assert!(date_time.year() == Integer::new(2024));
+        assert!(date_time.hour() == Some(Integer::new(10)));
+        assert!(date_time.timezone().unwrap().is_gmt());
+        assert!(date_time.is_extended());
+        assert_eq!(date_time.to_string(), "2024-03-15T10:15:30Z");
+    }
+
+    #[test]
+    fn parse_basic_date_time() {
+        let date_time = Iso8601DateTime::parse("20240315T101530.5+0100").unwrap();
+        assert!(!date_time.is_extended());
+        assert_eq!(date_time.fractional_second().unwrap().value, 0.5);
+        assert_eq!(date_time.to_string(), "20240315T101530.5+0100");
+    }
+
+    #[test]
+    fn parse_partial_date_time() {
+        let date_time = Iso8601DateTime::parse("2024-03-15T10").unwrap();
+        assert!(date_time.minute().is_none());
+        assert_eq!(date_time.value(), "2024-03-15T10");
+
+        let date_time = Iso8601DateTime::parse("2024-03").unwrap();
+        assert!(date_time.day().is_none());
+        assert!(date_time.time().is_none());
+    }
+
+    #[test]
+    fn parse_time_with_partial_date() {
+        assert_eq!(
+            Iso8601DateTime::parse("2024-03T10:00"),
+            Err(TimeError::InvalidFormat(TimeContext::DateTime))
+        );
+    }
+
+    #[test]
+    fn parse_mixed_format() {
+        assert_eq!(
+            Iso8601DateTime::parse("2024-03-15T101530"),
+            Err(TimeError::InconsistentFormat)
+        );
+    }
+
+    #[test]
+    fn date_times_are_ordered() {
+        let a = Iso8601DateTime::parse("2024-03-15T10:15:30").unwrap();
+        let b = Iso8601DateTime::parse("2024-03-15T11:00:00").unwrap();
+        let c = Iso8601DateTime::parse("2024-03-16T09:00:00").unwrap();
+        assert!(a < b);
+        assert!(b < c);
+    }
+
+    #[test]
+    fn date_time_is_equal() {
+        let a = Iso8601DateTime::parse("2024-03-15T10:15:30").unwrap();
+        let b = Iso8601DateTime::parse("20240315T101530").unwrap();
+        assert!(a.is_equal(&b));
+    }
+
+    #[test]
+    fn date_time_instance_of() {
+        let date_time = Iso8601DateTime::parse("2024-03-15T10:15:30").unwrap();
+        assert!(date_time.instance_of("Iso8601DateTime"));
+    }
+
+    #[test]
+    fn date_time_type_of() {
+        let date_time = Iso8601DateTime::parse("2024-03-15T10:15:30").unwrap();
+        assert_eq!(date_time.type_of(), "Iso8601DateTime");
+    }
+
+    #[test]
+    fn partial_date_time_queries() {
+        let date_time = Iso8601DateTime::parse("2024-03-15T10").unwrap();
+        assert!(date_time.is_partial());
+        assert!(!date_time.hour_unknown());
+        assert!(date_time.minute_unknown());
+        assert!(date_time.second_unknown());
+
+        let date_time = Iso8601DateTime::parse("2024-03").unwrap();
+        assert!(!date_time.month_unknown());
+        assert!(date_time.day_unknown());
+        assert!(date_time.hour_unknown());
+
+        let date_time = Iso8601DateTime::parse("2024-03-15T10:15:30.5").unwrap();
+        assert!(!date_time.is_partial());
+        assert!(date_time.has_fractional_second());
+    }
+
+    #[test]
+    fn date_times_with_timezones_are_compared_as_instants() {
+        let a = Iso8601DateTime::parse("2024-03-15T00:30+01:00").unwrap();
+        let b = Iso8601DateTime::parse("2024-03-14T23:30Z").unwrap();
+        let c = Iso8601DateTime::parse("2024-03-14T23:45Z").unwrap();
+        assert!(a == b);
+        assert!(a < c);
+    }
+
+    #[test]
+    fn overlapping_partial_date_times_are_undetermined() {
+        let a = Iso8601DateTime::parse("2024-03-15").unwrap();
+        let b = Iso8601DateTime::parse("2024-03-15T10").unwrap();
+        let c = Iso8601DateTime::parse("2024-03-14").unwrap();
+        assert_eq!(a.partial_cmp(&b), None);
+        assert_eq!(c.partial_cmp(&b), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn local_date_time_is_ordered_against_distant_zoned_date_time() {
+        let a = Iso8601DateTime::parse("2024-03-15T10:00:00").unwrap();
+        let b = Iso8601DateTime::parse("2024-03-15T12:00:00Z").unwrap();
+        let c = Iso8601DateTime::parse("2024-03-16T12:00:00Z").unwrap();
+        assert_eq!(a.partial_cmp(&b), None);
+        assert_eq!(a.partial_cmp(&c), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn add_duration_carries_into_date() {
+        let date_time = Iso8601DateTime::parse("2024-02-28T23:00:00Z").unwrap();
+        let result = date_time.add_duration(&Iso8601Duration::parse("PT2H30M").unwrap()).unwrap();
+        assert_eq!(result.to_string(), "2024-02-29T01:30:00Z");
+        let date_time = Iso8601DateTime::parse("2024-03-01T00:30").unwrap();
+        let result = date_time.subtract_duration(&Iso8601Duration::parse("PT1H").unwrap()).unwrap();
+        assert_eq!(result.to_string(), "2024-02-29T23:30");
+    }
+
+    #[test]
+    fn add_and_subtract_operators() {
+        let date_time = Iso8601DateTime::parse("2024-01-31T10:00Z").unwrap();
+        let result = (date_time.clone() + Iso8601Duration::parse("P1M").unwrap()).unwrap();
+        assert_eq!(result.to_string(), "2024-02-29T10:00Z");
+        let result = (date_time - Iso8601Duration::parse("P1Y1M").unwrap()).unwrap();
+        assert_eq!(result.to_string(), "2022-12-31T10:00Z");
+        let date_time = Iso8601DateTime::parse("2024-02-29T12:00").unwrap();
+        let result = (date_time.clone() + Iso8601Duration::parse("P1Y").unwrap()).unwrap();
+        assert_eq!(result.to_string(), "2025-02-28T12:00");
+        let result = (date_time - Iso8601Duration::parse("PT13H").unwrap()).unwrap();
+        assert_eq!(result.to_string(), "2024-02-28T23:00");
+    }
+
+    #[test]
+    fn add_duration_rejects_imprecise_durations() {
+        let date_time = Iso8601DateTime::parse("2024-03-15T10").unwrap();
+        let result = date_time.add_duration(&Iso8601Duration::parse("PT2H").unwrap()).unwrap();
+        assert_eq!(result.to_string(), "2024-03-15T12");
+        let result = date_time.clone() + Iso8601Duration::parse("PT30M").unwrap();
+        assert!(result.err() == Some(TimeError::ImpreciseDuration(TimeContext::DateTime)));
+        let date_time = Iso8601DateTime::parse("2024-03-15").unwrap();
+        let result = date_time - Iso8601Duration::parse("PT1H").unwrap();
+        assert!(result.err() == Some(TimeError::ImpreciseDuration(TimeContext::Date)));
+    }
+
+    #[test]
+    fn add_month_clamps_to_month_end() {
+        let date_time = Iso8601DateTime::parse("2023-01-31T10:00").unwrap();
+        let result = date_time.add_duration(&Iso8601Duration::parse("P1M1D").unwrap()).unwrap();
+        assert_eq!(result.to_string(), "2023-03-01T10:00");
+        let date_time = Iso8601DateTime::parse("9999-12-31T23:00").unwrap();
+        let result = date_time.add_duration(&Iso8601Duration::parse("PT1H").unwrap());
+        assert!(result.err() == Some(TimeError::OutOfRange(TimeContext::Year)));
+    }
+
+    #[test]
+    fn diff_across_time_zones() {
+        let a = Iso8601DateTime::parse("2024-03-15T10:00:00+01:00").unwrap();
+        let b = Iso8601DateTime::parse("2024-03-14T08:30:00Z").unwrap();
+        assert_eq!(a.diff(&b).unwrap().to_string(), "P1DT30M");
+        assert_eq!(b.diff(&a).unwrap().to_string(), "-P1DT30M");
+        let c = Iso8601DateTime::parse("2024-03-14T08:30:00").unwrap();
+        assert!(a.diff(&c).err() == Some(TimeError::MissingTimezone));
+        let d = Iso8601DateTime::parse("2024-03").unwrap();
+        assert!(a.diff(&d).err() == Some(TimeError::PartialValue(TimeContext::DateTime)));
+    }
+
+    #[test]
+    fn convert_to_timezone() {
+        let date_time = Iso8601DateTime::parse("2024-03-15T00:30:00+01:00").unwrap();
+        let utc = date_time.to_timezone(&Iso8601Timezone::utc()).unwrap();
+        assert_eq!(utc.to_string(), "2024-03-14T23:30:00Z");
+        assert!(utc == date_time);
+        let local = Iso8601DateTime::parse("2024-03-15T00:30:00").unwrap();
+        assert!(local.to_timezone(&Iso8601Timezone::utc()).err() == Some(TimeError::MissingTimezone));
+    }
+
+}
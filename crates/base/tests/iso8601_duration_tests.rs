@@ -0,0 +1,83 @@
+#[cfg(test)]
+mod tests {
+    use base::foundation_types::{Any, Integer, Iso8601Duration, TimeContext, TimeError};
+
+    #[test]
+    fn parse_full_duration() {
+        let duration = Iso8601Duration::parse("P1Y2M10DT2H30M15S").unwrap();
+        assert!(duration.years() == Integer::new(1));
+        assert!(duration.months() == Integer::new(2));
+        assert!(duration.days() == Integer::new(10));
+        assert!(duration.hours() == Integer::new(2));
+        assert!(duration.minutes() == Integer::new(30));
+        assert!(duration.seconds() == Integer::new(15));
+        assert_eq!(duration.to_string(), "P1Y2M10DT2H30M15S");
+    }
+
+    #[test]
+    fn parse_weeks() {
+        let duration = Iso8601Duration::parse("P2W").unwrap();
+        assert!(duration.weeks() == Integer::new(2));
+        assert_eq!(duration.to_seconds().value, 14.0 * 86_400.0);
+    }
+
+    #[test]
+    fn parse_fractional_seconds() {
+        let duration = Iso8601Duration::parse("PT0.5S").unwrap();
+        assert_eq!(duration.to_seconds().value, 0.5);
+        assert_eq!(duration.to_string(), "PT0.5S");
+    }
+
+    #[test]
+    fn parse_negative_duration() {
+        let duration = Iso8601Duration::parse("-P1D").unwrap();
+        assert!(duration.is_negative());
+        assert_eq!(duration.to_seconds().value, -86_400.0);
+        assert_eq!(duration.to_string(), "-P1D");
+    }
+
+    #[test]
+    fn zero_duration() {
+        let duration = Iso8601Duration::parse("P0D").unwrap();
+        assert_eq!(duration.to_string(), "PT0S");
+    }
+
+    #[test]
+    fn parse_invalid_duration() {
+        let invalid = TimeError::InvalidFormat(TimeContext::Duration);
+        assert_eq!(Iso8601Duration::parse("1D"), Err(invalid.clone()));
+        assert_eq!(Iso8601Duration::parse("P"), Err(invalid.clone()));
+        assert_eq!(Iso8601Duration::parse("P1DT"), Err(invalid.clone()));
+        assert_eq!(Iso8601Duration::parse("PT1H1D"), Err(invalid.clone()));
+        assert_eq!(Iso8601Duration::parse("P1D2Y"), Err(invalid));
+    }
+
+    #[test]
+    fn durations_are_ordered() {
+        let a = Iso8601Duration::parse("PT23H").unwrap();
+        let b = Iso8601Duration::parse("P1D").unwrap();
+        let c = Iso8601Duration::parse("-P1Y").unwrap();
+        assert!(a < b);
+        assert!(c < a);
+    }
+
+    #[test]
+    fn duration_is_equal() {
+        let a = Iso8601Duration::new(0, 0, 0, 1, 0, 0, 0);
+        let b = Iso8601Duration::parse("P1D").unwrap();
+        assert!(a.is_equal(&b));
+    }
+
+    #[test]
+    fn duration_instance_of() {
+        let duration = Iso8601Duration::parse("P1D").unwrap();
+        assert!(duration.instance_of("Iso8601Duration"));
+    }
+
+    #[test]
+    fn duration_type_of() {
+        let duration = Iso8601Duration::parse("P1D").unwrap();
+        assert_eq!(duration.type_of(), "Iso8601Duration");
+    }
+
+}
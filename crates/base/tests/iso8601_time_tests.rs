@@ -0,0 +1,178 @@
+#[cfg(test)]
+mod tests {
+    use std::cmp::Ordering;
+    use base::foundation_types::{Any, Integer, Iso8601Duration, Iso8601Time, Iso8601Type, TimeContext, TimeError};
+
+    #[test]
+    fn parse_extended_time() {
+        let time = Iso8601Time::parse("10:15:30").unwrap();
+        assert!(time.hour() == Integer::new(10));
+        assert!(time.minute() == Some(Integer::new(15)));
+        assert!(time.second() == Some(Integer::new(30)));
+        assert!(time.is_extended());
+        assert_eq!(time.to_string(), "10:15:30");
+    }
+
+    #[test]
+    fn parse_basic_time() {
+        let time = Iso8601Time::parse("101530").unwrap();
+        assert!(!time.is_extended());
+        assert_eq!(time.to_string(), "101530");
+    }
+
+    #[test]
+    fn parse_time_with_designator() {
+        let time = Iso8601Time::parse("T10").unwrap();
+        assert!(time.hour() == Integer::new(10));
+        assert!(time.minute().is_none());
+        assert_eq!(time.to_string(), "10");
+    }
+
+    #[test]
+    fn parse_hour_and_minute() {
+        let time = Iso8601Time::parse("10:15").unwrap();
+        assert!(time.second().is_none());
+        assert_eq!(time.value(), "10:15");
+    }
+
+    #[test]
+    fn parse_fractional_second() {
+        let time = Iso8601Time::parse("10:15:30,250").unwrap();
+        assert_eq!(time.fractional_second().unwrap().value, 0.25);
+        assert_eq!(time.to_string(), "10:15:30.250");
+    }
+
+    #[test]
+    fn parse_time_with_timezone() {
+        let time = Iso8601Time::parse("10:15:30+01:00").unwrap();
+        assert_eq!(time.timezone().unwrap().offset_minutes(), 60);
+        assert_eq!(time.to_string(), "10:15:30+01:00");
+        let time = Iso8601Time::parse("101530Z").unwrap();
+        assert!(time.timezone().unwrap().is_gmt());
+    }
+
+    #[test]
+    fn parse_invalid_time() {
+        assert_eq!(
+            Iso8601Time::parse("24:00:00"),
+            Err(TimeError::OutOfRange(TimeContext::Hour))
+        );
+        assert_eq!(
+            Iso8601Time::parse("10:60"),
+            Err(TimeError::OutOfRange(TimeContext::Minute))
+        );
+        assert!(Iso8601Time::parse("10:15.5").is_err());
+        assert!(Iso8601Time::parse("1015:30").is_err());
+    }
+
+    #[test]
+    fn parse_mixed_format() {
+        assert_eq!(
+            Iso8601Time::parse("101530+01:00"),
+            Err(TimeError::InconsistentFormat)
+        );
+    }
+
+    #[test]
+    fn times_are_ordered() {
+        let a = Iso8601Time::parse("10:15:30").unwrap();
+        let b = Iso8601Time::parse("10:15:31").unwrap();
+        assert!(a < b);
+    }
+
+    #[test]
+    fn time_instance_of() {
+        let time = Iso8601Time::new(10, 15, 30).unwrap();
+        assert!(time.instance_of("Iso8601Time"));
+    }
+
+    #[test]
+    fn time_type_of() {
+        let time = Iso8601Time::new(10, 15, 30).unwrap();
+        assert_eq!(time.type_of(), "Iso8601Time");
+    }
+
+    #[test]
+    fn partial_time_queries() {
+        let time = Iso8601Time::parse("10:15").unwrap();
+        assert!(time.is_partial());
+        assert!(!time.minute_unknown());
+        assert!(time.second_unknown());
+
+        let time = Iso8601Time::parse("T10").unwrap();
+        assert!(time.minute_unknown());
+        assert!(time.second_unknown());
+
+        let time = Iso8601Time::parse("10:15:30").unwrap();
+        assert!(!time.is_partial());
+    }
+
+    #[test]
+    fn times_with_timezones_are_compared_as_instants() {
+        let a = Iso8601Time::parse("10:00+01:00").unwrap();
+        let b = Iso8601Time::parse("09:00Z").unwrap();
+        let c = Iso8601Time::parse("09:30Z").unwrap();
+        assert!(a == b);
+        assert!(a < c);
+    }
+
+    #[test]
+    fn overlapping_partial_times_are_undetermined() {
+        let a = Iso8601Time::parse("10").unwrap();
+        let b = Iso8601Time::parse("10:15").unwrap();
+        let c = Iso8601Time::parse("09:59:59").unwrap();
+        assert_eq!(a.partial_cmp(&b), None);
+        assert_eq!(c.partial_cmp(&a), Some(Ordering::Less));
+    }
+
+    #[test]
+    fn time_without_timezone_is_undetermined_against_time_with_timezone() {
+        let a = Iso8601Time::parse("10:00:00").unwrap();
+        let b = Iso8601Time::parse("11:00:00Z").unwrap();
+        assert_eq!(a.partial_cmp(&b), None);
+    }
+
+    #[test]
+    fn add_duration_wraps_around_midnight() {
+        let time = Iso8601Time::parse("23:30:00").unwrap();
+        let result = (time + Iso8601Duration::parse("PT45M").unwrap()).unwrap();
+        assert_eq!(result.to_string(), "00:15:00");
+        let time = Iso8601Time::parse("00:15:00+01:00").unwrap();
+        let result = (time - Iso8601Duration::parse("PT30M").unwrap()).unwrap();
+        assert_eq!(result.to_string(), "23:45:00+01:00");
+    }
+
+    #[test]
+    fn add_duration_keeps_precision_and_format() {
+        let time = Iso8601Time::parse("10").unwrap();
+        assert_eq!((time + Iso8601Duration::parse("PT2H").unwrap()).unwrap().to_string(), "12");
+        let time = Iso8601Time::parse("101500").unwrap();
+        let result = time.add_duration(&Iso8601Duration::parse("PT1.5S").unwrap()).unwrap();
+        assert_eq!(result.to_string(), "101501.5");
+        assert_eq!((time + Iso8601Duration::parse("PT1S").unwrap()).unwrap().to_string(), "101501");
+    }
+
+    #[test]
+    fn add_duration_rejects_imprecise_durations() {
+        let time = Iso8601Time::parse("10").unwrap();
+        let result = time.add_duration(&Iso8601Duration::parse("PT30M").unwrap());
+        assert!(result.err() == Some(TimeError::ImpreciseDuration(TimeContext::Time)));
+        let time = Iso8601Time::parse("10:15").unwrap();
+        let result = time.subtract_duration(&Iso8601Duration::parse("PT90S").unwrap());
+        assert!(result.err() == Some(TimeError::ImpreciseDuration(TimeContext::Time)));
+        let result = time.add_duration(&Iso8601Duration::parse("PT120S").unwrap()).unwrap();
+        assert_eq!(result.to_string(), "10:17");
+    }
+
+    #[test]
+    fn add_duration_rejects_date_components() {
+        let time = Iso8601Time::parse("10:00").unwrap();
+        for duration in ["P1Y", "P1M", "P1W", "P1DT2H"] {
+            let result = time.add_duration(&Iso8601Duration::parse(duration).unwrap());
+            assert!(result.err() == Some(TimeError::UnsupportedDuration(TimeContext::Time)));
+        }
+        let result = time - Iso8601Duration::parse("P1D").unwrap();
+        assert!(result.err() == Some(TimeError::UnsupportedDuration(TimeContext::Time)));
+    }
+
+}
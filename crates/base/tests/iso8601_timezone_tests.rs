@@ -0,0 +1,75 @@
+#[cfg(test)]
+mod tests {
+    use base::foundation_types::{Any, Integer, Iso8601Timezone, Iso8601Type, TimeContext, TimeError};
+
+    #[test]
+    fn parse_utc() {
+        let timezone = Iso8601Timezone::parse("Z").unwrap();
+        assert!(timezone.is_gmt());
+        assert_eq!(timezone.offset_minutes(), 0);
+        assert_eq!(timezone.to_string(), "Z");
+    }
+
+    #[test]
+    fn parse_extended_timezone() {
+        let timezone = Iso8601Timezone::parse("-03:30").unwrap();
+        assert!(timezone.sign() == Integer::new(-1));
+        assert!(timezone.hour() == Integer::new(3));
+        assert!(timezone.minute() == Some(Integer::new(30)));
+        assert_eq!(timezone.offset_minutes(), -210);
+        assert!(timezone.is_extended());
+    }
+
+    #[test]
+    fn parse_basic_timezone() {
+        let timezone = Iso8601Timezone::parse("+0530").unwrap();
+        assert!(!timezone.is_extended());
+        assert_eq!(timezone.to_string(), "+0530");
+    }
+
+    #[test]
+    fn parse_hour_only_timezone() {
+        let timezone = Iso8601Timezone::parse("+05").unwrap();
+        assert!(timezone.minute().is_none());
+        assert_eq!(timezone.offset_minutes(), 300);
+    }
+
+    #[test]
+    fn parse_invalid_timezone() {
+        assert_eq!(
+            Iso8601Timezone::parse("05:00"),
+            Err(TimeError::InvalidFormat(TimeContext::Timezone))
+        );
+        assert_eq!(
+            Iso8601Timezone::parse("+05:60"),
+            Err(TimeError::OutOfRange(TimeContext::Minute))
+        );
+    }
+
+    #[test]
+    fn equal_offsets_are_equal() {
+        let a = Iso8601Timezone::parse("+00:00").unwrap();
+        let b = Iso8601Timezone::parse("Z").unwrap();
+        assert!(a.is_equal(&b));
+    }
+
+    #[test]
+    fn timezone_from_offset_minutes() {
+        let timezone = Iso8601Timezone::from_offset_minutes(-90).unwrap();
+        assert_eq!(timezone.to_string(), "-01:30");
+        assert!(Iso8601Timezone::from_offset_minutes(24 * 60).is_err());
+    }
+
+    #[test]
+    fn timezone_instance_of() {
+        let timezone = Iso8601Timezone::utc();
+        assert!(timezone.instance_of("Iso8601Timezone"));
+    }
+
+    #[test]
+    fn timezone_type_of() {
+        let timezone = Iso8601Timezone::utc();
+        assert_eq!(timezone.type_of(), "Iso8601Timezone");
+    }
+
+}
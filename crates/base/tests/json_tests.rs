@@ -0,0 +1,169 @@
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use base::foundation_types::{
+        Any,
+        Boolean,
+        Double,
+        Hash,
+        Integer,
+        Iso8601Date,
+        List,
+        OpenEhrValue,
+        Octets,
+        TerminologyCode,
+        TerminologyTerm,
+        Uri,
+    };
+    use base::serialisation::{
+        from_json,
+        is_known_type,
+        openehr_type_name,
+        rust_type_name,
+        to_json,
+        Error,
+    };
+
+    fn code() -> TerminologyCode {
+        TerminologyCode {
+            terminology_id: "openehr".to_string(),
+            terminology_version: String::new(),
+            code_string: "433".to_string(),
+            uri: Uri::default(),
+        }
+    }
+
+    #[test]
+    fn openehr_type_names() {
+        assert_eq!(openehr_type_name("Integer"), "INTEGER");
+        assert_eq!(openehr_type_name("Integer64"), "INTEGER64");
+        assert_eq!(openehr_type_name("TerminologyCode"), "TERMINOLOGY_CODE");
+        assert_eq!(openehr_type_name("Iso8601DateTime"), "ISO8601_DATE_TIME");
+        assert_eq!(openehr_type_name("Hash<String, Integer>"), "HASH<STRING,INTEGER>");
+        assert_eq!(rust_type_name("TERMINOLOGY_CODE"), "TerminologyCode");
+        assert_eq!(rust_type_name("ISO8601_DATE_TIME"), "Iso8601DateTime");
+        assert_eq!(rust_type_name("HASH<STRING,LIST<INTEGER64>>"), "Hash<String, List<Integer64>>");
+        assert!(is_known_type("LIST<INTEGER>"));
+        assert!(is_known_type("ORDER_NUMERIC"));
+        assert!(!is_known_type("LIST<INTEGRE>"));
+        assert!(!is_known_type("DV_TXET"));
+    }
+
+    #[test]
+    fn write_primitive_bare() {
+        assert_eq!(to_json(&Integer::new(42)).unwrap(), "42");
+        assert_eq!(to_json(&Boolean::new(true)).unwrap(), "true");
+        assert_eq!(from_json::<Integer>("42").unwrap(), Integer::new(42));
+    }
+
+    #[test]
+    fn octets_as_base64() {
+        let octets = Octets::from(b"hi??".to_vec());
+        let json = to_json(&octets).unwrap();
+        assert_eq!(json, r#""aGk/Pw==""#);
+        assert_eq!(from_json::<Octets>(&json).unwrap(), octets);
+        assert!(from_json::<Octets>(r#""aGk_Pw""#).is_err());
+    }
+
+    #[test]
+    fn write_object_with_type() {
+        assert_eq!(
+            to_json(&code()).unwrap(),
+            r#"{"_type":"TERMINOLOGY_CODE","terminology_id":"openehr","code_string":"433"}"#
+        );
+    }
+
+    #[test]
+    fn read_with_type() {
+        let value: Double = from_json("2.5").unwrap();
+        assert!(value == Double::new(2.5));
+        let code: TerminologyCode = from_json(&to_json(&code()).unwrap()).unwrap();
+        assert!(code == self::code());
+        let term = TerminologyTerm { concept: self::code(), text: "event".to_string() };
+        let read: TerminologyTerm = from_json(&to_json(&term).unwrap()).unwrap();
+        assert!(read == term);
+    }
+
+    #[test]
+    fn reject_missing_type() {
+        let json = r#"{"terminology_id": "openehr", "code_string": "433"}"#;
+        assert_eq!(from_json::<TerminologyCode>(json).err(), Some(Error::MissingType));
+    }
+
+    #[test]
+    fn reject_unknown_type() {
+        let json = r#"{"_type": "TERMINOLOGY_COED", "terminology_id": "openehr", "code_string": "433"}"#;
+        let result = from_json::<TerminologyCode>(json);
+        assert_eq!(result.err(), Some(Error::UnknownType("TERMINOLOGY_COED".to_string())));
+    }
+
+    #[test]
+    fn reject_incompatible_type() {
+        let json = r#"{"_type": "TERMINOLOGY_TERM", "terminology_id": "openehr", "code_string": "433"}"#;
+        let result = from_json::<TerminologyCode>(json);
+        assert_eq!(
+            result.err(),
+            Some(Error::IncompatibleType {
+                expected: "TERMINOLOGY_CODE".to_string(),
+                found: "TERMINOLOGY_TERM".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn reject_invalid_content() {
+        assert!(matches!(from_json::<Integer>(r#""ten""#), Err(Error::InvalidDocument(_))));
+        let result = from_json::<TerminologyCode>(r#"{"_type": "TERMINOLOGY_CODE"}"#);
+        assert!(matches!(result, Err(Error::InvalidDocument(_))));
+        assert!(matches!(from_json::<Integer>("{"), Err(Error::InvalidDocument(_))));
+    }
+
+    #[test]
+    fn open_ehr_value_round_trip() {
+        let dates: List<OpenEhrValue> = vec![OpenEhrValue::from(Iso8601Date::parse("2024-03-15").unwrap())].into();
+        let items: List<OpenEhrValue> = vec![
+            OpenEhrValue::from(Integer::new(1)),
+            OpenEhrValue::from(code()),
+            OpenEhrValue::from(dates),
+        ]
+        .into();
+        let mut hash = Hash::new();
+        hash.insert(OpenEhrValue::from(base::foundation_types::String::from("a")), OpenEhrValue::from(items));
+        let value = OpenEhrValue::from(hash);
+        let json = to_json(&value).unwrap();
+        assert!(json.starts_with(r#"{"_type":"HASH<STRING,LIST<ANY>>","entries":[{"key":"a","value":{"_type":"LIST<ANY>","items":[1,"#));
+        assert!(json.contains(r#"{"_type":"TERMINOLOGY_CODE","terminology_id":"openehr","code_string":"433"}"#));
+        assert!(json.contains(r#"{"_type":"LIST<ISO8601_DATE>","items":["2024-03-15"]}"#));
+        assert!(from_json::<OpenEhrValue>(&json).unwrap() == value);
+    }
+
+    #[test]
+    fn open_ehr_value_primitive_bare() {
+        assert_eq!(to_json(&OpenEhrValue::from(Integer::new(42))).unwrap(), to_json(&Integer::new(42)).unwrap());
+        assert_eq!(to_json(&OpenEhrValue::from(Iso8601Date::parse("2024-03-15").unwrap())).unwrap(), r#""2024-03-15""#);
+        assert!(from_json::<OpenEhrValue>("42").unwrap() == OpenEhrValue::from(Integer::new(42)));
+    }
+
+    #[test]
+    fn open_ehr_value_dispatch() {
+        let value: OpenEhrValue = from_json(r#"{"_type": "LIST<ANY>", "items": [1, 2.5, "x", {"_type": "LIST<INTEGER64>", "items": [7]}]}"#).unwrap();
+        assert_eq!(value.type_of(), "List<Any>");
+        let items = value.downcast::<List<OpenEhrValue>>().unwrap();
+        assert!(items.get(0).unwrap().is::<Integer>());
+        assert!(items.get(1).unwrap().is::<Double>());
+        assert_eq!(items.get(3).unwrap().type_of(), "List<Integer64>");
+        let result = from_json::<OpenEhrValue>(r#"{"_type": "LIST<INTEGER>", "items": [1, 2.5]}"#);
+        assert!(matches!(result, Err(Error::InvalidDocument(_))));
+        let result = from_json::<OpenEhrValue>(r#"{"_type": "DV_TEXT", "value": "x"}"#);
+        assert_eq!(result.err(), Some(Error::UnknownType("DV_TEXT".to_string())));
+    }
+
+    #[test]
+    fn open_ehr_value_type_last() {
+        let json = r#"{"items": ["2024-03-15", {"terminology_id": "openehr", "code_string": "433", "_type": "TERMINOLOGY_CODE"}], "_type": "LIST<ANY>"}"#;
+        let items = from_json::<OpenEhrValue>(json).unwrap().downcast::<List<OpenEhrValue>>().unwrap();
+        assert!(items.get(1).unwrap().downcast_ref::<TerminologyCode>() == Some(&code()));
+        let json = r#"{"items": ["2024-03-15"], "_type": "LIST<ISO8601_DATE>"}"#;
+        assert_eq!(from_json::<OpenEhrValue>(json).unwrap().type_of(), "List<Iso8601Date>");
+    }
+
+}
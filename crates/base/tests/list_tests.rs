@@ -0,0 +1,59 @@
+#[cfg(test)]
+mod tests {
+    use base::foundation_types::{Any, Array, Container, Integer, List, String};
+
+    fn numbers() -> List<Integer> {
+        vec![Integer::new(3), Integer::new(1), Integer::new(4), Integer::new(1)].into()
+    }
+
+    #[test]
+    fn query_list() {
+        let list = numbers();
+        assert!(list.has(&Integer::new(4)));
+        assert!(!list.has(&Integer::new(5)));
+        assert!(list.count() == Integer::new(4));
+        assert!(!list.is_empty());
+        assert!(List::<Integer>::new().is_empty());
+        assert!(list.first() == Some(&Integer::new(3)));
+        assert!(list.last() == Some(&Integer::new(1)));
+    }
+
+    #[test]
+    fn quantifiers() {
+        let list = numbers();
+        assert!(list.there_exists(|item| item.value > 3));
+        assert!(!list.there_exists(|item| item.value > 4));
+        assert!(list.for_all(|item| item.value > 0));
+        assert!(!list.for_all(|item| item.value > 1));
+        assert!(List::<Integer>::new().for_all(|item| item.value > 1));
+    }
+
+    #[test]
+    fn matching_items() {
+        let matching = numbers().matching(|item| item.value < 4);
+        assert!(matching == vec![Integer::new(3), Integer::new(1), Integer::new(1)].into());
+        assert!(numbers().matching(|item| item.value > 10).is_empty());
+    }
+
+    #[test]
+    fn list_type() {
+        assert_eq!(numbers().type_of(), "List<Integer>");
+        assert!(numbers().instance_of("List<Integer>"));
+        let strings: List<String> = vec!["a".to_string()].into();
+        assert_eq!(strings.type_of(), "List<String>");
+        let nested: List<List<Integer>> = vec![numbers()].into();
+        assert_eq!(nested.type_of(), "List<List<Integer>>");
+    }
+
+    #[test]
+    fn array_access() {
+        let mut array: Array<Integer> = (1..=3).map(Integer::new).collect();
+        assert!(array.get(1) == Some(&Integer::new(2)));
+        assert!(array.set(1, Integer::new(5)).ok() == Some(Integer::new(2)));
+        assert!(array.set(3, Integer::new(5)).is_err());
+        assert!(array.has(&Integer::new(5)));
+        assert!(array.count() == Integer::new(3));
+        assert_eq!(array.type_of(), "Array<Integer>");
+    }
+
+}
@@ -0,0 +1,128 @@
+#[cfg(test)]
+mod tests {
+    use base::foundation_types::{
+        Boolean,
+        Character,
+        Double,
+        Integer,
+        Integer64,
+        Literal,
+        Number,
+        Octet,
+        ParseError,
+        Real,
+    };
+
+    #[test]
+    fn integer_round_trip() {
+        assert_eq!("-42".parse::<Integer>(), Ok(Integer::new(-42)));
+        assert_eq!("+7".parse::<Integer>(), Ok(Integer::new(7)));
+        assert_eq!(Integer::new(-42).to_string(), "-42");
+        let value = "9007199254740993".parse::<Integer64>().unwrap();
+        assert_eq!(value.to_string(), "9007199254740993");
+    }
+
+    #[test]
+    fn integer_errors() {
+        assert_eq!("".parse::<Integer>(), Err(ParseError::Empty(Literal::Integer)));
+        assert_eq!("12a".parse::<Integer>(), Err(ParseError::InvalidFormat(Literal::Integer)));
+        assert_eq!("1.0".parse::<Integer>(), Err(ParseError::InvalidFormat(Literal::Integer)));
+        assert_eq!("2147483648".parse::<Integer>(), Err(ParseError::OutOfRange(Literal::Integer)));
+        let error = "9223372036854775808".parse::<Integer64>();
+        assert_eq!(error, Err(ParseError::OutOfRange(Literal::Integer64)));
+    }
+
+    #[test]
+    fn real_round_trip() {
+        assert_eq!("2.5".parse::<Double>(), Ok(Double::new(2.5)));
+        assert_eq!("-1.0e-3".parse::<Double>(), Ok(Double::new(-0.001)));
+        assert_eq!("1.5E2".parse::<Real>(), Ok(Real::new(150.0)));
+        assert_eq!(Double::new(5.0).to_string(), "5.0");
+        assert_eq!(Double::new(-0.25).to_string(), "-0.25");
+        assert_eq!(Real::new(3.0).to_string(), "3.0");
+        assert_eq!(Double::new(0.1).to_string().parse::<Double>(), Ok(Double::new(0.1)));
+    }
+
+    #[test]
+    fn real_requires_decimal_point() {
+        assert_eq!("5".parse::<Double>(), Err(ParseError::MissingDecimalPoint(Literal::Double)));
+        assert_eq!("1e5".parse::<Real>(), Err(ParseError::MissingDecimalPoint(Literal::Real)));
+        for text in [".5", "5.", "1.0e", "1,5", "NaN", "inf"] {
+            assert_eq!(
+                text.parse::<Double>(),
+                Err(ParseError::InvalidFormat(Literal::Double)),
+                "{}",
+                text
+            );
+        }
+        assert_eq!("1.0e999".parse::<Double>(), Err(ParseError::OutOfRange(Literal::Double)));
+        assert_eq!("1.0e39".parse::<Real>(), Err(ParseError::OutOfRange(Literal::Real)));
+    }
+
+    #[test]
+    fn boolean_literals() {
+        assert_eq!("True".parse::<Boolean>(), Ok(Boolean::new(true)));
+        assert_eq!("FALSE".parse::<Boolean>(), Ok(Boolean::new(false)));
+        assert_eq!(Boolean::new(true).to_string(), "True");
+        assert_eq!(Boolean::new(false).to_string(), "False");
+        assert_eq!("yes".parse::<Boolean>(), Err(ParseError::InvalidFormat(Literal::Boolean)));
+        assert_eq!("".parse::<Boolean>(), Err(ParseError::Empty(Literal::Boolean)));
+    }
+
+    #[test]
+    fn character_literals() {
+        assert_eq!("'a'".parse::<Character>(), Ok(Character::new('a')));
+        assert_eq!("'é'".parse::<Character>(), Ok(Character::new('é')));
+        assert_eq!(r"'\n'".parse::<Character>(), Ok(Character::new('\n')));
+        assert_eq!(r"'\''".parse::<Character>(), Ok(Character::new('\'')));
+        assert_eq!(r"'é'".parse::<Character>(), Ok(Character::new('é')));
+        assert_eq!(Character::new('a').to_string(), "'a'");
+        assert_eq!(Character::new('\'').to_string(), r"'\''");
+        assert_eq!(Character::new('\u{7}').to_string(), r"'\u0007'");
+    }
+
+    #[test]
+    fn character_round_trip() {
+        for c in ['a', '\'', '\\', '"', '\t', '\r', '\u{1b}', 'ß', '字'] {
+            let text = Character::new(c).to_string();
+            assert_eq!(text.parse::<Character>(), Ok(Character::new(c)), "{}", text);
+        }
+    }
+
+    #[test]
+    fn character_errors() {
+        let invalid = Err(ParseError::InvalidFormat(Literal::Character));
+        assert_eq!("a".parse::<Character>(), invalid);
+        assert_eq!("''".parse::<Character>(), invalid);
+        assert_eq!("'ab'".parse::<Character>(), invalid);
+        assert_eq!("'''".parse::<Character>(), invalid);
+        let escape = Err(ParseError::InvalidEscape(Literal::Character));
+        assert_eq!(r"'\q'".parse::<Character>(), escape);
+        assert_eq!(r"'\u12'".parse::<Character>(), escape);
+        assert_eq!(r"'\ud800'".parse::<Character>(), escape);
+    }
+
+    #[test]
+    fn octet_literals() {
+        assert_eq!("255".parse::<Octet>(), Ok(Octet::new(255)));
+        assert_eq!(Octet::new(7).to_string(), "7");
+        assert_eq!("256".parse::<Octet>(), Err(ParseError::OutOfRange(Literal::Octet)));
+        assert_eq!("-1".parse::<Octet>(), Err(ParseError::OutOfRange(Literal::Octet)));
+        assert_eq!("0xff".parse::<Octet>(), Err(ParseError::InvalidFormat(Literal::Octet)));
+    }
+
+    #[test]
+    fn error_messages_name_the_literal() {
+        let error = "5".parse::<Double>().unwrap_err();
+        assert_eq!(error.to_string(), "double literal without decimal point");
+        let error = "'\\q'".parse::<Character>().unwrap_err();
+        assert_eq!(error.to_string(), "invalid escape sequence in character literal");
+    }
+
+    #[test]
+    fn number_display() {
+        assert_eq!(Number::from(Integer::new(3)).to_string(), "3");
+        assert_eq!(Number::from(Double::new(3.0)).to_string(), "3.0");
+    }
+
+}
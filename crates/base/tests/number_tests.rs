@@ -0,0 +1,120 @@
+#[cfg(test)]
+mod tests {
+    use base::foundation_types::{Any, Double, Integer, Integer64, Number, Numeric, Real};
+
+    #[test]
+    fn add_integer_and_double() {
+        let result = Integer::new(2) + Double::new(0.5);
+        assert_eq!(result.value, 2.5);
+        let result = Double::new(0.5) + Integer::new(2);
+        assert_eq!(result.value, 2.5);
+    }
+
+    #[test]
+    fn subtract_integer64_from_integer() {
+        let result = Integer::new(10) - Integer64::new(i64::from(i32::MAX) + 1);
+        assert_eq!(result.value, 9 - i64::from(i32::MAX));
+    }
+
+    #[test]
+    fn multiply_integer64_and_real() {
+        let result = Integer64::new(3) * Real::new(1.5);
+        assert_eq!(result.value, 4.5f32);
+    }
+
+    #[test]
+    fn multiply_real_and_double() {
+        let result = Real::new(0.5) * Double::new(3.0);
+        assert_eq!(result.value, 1.5);
+    }
+
+    #[test]
+    fn divide_integer_types_gives_double() {
+        let result = Integer::new(7) / Integer64::new(2);
+        assert_eq!(result.value, 3.5);
+        let result = Integer64::new(1) / Integer::new(4);
+        assert_eq!(result.value, 0.25);
+    }
+
+    #[test]
+    fn divide_integer_by_real_gives_real() {
+        let result = Integer::new(3) / Real::new(2.0);
+        assert_eq!(result.value, 1.5f32);
+    }
+
+    #[test]
+    fn compare_mixed_types() {
+        assert!(Integer::new(3) == Integer64::new(3));
+        assert!(Integer64::new(3) == Integer::new(3));
+        assert!(Integer::new(2) < Double::new(2.5));
+        assert!(Double::new(2.5) > Integer::new(2));
+        assert!(Real::new(1.0) != Double::new(1.5));
+        assert!(Integer64::new(-1) <= Real::new(-1.0));
+    }
+
+    #[test]
+    fn compare_mixed_types_exactly() {
+        assert!(Integer64::new(16_777_216) == Real::new(16_777_216.0));
+        assert!(Integer64::new(16_777_217) != Real::new(16_777_216.0));
+        assert!(Integer64::new(16_777_217) > Real::new(16_777_216.0));
+        assert!(Real::new(16_777_216.0) < Integer64::new(16_777_217));
+        assert!(Integer::new(16_777_217) != Real::new(16_777_216.0));
+        assert!(Integer64::new((1 << 53) + 1) != Double::new((1u64 << 53) as f64));
+        assert!(Integer64::new((1 << 53) + 1) > Double::new((1u64 << 53) as f64));
+        assert!(Integer64::new(i64::MAX) < Double::new(i64::MAX as f64));
+        assert!(Integer64::new(-2) > Real::new(-2.5));
+        assert!(Integer::new(1) != Double::new(f64::NAN));
+        assert!(Number::from(Integer64::new(16_777_217)) != Number::from(Real::new(16_777_216.0)));
+        assert!(Number::from(Integer64::new(16_777_216)) == Number::from(Real::new(16_777_216.0)));
+    }
+
+    #[test]
+    fn number_promotes_operands() {
+        let result = Number::from(Integer::new(2)) * Number::from(Real::new(1.5));
+        assert!(result.instance_of("Real"));
+        assert_eq!(result.to_double().value, 3.0);
+    }
+
+    #[test]
+    fn number_keeps_integer_type() {
+        let result = Number::from(Integer::new(2)) + Number::from(Integer::new(3));
+        assert_eq!(result.type_of(), "Integer");
+        assert!(result == Number::from(Integer64::new(5)));
+    }
+
+    #[test]
+    fn number_integer_division_gives_double() {
+        let result = Number::from(Integer64::new(1)) / Number::from(Integer::new(2));
+        assert_eq!(result.type_of(), "Double");
+        assert_eq!(result.to_double().value, 0.5);
+    }
+
+    #[test]
+    fn number_negate_and_compare() {
+        let a = -Number::from(Double::new(1.5));
+        let b = Number::from(Integer::new(-1));
+        assert!(a < b);
+        assert!(b.is_equal(&Number::from(Real::new(-1.0))));
+    }
+
+    #[test]
+    fn number_pow() {
+        let result = Number::from(Integer64::new(2)).pow(&Double::new(10.0));
+        assert_eq!(result.value, 1024.0);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn sum_of_mixed_numbers() {
+        let values = [
+            Number::from(Integer::new(2)),
+            Number::from(Integer64::new(3)),
+            Number::from(Double::new(0.5)),
+        ];
+        let total: Number = values.iter().sum();
+        assert_eq!(total.type_of(), "Double");
+        assert_eq!(total.to_double().value, 5.5);
+        assert_eq!((&values[0] * &values[1]).type_of(), "Integer64");
+    }
+
+}
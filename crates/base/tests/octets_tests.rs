@@ -0,0 +1,115 @@
+#[cfg(test)]
+mod tests {
+    use base::foundation_types::{
+        Any,
+        ChecksumAlgorithm,
+        Octet,
+        Octets,
+        OctetsError,
+    };
+    #[cfg(feature = "encoding")]
+    use base::foundation_types::OctetsEncoding;
+
+    fn hello() -> Octets {
+        Octets::from(b"hi??".to_vec())
+    }
+
+    #[test]
+    #[cfg(feature = "encoding")]
+    fn encode_base64() {
+        assert_eq!(hello().encode(OctetsEncoding::Base64), "aGk/Pw==");
+        assert_eq!(hello().encode(OctetsEncoding::Base64Url), "aGk_Pw");
+        assert_eq!(hello().encode(OctetsEncoding::Hex), "68693f3f");
+    }
+
+    #[test]
+    #[cfg(feature = "encoding")]
+    fn decode_round_trip() {
+        for encoding in [OctetsEncoding::Base64, OctetsEncoding::Base64Url, OctetsEncoding::Hex] {
+            let text = hello().encode(encoding);
+            assert_eq!(Octets::decode(&text, encoding), Ok(hello()));
+        }
+        assert_eq!(Octets::decode("aGk_Pw==", OctetsEncoding::Base64Url), Ok(hello()));
+        assert_eq!(Octets::decode("68693F3F", OctetsEncoding::Hex), Ok(hello()));
+    }
+
+    #[test]
+    #[cfg(feature = "encoding")]
+    fn reject_invalid_text() {
+        let invalid = |encoding| Err(OctetsError::InvalidEncoding(encoding));
+        let base64 = OctetsEncoding::Base64;
+        assert_eq!(Octets::decode("aGk_Pw==", base64), invalid(base64));
+        assert_eq!(Octets::decode("aGk/Pw", base64), invalid(base64));
+        assert_eq!(Octets::decode("0g", OctetsEncoding::Hex), invalid(OctetsEncoding::Hex));
+        assert_eq!(Octets::decode("abc", OctetsEncoding::Hex), invalid(OctetsEncoding::Hex));
+    }
+
+    #[test]
+    fn length_limits() {
+        let too_long = Err(OctetsError::TooLong { length: 4, max_length: 3 });
+        assert_eq!(Octets::with_max_length(b"hi??".to_vec(), 3), too_long);
+        assert_eq!(hello().check_length(4), Ok(()));
+        assert_eq!(
+            OctetsError::TooLong { length: 4, max_length: 3 }.to_string(),
+            "4 octets exceed the maximum of 3"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "encoding")]
+    fn decode_length_limits() {
+        let too_long = Err(OctetsError::TooLong { length: 4, max_length: 3 });
+        assert_eq!(Octets::decode_with_max_length("aGk/Pw==", OctetsEncoding::Base64, 3), too_long);
+        assert_eq!(Octets::decode_with_max_length("68693f3f", OctetsEncoding::Hex, 3), too_long);
+        let decoded = Octets::decode_with_max_length("aGk_Pw", OctetsEncoding::Base64Url, 4);
+        assert_eq!(decoded, Ok(hello()));
+    }
+
+    #[test]
+    #[cfg(feature = "checksum")]
+    fn checksums() {
+        let data = Octets::from(b"abc".as_slice());
+        let hex = |algorithm| {
+            data.checksum(algorithm)
+                .iter()
+                .map(|octet| format!("{:02x}", octet.value))
+                .collect::<std::string::String>()
+        };
+        assert_eq!(hex(ChecksumAlgorithm::Crc32), "352441c2");
+        assert_eq!(hex(ChecksumAlgorithm::Sha1), "a9993e364706816aba3e25717850c26c9cd0d89d");
+        assert_eq!(
+            hex(ChecksumAlgorithm::Sha256),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        let expected = data.checksum(ChecksumAlgorithm::Sha256);
+        assert!(data.verify(ChecksumAlgorithm::Sha256, &expected));
+        assert!(!hello().verify(ChecksumAlgorithm::Sha256, &expected));
+    }
+
+    #[test]
+    fn checksum_algorithm_names() {
+        assert_eq!(ChecksumAlgorithm::Sha256.name(), "SHA-256");
+        assert_eq!(ChecksumAlgorithm::from_name("sha-1"), Some(ChecksumAlgorithm::Sha1));
+        assert_eq!(ChecksumAlgorithm::from_name("MD5"), None);
+    }
+
+    #[test]
+    fn built_on_octets() {
+        let mut octets: Octets = [104, 105].into_iter().map(Octet::new).collect();
+        octets.push(Octet::new(63));
+        assert_eq!(octets.len(), 3);
+        assert_eq!(octets.count().value, 3);
+        assert_eq!(octets.get(1), Some(Octet::new(105)));
+        assert_eq!(octets.get(3), None);
+        assert_eq!(octets.iter().map(|octet| octet.value as u32).sum::<u32>(), 272);
+        assert_eq!(octets.as_bytes(), b"hi?");
+        assert!(Octets::new().is_empty());
+    }
+
+    #[test]
+    fn octets_type_of() {
+        assert_eq!(hello().type_of(), "Octets");
+        assert!(hello().is_equal(&Octets::from(b"hi??".as_slice())));
+    }
+
+}
@@ -0,0 +1,121 @@
+#[cfg(test)]
+mod tests {
+    use base::foundation_types::{
+        Any,
+        Container,
+        Double,
+        Hash,
+        Integer,
+        Iso8601Date,
+        List,
+        OpenEhrValue,
+        Set,
+        String,
+        ValueError,
+    };
+
+    fn integers(values: &[i32]) -> OpenEhrValue {
+        let list: List<OpenEhrValue> = values
+            .iter()
+            .map(|value| OpenEhrValue::from(Integer::new(*value)))
+            .collect();
+        OpenEhrValue::from(list)
+    }
+
+    #[test]
+    fn primitive_type_of() {
+        assert_eq!(OpenEhrValue::from(Integer::new(1)).type_of(), "Integer");
+        assert_eq!(OpenEhrValue::from(String::from("x")).type_of(), "String");
+        let date = Iso8601Date::parse("2024-03-15").unwrap();
+        assert_eq!(OpenEhrValue::from(date).type_of(), "Iso8601Date");
+    }
+
+    #[test]
+    fn primitive_instance_of() {
+        let value = OpenEhrValue::from(Double::new(2.5));
+        assert!(value.instance_of("Double"));
+        assert!(value.instance_of("Any"));
+        assert!(!value.instance_of("Integer"));
+    }
+
+    #[test]
+    fn downcast_ref() {
+        let value = OpenEhrValue::from(Integer::new(42));
+        assert!(value.is::<Integer>());
+        assert_eq!(value.downcast_ref::<Integer>(), Some(&Integer::new(42)));
+        assert_eq!(value.downcast_ref::<Double>(), None);
+    }
+
+    #[test]
+    fn downcast_owned() {
+        let value = OpenEhrValue::from(String::from("systolic"));
+        assert_eq!(value.clone().downcast::<String>(), Ok(String::from("systolic")));
+        assert_eq!(
+            value.downcast::<Integer>(),
+            Err(ValueError::TypeMismatch {
+                expected: "Integer".to_string(),
+                found: "String".to_string(),
+            })
+        );
+        assert_eq!(Integer::try_from(OpenEhrValue::from(Integer::new(7))), Ok(Integer::new(7)));
+    }
+
+    #[test]
+    fn list_type_follows_items() {
+        assert_eq!(integers(&[1, 2]).type_of(), "List<Integer>");
+        assert_eq!(integers(&[]).type_of(), "List<Any>");
+        let mixed: List<OpenEhrValue> = vec![
+            OpenEhrValue::from(Integer::new(1)),
+            OpenEhrValue::from(Double::new(1.5)),
+        ]
+        .into();
+        assert_eq!(OpenEhrValue::from(mixed).type_of(), "List<Any>");
+    }
+
+    #[test]
+    fn list_instance_of() {
+        let value = integers(&[1, 2]);
+        assert!(value.instance_of("List<Integer>"));
+        assert!(value.instance_of("List<Any>"));
+        assert!(!value.instance_of("List<Double>"));
+        assert!(!value.instance_of("Set<Integer>"));
+        assert!(integers(&[]).instance_of("List<Double>"));
+    }
+
+    #[test]
+    fn nested_containers() {
+        let mut hash = Hash::new();
+        hash.insert(OpenEhrValue::from(String::from("readings")), integers(&[120, 80]));
+        let value = OpenEhrValue::from(hash);
+        assert_eq!(value.type_of(), "Hash<String, List<Integer>>");
+        assert!(value.instance_of("Hash<String, List<Integer>>"));
+        assert!(value.instance_of("Hash<String,List<Any>>"));
+        assert!(!value.instance_of("Hash<String, List<Double>>"));
+        let hash = value.downcast_ref::<Hash<OpenEhrValue, OpenEhrValue>>().unwrap();
+        let readings = hash.item(&OpenEhrValue::from(String::from("readings"))).unwrap();
+        let list = readings.downcast_ref::<List<OpenEhrValue>>().unwrap();
+        let first = list.first().and_then(|item| item.downcast_ref::<Integer>());
+        assert_eq!(first, Some(&Integer::new(120)));
+    }
+
+    #[test]
+    fn set_equality_ignores_order() {
+        let value = |v| OpenEhrValue::from(Integer::new(v));
+        let a: Set<OpenEhrValue> = [value(1), value(2)].into_iter().collect();
+        let b: Set<OpenEhrValue> = [value(2), value(1)].into_iter().collect();
+        assert!(OpenEhrValue::from(a).is_equal(&OpenEhrValue::from(b)));
+    }
+
+    #[test]
+    fn doubles_as_set_items() {
+        let value = |v| OpenEhrValue::from(Double::new(v));
+        let set: Set<OpenEhrValue> = [value(f64::NAN), value(f64::NAN), value(0.0), value(-0.0)]
+            .into_iter()
+            .collect();
+        assert_eq!(set.items().count(), 3);
+        assert!(set.has(&value(f64::NAN)));
+        assert!(value(f64::NAN) == value(f64::NAN));
+        assert!(value(0.0) != value(-0.0));
+    }
+
+}
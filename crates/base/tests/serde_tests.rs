@@ -0,0 +1,98 @@
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use base::foundation_types::{
+        Boolean,
+        Character,
+        Decimal,
+        Double,
+        Integer,
+        Integer64,
+        Octet,
+        Real,
+        TerminologyCode,
+        TerminologyTerm,
+        Uri,
+    };
+
+    #[test]
+    fn serialize_primitives() {
+        assert_eq!(serde_json::to_string(&Integer::new(42)).unwrap(), "42");
+        assert_eq!(serde_json::to_string(&Integer64::new(-1 << 40)).unwrap(), "-1099511627776");
+        assert_eq!(serde_json::to_string(&Double::new(2.5)).unwrap(), "2.5");
+        assert_eq!(serde_json::to_string(&Real::new(0.5)).unwrap(), "0.5");
+        assert_eq!(serde_json::to_string(&Boolean::new(true)).unwrap(), "true");
+        assert_eq!(serde_json::to_string(&Character::new('x')).unwrap(), "\"x\"");
+        assert_eq!(serde_json::to_string(&Octet::new(255)).unwrap(), "255");
+    }
+
+    #[test]
+    fn deserialize_primitives() {
+        assert!(serde_json::from_str::<Integer>("42").unwrap() == Integer::new(42));
+        assert!(serde_json::from_str::<Double>("3").unwrap() == Double::new(3.0));
+        assert!(serde_json::from_str::<Boolean>("false").unwrap() == false);
+        assert!(serde_json::from_str::<Character>("\"é\"").unwrap() == 'é');
+        assert!(serde_json::from_str::<Integer>("4.5").is_err());
+        assert!(serde_json::from_str::<Octet>("256").is_err());
+        assert!(serde_json::from_str::<Character>("\"ab\"").is_err());
+    }
+
+    #[test]
+    fn decimal_round_trip_as_string() {
+        let value = Decimal::parse("5.10").unwrap();
+        assert_eq!(serde_json::to_string(&value).unwrap(), "\"5.10\"");
+        let value: Decimal = serde_json::from_str("\"-0.030\"").unwrap();
+        assert_eq!(value.to_string(), "-0.030");
+        assert!(serde_json::from_str::<Decimal>("\"5,10\"").is_err());
+    }
+
+    #[test]
+    fn terminology_code_round_trip() {
+        let json = r#"{"terminology_id":"SNOMED-CT","code_string":"38341003","uri":"http://snomed.info/id/38341003"}"#;
+        let code: TerminologyCode = serde_json::from_str(json).unwrap();
+        assert_eq!(code.terminology_id, "SNOMED-CT");
+        assert!(code.terminology_version.is_empty());
+        assert_eq!(code.uri.to_string(), "http://snomed.info/id/38341003");
+        assert_eq!(serde_json::to_string(&code).unwrap(), json);
+    }
+
+    #[test]
+    fn terminology_term_round_trip() {
+        let term = TerminologyTerm {
+            concept: TerminologyCode {
+                terminology_id: "openehr".to_string(),
+                terminology_version: "1.0".to_string(),
+                code_string: "433".to_string(),
+                uri: Uri::default(),
+            },
+            text: "event".to_string(),
+        };
+        let json = serde_json::to_string(&term).unwrap();
+        assert_eq!(
+            json,
+            r#"{"concept":{"terminology_id":"openehr","terminology_version":"1.0","code_string":"433"},"text":"event"}"#
+        );
+        assert!(serde_json::from_str::<TerminologyTerm>(&json).unwrap() == term);
+    }
+
+    #[test]
+    fn reject_invalid_uri() {
+        let json = r#"{"terminology_id":"local","code_string":"at1","uri":"http://[::1"}"#;
+        assert!(serde_json::from_str::<TerminologyCode>(json).is_err());
+    }
+
+}
+
+#[cfg(all(test, feature = "url"))]
+mod url_tests {
+    use base::foundation_types::Uri;
+
+    #[test]
+    fn convert_to_and_from_url() {
+        let uri = Uri::parse("https://www.example.com/path?query#fragment").unwrap();
+        let url = url_::Url::try_from(uri).unwrap();
+        assert_eq!(url.as_str(), "https://www.example.com/path?query#fragment");
+        let uri: Uri = url.try_into().unwrap();
+        assert_eq!(uri.to_string(), "https://www.example.com/path?query#fragment");
+    }
+
+}
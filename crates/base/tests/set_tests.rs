@@ -0,0 +1,52 @@
+#[cfg(test)]
+mod tests {
+    use base::foundation_types::{Any, Container, Integer, Set, String};
+
+    #[test]
+    fn set_has_no_duplicates() {
+        let mut set = Set::new();
+        assert!(set.insert(Integer::new(1)));
+        assert!(set.insert(Integer::new(2)));
+        assert!(!set.insert(Integer::new(1)));
+        assert!(set.count() == Integer::new(2));
+        let set: Set<Integer> = [1, 2, 2, 3, 3, 3].into_iter().map(Integer::new).collect();
+        assert!(set.count() == Integer::new(3));
+    }
+
+    #[test]
+    fn remove_from_set() {
+        let mut set: Set<Integer> = (1..=3).map(Integer::new).collect();
+        assert!(set.remove(&Integer::new(2)));
+        assert!(!set.remove(&Integer::new(2)));
+        assert!(!set.has(&Integer::new(2)));
+        assert!(set.has(&Integer::new(3)));
+    }
+
+    #[test]
+    fn set_keeps_insertion_order() {
+        let set: Set<Integer> = (0..10_000).rev().chain(0..10_000).map(Integer::new).collect();
+        assert!(set.count() == Integer::new(10_000));
+        assert!(set.items().next() == Some(&Integer::new(9_999)));
+        assert!(set.has(&Integer::new(0)));
+    }
+
+    #[test]
+    fn sets_are_unordered() {
+        let a: Set<Integer> = (1..=3).map(Integer::new).collect();
+        let b: Set<Integer> = (1..=3).rev().map(Integer::new).collect();
+        let c: Set<Integer> = (1..=4).map(Integer::new).collect();
+        assert!(a == b);
+        assert!(a.is_equal(&b));
+        assert!(a != c);
+    }
+
+    #[test]
+    fn quantifiers_and_type() {
+        let set: Set<String> = ["at0001", "at0002"].into_iter().map(String::from).collect();
+        assert!(set.for_all(|code| code.starts_with("at")));
+        assert!(set.there_exists(|code| code.ends_with('2')));
+        assert!(set.matching(|code| code.ends_with('1')).count() == Integer::new(1));
+        assert_eq!(set.type_of(), "Set<String>");
+    }
+
+}
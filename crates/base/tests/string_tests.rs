@@ -1,6 +1,7 @@
 #[cfg(test)]
 mod tests {
-    use base::foundation_types::{Any, String, StringExtension};
+    use std::cmp::Ordering;
+    use base::foundation_types::{Any, Boolean, Double, Integer64, Real, String, StringExtension};
 
     #[test]
     fn string_is_integer_with_valid_integer() {
@@ -17,7 +18,7 @@ mod tests {
     #[test]
     fn string_as_integer_with_valid_integer() {
         let s = String::from("123");
-        assert_eq!(s.as_integer(), Some(123));
+        assert_eq!(s.as_integer(), Some(Integer64::new(123)));
     }
 
     #[test]
@@ -67,5 +68,110 @@ mod tests {
         assert_eq!(s.type_of(), "String");
     }
 
+    #[test]
+    fn string_integer_follows_literal_syntax() {
+        let value = String::from("-9007199254740993").as_integer();
+        assert_eq!(value, Some(Integer64::new(-9007199254740993)));
+        assert!(!String::from(" 1").is_integer());
+        assert!(!String::from("1_000").is_integer());
+        assert!(!String::from("99999999999999999999").is_integer());
+    }
+
+    #[test]
+    fn string_as_real() {
+        assert_eq!(String::from("2.5").as_real(), Some(Real::new(2.5)));
+        assert_eq!(String::from("-1.0e-3").as_double(), Some(Double::new(-0.001)));
+        assert!(String::from("1.0e3").is_real());
+        assert!(!String::from("5").is_real());
+        assert!(!String::from("NaN").is_double());
+        assert!(!String::from("inf").is_real());
+        assert!(!String::from(".5").is_real());
+    }
+
+    #[test]
+    fn string_as_boolean() {
+        assert_eq!(String::from("True").as_boolean(), Some(Boolean::new(true)));
+        assert_eq!(String::from("false").as_boolean(), Some(Boolean::new(false)));
+        assert!(!String::from("yes").is_boolean());
+        assert!(!String::from("1").is_boolean());
+    }
+
+    #[test]
+    fn string_as_number() {
+        let integer = String::from("42").as_number().unwrap();
+        assert_eq!(integer.type_of(), "Integer64");
+        let real = String::from("4.2").as_number().unwrap();
+        assert_eq!(real.type_of(), "Double");
+        assert!(String::from("4.2").is_numeric());
+        assert!(!String::from("four").is_numeric());
+    }
+
+    #[test]
+    fn string_is_empty() {
+        assert!(String::new().is_empty_str());
+        assert!(!String::from(" ").is_empty_str());
+    }
+
+    #[test]
+    fn string_substring_counts_characters() {
+        let s = String::from("Blutdruck über");
+        assert_eq!(s.substring(0, 4), Some(String::from("Blut")));
+        assert_eq!(s.substring(10, 14), Some(String::from("über")));
+        assert_eq!(s.substring(3, 3), Some(String::new()));
+        assert_eq!(s.substring(14, 14), Some(String::new()));
+        assert_eq!(s.substring(10, 15), None);
+        assert_eq!(s.substring(4, 2), None);
+    }
+
+    #[test]
+    fn string_starts_with_and_contains() {
+        let s = String::from("openEHR-EHR-OBSERVATION.blood_pressure.v2");
+        assert!(s.starts_with_str("openEHR-EHR"));
+        assert!(!s.starts_with_str("EHR"));
+        assert!(s.contains_str("OBSERVATION"));
+        assert!(!s.contains_str("EVALUATION"));
+    }
+
+    #[test]
+    fn string_methods_keep_working_with_the_trait_in_scope() {
+        let s = String::from("a1-b2");
+        assert!(s.contains('a'));
+        assert!(s.contains(char::is_numeric));
+        assert!(s.starts_with(char::is_alphabetic));
+        assert!(s.starts_with(['a', 'b']));
+        assert!(!s.starts_with(|c: char| c.is_ascii_digit()));
+        assert!(!s.is_empty());
+    }
+
+    #[test]
+    fn string_normalization() {
+        let composed = String::from("M\u{fc}ller");
+        let decomposed = String::from("Mu\u{308}ller");
+        assert_ne!(composed, decomposed);
+        assert_eq!(decomposed.to_nfc(), composed);
+        assert_eq!(composed.to_nfd(), decomposed);
+        assert!(composed.canonical_eq(&decomposed));
+        assert!(!composed.canonical_eq("Muller"));
+    }
+
+    #[test]
+    fn string_collate_ignores_accents_and_case_first() {
+        let mut names: Vec<String> = ["Emma", "émile", "Zoë", "zoe", "Emil", "Álvarez", "adams"]
+            .into_iter()
+            .map(String::from)
+            .collect();
+        names.sort_by(|a, b| a.collate(b));
+        assert_eq!(names, ["adams", "Álvarez", "Emil", "émile", "Emma", "zoe", "Zoë"]);
+    }
+
+    #[test]
+    fn string_collate_levels() {
+        assert_eq!(String::from("cote").collate("côte"), Ordering::Less);
+        assert_eq!(String::from("role").collate("Role"), Ordering::Less);
+        assert_eq!(String::from("Role").collate("rôle"), Ordering::Less);
+        assert_eq!(String::from("Straße").collate("strasse"), Ordering::Greater);
+        assert_eq!(String::from("Straße").collate("Strasze"), Ordering::Less);
+        assert_eq!(String::from("Mu\u{308}ller").collate("M\u{fc}ller"), Ordering::Equal);
+    }
 
-}
\ No newline at end of file
+}
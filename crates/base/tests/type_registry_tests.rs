@@ -0,0 +1,135 @@
+#[cfg(test)]
+mod tests {
+    use base::foundation_types::{
+        Any,
+        Double,
+        Integer,
+        List,
+        MultiplicityInterval,
+        OpenEhrValue,
+        String,
+        TypeRegistry,
+        TypeRegistryError,
+        Uri,
+    };
+
+    #[test]
+    fn instance_of_ancestors() {
+        let integer = Integer::new(1);
+        assert!(integer.instance_of("Integer"));
+        assert!(integer.instance_of("OrderNumeric"));
+        assert!(integer.instance_of("Numeric"));
+        assert!(integer.instance_of("Ordered"));
+        assert!(integer.instance_of("Any"));
+        assert!(!integer.instance_of("Double"));
+        assert!(!integer.instance_of("Integer64"));
+    }
+
+    #[test]
+    fn conforms_to_parent_type() {
+        assert!(Uri::default().conforms_to("String"));
+        assert!(Uri::default().conforms_to("Ordered"));
+        assert!(!String::from("x").conforms_to("Uri"));
+        let multiplicity = MultiplicityInterval::new(0, Some(1)).unwrap();
+        assert!(multiplicity.conforms_to("ProperInterval<Integer>"));
+        assert!(multiplicity.conforms_to("Interval<Numeric>"));
+    }
+
+    #[test]
+    fn generic_types_conform_covariantly() {
+        let list: List<Integer> = (1..=3).map(Integer::new).collect();
+        assert!(list.instance_of("List<Integer>"));
+        assert!(list.instance_of("List<Numeric>"));
+        assert!(list.instance_of("Container<Ordered>"));
+        assert!(list.instance_of("Container"));
+        assert!(list.instance_of("List"));
+        assert!(!list.instance_of("List<Double>"));
+        assert!(!list.instance_of("Set<Integer>"));
+    }
+
+    #[test]
+    fn registry_conformance() {
+        let registry = TypeRegistry::default();
+        assert!(registry.conforms_to("Hash<String, Integer>", "Container<Numeric>"));
+        assert!(registry.conforms_to("Function<<Integer, Integer>, Double>", "Routine"));
+        assert!(registry.conforms_to("<Integer, Uri>", "<Numeric, String>"));
+        assert!(registry.conforms_to("<Integer, Uri>", "Tuple"));
+        assert!(!registry.conforms_to("<Integer, Uri>", "<Integer>"));
+        assert!(registry.conforms_to("Unknown", "Unknown"));
+        assert!(registry.conforms_to("Unknown", "Any"));
+        assert!(!registry.conforms_to("List<Integer", "Any"));
+    }
+
+    #[test]
+    fn ancestors() {
+        let registry = TypeRegistry::default();
+        assert_eq!(registry.ancestors("List<Integer>"), vec!["Container<Integer>", "Any"]);
+        assert_eq!(
+            registry.ancestors("Integer"),
+            vec!["OrderNumeric", "Ordered", "Numeric", "Any"]
+        );
+        assert!(registry.ancestors("Any").is_empty());
+    }
+
+    #[test]
+    fn is_known() {
+        let registry = TypeRegistry::default();
+        assert!(registry.is_known("List<Integer>"));
+        assert!(registry.is_known("Hash<String, List<Iso8601Date>>"));
+        assert!(registry.is_known("List"));
+        assert!(!registry.is_known("List<Integre>"));
+        assert!(!registry.is_known("List<Integer, String>"));
+    }
+
+    #[test]
+    fn register() {
+        let mut registry = TypeRegistry::default();
+        registry.register("Quantified", &["Ordered"]).unwrap();
+        registry.register("Quantity<U>", &["Quantified", "Container<U>"]).unwrap();
+        assert!(registry.conforms_to("Quantity<Double>", "Ordered"));
+        assert!(registry.conforms_to("Quantity<Double>", "Container<Numeric>"));
+        assert!(!TypeRegistry::new().is_known("Quantified"));
+    }
+
+    #[test]
+    fn register_errors() {
+        let mut registry = TypeRegistry::default();
+        assert_eq!(
+            registry.register("Integer", &["Any"]),
+            Err(TypeRegistryError::AlreadyRegistered("Integer".to_string()))
+        );
+        assert_eq!(
+            registry.register("Quantity", &["Quantified"]),
+            Err(TypeRegistryError::UnknownType("Quantified".to_string()))
+        );
+        assert_eq!(
+            registry.register("Quantity<U, U>", &["Any"]),
+            Err(TypeRegistryError::InvalidTypeName("Quantity<U, U>".to_string()))
+        );
+        assert_eq!(
+            registry.register("Quantity<U>", &["List<U, U>"]),
+            Err(TypeRegistryError::WrongArgumentCount {
+                type_name: "List".to_string(),
+                expected: 1,
+                found: 2,
+            })
+        );
+    }
+
+    #[test]
+    fn value_items_conform() {
+        let mixed: List<OpenEhrValue> = vec![
+            OpenEhrValue::from(Integer::new(1)),
+            OpenEhrValue::from(Double::new(2.5)),
+        ]
+        .into_iter()
+        .collect();
+        let value = OpenEhrValue::from(mixed);
+        assert_eq!(value.type_of(), "List<Any>");
+        assert!(value.instance_of("Container<Numeric>"));
+        assert!(value.instance_of("List<OrderNumeric>"));
+        assert!(!value.instance_of("List<Integer>"));
+        assert!(!value.instance_of("Set<Numeric>"));
+    }
+
+}
@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+    use base::foundation_types::{UriTemplate, UriTemplateError, UriTemplateValue};
+
+    fn variables() -> HashMap<&'static str, UriTemplateValue> {
+        HashMap::from([
+            ("ehr_id", UriTemplateValue::from("347a5490-55ee-4da9-b91a-9bba710f730e")),
+            ("uid", UriTemplateValue::from("8728::rmh.nhs.net::2")),
+            ("var", UriTemplateValue::from("value")),
+            ("hello", UriTemplateValue::from("Hello World!")),
+            ("path", UriTemplateValue::from("/foo/bar")),
+            ("empty", UriTemplateValue::from("")),
+            ("x", UriTemplateValue::from("1024")),
+            ("y", UriTemplateValue::from("768")),
+            ("list", UriTemplateValue::list(["red", "green", "blue"])),
+            ("keys", UriTemplateValue::map([("semi", ";"), ("dot", "."), ("comma", ",")])),
+            ("none", UriTemplateValue::list(Vec::<String>::new())),
+        ])
+    }
+
+    fn expand(template: &str) -> String {
+        UriTemplate::parse(template)
+            .unwrap()
+            .expand(&variables())
+            .unwrap()
+            .to_string()
+    }
+
+    #[test]
+    fn expand_ehr_template() {
+        let template = "/ehr/{ehr_id}/composition/{uid}{?version_at_time}";
+        assert_eq!(
+            expand(template),
+            "/ehr/347a5490-55ee-4da9-b91a-9bba710f730e/composition/8728%3A%3Armh.nhs.net%3A%3A2"
+        );
+        let uri = UriTemplate::parse(template).unwrap().expand(&variables()).unwrap();
+        assert_eq!(
+            uri.path_to_string().unwrap(),
+            "/ehr/347a5490-55ee-4da9-b91a-9bba710f730e/composition/8728::rmh.nhs.net::2"
+        );
+        let mut variables = variables();
+        variables.insert("version_at_time", UriTemplateValue::from("2024-03-15T10:00:00+01:00"));
+        let uri = UriTemplate::parse(template).unwrap().expand(&variables).unwrap();
+        assert_eq!(
+            uri.query_parameter("version_at_time"),
+            Some("2024-03-15T10:00:00+01:00".to_string())
+        );
+        assert!(uri
+            .to_string()
+            .ends_with("?version_at_time=2024-03-15T10%3A00%3A00%2B01%3A00"));
+    }
+
+    #[test]
+    fn expand_simple_and_reserved() {
+        assert_eq!(expand("?q={hello}"), "?q=Hello%20World%21");
+        assert_eq!(expand("?q={+hello}"), "?q=Hello%20World!");
+        assert_eq!(expand("{+path}/here"), "/foo/bar/here");
+        assert_eq!(expand("?q={x,y}"), "?q=1024,768");
+        assert_eq!(expand("?q={var:3}"), "?q=val");
+        assert_eq!(expand("?q={undefined}"), "?q=");
+    }
+
+    #[test]
+    fn expand_fragment_and_label() {
+        assert_eq!(expand("{#x,y}"), "#1024,768");
+        assert_eq!(expand("X{.var}"), "X.value");
+        assert_eq!(expand("X{.list*}"), "X.red.green.blue");
+    }
+
+    #[test]
+    fn expand_path() {
+        assert_eq!(expand("{/var,x}/here"), "/value/1024/here");
+        assert_eq!(expand("{/list*}"), "/red/green/blue");
+        assert_eq!(expand("{;x,y,empty}"), ";x=1024;y=768;empty");
+    }
+
+    #[test]
+    fn expand_query() {
+        assert_eq!(expand("{?x,y,empty}"), "?x=1024&y=768&empty=");
+        assert_eq!(expand("{?list}"), "?list=red,green,blue");
+        assert_eq!(expand("{?list*}"), "?list=red&list=green&list=blue");
+        assert_eq!(expand("{?keys*}"), "?semi=%3B&dot=.&comma=%2C");
+        assert_eq!(expand("{?keys}"), "?keys=semi,%3B,dot,.,comma,%2C");
+        assert_eq!(expand("?fixed=yes{&x}"), "?fixed=yes&x=1024");
+        assert_eq!(expand("{?none,undefined}"), "");
+    }
+
+    #[test]
+    fn expand_non_ascii() {
+        assert_eq!(expand("é{var}"), "%C3%A9value");
+        assert_eq!(expand("/a%20é{var}"), "/a%20%C3%A9value");
+        let mut variables = variables();
+        variables.insert("u", UriTemplateValue::from("é/x"));
+        let expand = |template: &str| {
+            UriTemplate::parse(template).unwrap().expand(&variables).unwrap().to_string()
+        };
+        assert_eq!(expand("{+u}"), "%C3%A9/x");
+        assert_eq!(expand("{#u}"), "#%C3%A9/x");
+        assert_eq!(expand("?q={u}"), "?q=%C3%A9%2Fx");
+    }
+
+    #[test]
+    fn variable_names() {
+        let template = UriTemplate::parse("/ehr/{ehr_id}{/uid}{?uid,version_at_time}").unwrap();
+        assert_eq!(template.variable_names(), vec!["ehr_id", "uid", "version_at_time"]);
+        assert_eq!(template.to_string(), "/ehr/{ehr_id}{/uid}{?uid,version_at_time}");
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            UriTemplate::parse("/ehr/{ehr_id").err(),
+            Some(UriTemplateError::UnclosedExpression(5))
+        );
+        assert_eq!(
+            UriTemplate::parse("/ehr/ehr_id}").err(),
+            Some(UriTemplateError::IllegalLiteral('}'))
+        );
+        assert_eq!(
+            UriTemplate::parse("/ehr /{ehr_id}").err(),
+            Some(UriTemplateError::IllegalLiteral(' '))
+        );
+        assert_eq!(
+            UriTemplate::parse("{}").err(),
+            Some(UriTemplateError::InvalidExpression(String::new()))
+        );
+        assert_eq!(
+            UriTemplate::parse("{=var}").err(),
+            Some(UriTemplateError::InvalidExpression("=var".to_string()))
+        );
+        assert_eq!(
+            UriTemplate::parse("{var:0}").err(),
+            Some(UriTemplateError::InvalidExpression("var:0".to_string()))
+        );
+        assert!(UriTemplate::parse("/a%20b/{var}").is_ok());
+    }
+
+    #[test]
+    fn expand_errors() {
+        let template = UriTemplate::parse("{list:2}").unwrap();
+        assert_eq!(
+            template.expand(&variables()).err(),
+            Some(UriTemplateError::PrefixOnComposite("list".to_string()))
+        );
+        let template = UriTemplate::parse("http://example.com:{var}/").unwrap();
+        assert!(matches!(
+            template.expand(&variables()),
+            Err(UriTemplateError::InvalidUri(_))
+        ));
+    }
+
+}
@@ -0,0 +1,139 @@
+#[cfg(test)]
+mod tests {
+    use base::foundation_types::Uri;
+
+    fn uri(text: &str) -> Uri {
+        Uri::parse(text).unwrap()
+    }
+
+    #[test]
+    fn normalize() {
+        let mut normalized = uri("HTTP://User@Example.COM:80/a/./b/../c/%7efile?x=%3a#Top");
+        normalized.normalize();
+        assert_eq!(normalized.to_string(), "http://User@example.com/a/c/~file?x=%3A#Top");
+        assert_eq!(normalized.port(), None);
+    }
+
+    #[test]
+    fn normalize_keeps_reserved_characters_encoded() {
+        let mut normalized = uri("http://example.com/?a=%2f%3f&b=%26%3d&c=%2B&d=%41%2d");
+        normalized.normalize();
+        assert_eq!(normalized.to_string(), "http://example.com/?a=%2F%3F&b=%26%3D&c=%2B&d=A-");
+        assert!(!uri("http://example.com/?x=%3A").equivalent(&uri("http://example.com/?x=:")));
+        assert!(uri("http://example.com/?x=%3a").equivalent(&uri("http://example.com/?x=%3A")));
+    }
+
+    #[test]
+    fn normalize_keeps_reserved_characters_encoded_in_path_and_fragment() {
+        let mut normalized = uri("http://example.com/a%3ab/%2f/%7e#x%2cy");
+        normalized.normalize();
+        assert_eq!(normalized.to_string(), "http://example.com/a%3Ab/%2F/~#x%2Cy");
+        assert_eq!(normalized.path_to_string().unwrap(), "/a:b///~");
+        assert_eq!(normalized.fragment_to_string().unwrap().as_deref(), Some("x,y"));
+        assert!(!uri("http://example.com/a%3Ab").equivalent(&uri("http://example.com/a:b")));
+        assert!(!uri("http://example.com/a#x%2Cy").equivalent(&uri("http://example.com/a#x,y")));
+        assert!(uri("http://example.com/a%3ab#x%2cy")
+            .equivalent(&uri("http://example.com/a%3Ab#x%2Cy")));
+    }
+
+    #[test]
+    fn normalize_scheme_set_later() {
+        let mut normalized = uri("//example.com:443/");
+        normalized.set_scheme(Some("HTTPS".to_string())).unwrap();
+        normalized.normalize();
+        assert_eq!(normalized.to_string(), "https://example.com/");
+    }
+
+    #[test]
+    fn normalize_keeps_other_ports() {
+        let mut normalized = uri("http://example.com:8080/a");
+        normalized.normalize();
+        assert_eq!(normalized.port(), Some(8080));
+        let mut normalized = uri("https://example.com:80/a");
+        normalized.normalize();
+        assert_eq!(normalized.port(), Some(80));
+    }
+
+    #[test]
+    fn equivalent() {
+        let a = uri("HTTP://Example.com:80/a");
+        let b = uri("http://example.com/a");
+        assert_ne!(a, b);
+        assert!(a.equivalent(&b));
+        assert!(uri("https://example.com:443").equivalent(&uri("https://example.com/")));
+        assert!(uri("http://example.com/%7Euser/%41").equivalent(&uri("http://example.com/~user/A")));
+        assert!(uri("http://example.com/a/b/../c").equivalent(&uri("http://example.com/a/c")));
+    }
+
+    #[test]
+    fn not_equivalent() {
+        assert!(!uri("http://example.com/a").equivalent(&uri("https://example.com/a")));
+        assert!(!uri("http://example.com/a").equivalent(&uri("http://example.com/A")));
+        assert!(!uri("http://example.com:8080/").equivalent(&uri("http://example.com/")));
+        assert!(!uri("http://example.com/a?x").equivalent(&uri("http://example.com/a?y")));
+        assert!(!uri("http://example.com/a?x=%26").equivalent(&uri("http://example.com/a?x=&")));
+    }
+
+    #[test]
+    fn query_parameters() {
+        let ehr = uri("https://example.com/ehr?version=2&filter=a%26b%3Dc&name=John+Doe&verbose");
+        assert_eq!(
+            ehr.query_parameters(),
+            vec![
+                ("version".to_string(), "2".to_string()),
+                ("filter".to_string(), "a&b=c".to_string()),
+                ("name".to_string(), "John Doe".to_string()),
+                ("verbose".to_string(), "".to_string()),
+            ]
+        );
+        assert_eq!(ehr.query_parameter("filter"), Some("a&b=c".to_string()));
+        assert_eq!(ehr.query_parameter("missing"), None);
+        assert!(uri("https://example.com/ehr").query_parameters().is_empty());
+    }
+
+    #[test]
+    fn append_query_parameter() {
+        let mut ehr = uri("https://example.com/ehr");
+        ehr.append_query_parameter("filter", "a&b=c+d e");
+        ehr.append_query_parameter("tag", "x");
+        ehr.append_query_parameter("tag", "y");
+        assert_eq!(
+            ehr.to_string(),
+            "https://example.com/ehr?filter=a%26b%3Dc%2Bd%20e&tag=x&tag=y"
+        );
+        assert_eq!(ehr.query_parameter("filter"), Some("a&b=c+d e".to_string()));
+        let reparsed = uri(&ehr.to_string());
+        assert_eq!(reparsed, ehr);
+        assert_eq!(reparsed.query_parameter("filter"), Some("a&b=c+d e".to_string()));
+    }
+
+    #[test]
+    fn set_query_parameter() {
+        let mut ehr = uri("https://example.com/ehr?tag=x&version=1&tag=y#top");
+        ehr.set_query_parameter("tag", "z");
+        assert_eq!(ehr.to_string(), "https://example.com/ehr?tag=z&version=1#top");
+        ehr.set_query_parameter("page", "2");
+        assert_eq!(ehr.to_string(), "https://example.com/ehr?tag=z&version=1&page=2#top");
+    }
+
+    #[test]
+    fn remove_query_parameter() {
+        let mut ehr = uri("https://example.com/ehr?tag=x&version=1&tag=y");
+        assert!(ehr.remove_query_parameter("tag"));
+        assert_eq!(ehr.to_string(), "https://example.com/ehr?version=1");
+        assert!(!ehr.remove_query_parameter("tag"));
+        assert!(ehr.remove_query_parameter("version"));
+        assert_eq!(ehr.to_string(), "https://example.com/ehr");
+        assert_eq!(ehr.query(), None);
+    }
+
+    #[test]
+    fn set_query_encodes_plus() {
+        let mut ehr = uri("https://example.com/ehr");
+        ehr.set_query(b"a=1+1".to_vec());
+        assert_eq!(ehr.to_string(), "https://example.com/ehr?a=1%2B1");
+        assert_eq!(ehr.query(), Some(&b"a=1+1"[..]));
+        assert_eq!(ehr.query_parameter("a"), Some("1+1".to_string()));
+    }
+
+}
@@ -0,0 +1,170 @@
+#[cfg(all(test, feature = "xml"))]
+mod tests {
+    use base::foundation_types::{
+        Any,
+        Boolean,
+        Character,
+        Double,
+        Hash,
+        Integer,
+        List,
+        OpenEhrValue,
+        Octets,
+        TerminologyCode,
+        TerminologyTerm,
+        Uri,
+    };
+    use base::serialisation::{from_xml, to_xml, Error};
+
+    fn term() -> TerminologyTerm {
+        TerminologyTerm {
+            concept: TerminologyCode {
+                terminology_id: "SNOMED-CT".to_string(),
+                terminology_version: String::new(),
+                code_string: "38341003".to_string(),
+                uri: Uri::parse("http://snomed.info/id/38341003").unwrap(),
+            },
+            text: "Hypertension <disorder> & more".to_string(),
+        }
+    }
+
+    #[test]
+    fn write_primitive() {
+        assert_eq!(
+            to_xml("value", &Integer::new(42)).unwrap(),
+            r#"<value xmlns="http://schemas.openehr.org/v1" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:type="INTEGER">42</value>"#
+        );
+    }
+
+    #[test]
+    fn octets_round_trip() {
+        let octets = Octets::from(b"hi??".to_vec());
+        let xml = to_xml("data", &octets).unwrap();
+        assert!(xml.ends_with(r#"xsi:type="OCTETS">aGk/Pw==</data>"#));
+        assert_eq!(from_xml::<Octets>(&xml).unwrap(), octets);
+    }
+
+    #[test]
+    fn write_object() {
+        let xml = to_xml("term", &term()).unwrap();
+        assert!(xml.starts_with(r#"<term xmlns="http://schemas.openehr.org/v1""#));
+        assert!(xml.contains(r#"xsi:type="TERMINOLOGY_TERM""#));
+        assert!(xml.contains("<concept><terminology_id>SNOMED-CT</terminology_id>"));
+        assert!(xml.contains("<text>Hypertension &lt;disorder&gt; &amp; more</text>"));
+    }
+
+    #[test]
+    fn round_trip() {
+        let term: TerminologyTerm = from_xml(&to_xml("term", &term()).unwrap()).unwrap();
+        assert!(term == self::term());
+        let value: Double = from_xml(&to_xml("value", &Double::new(0.1)).unwrap()).unwrap();
+        assert!(value == Double::new(0.1));
+        let value: Boolean = from_xml(&to_xml("value", &Boolean::new(false)).unwrap()).unwrap();
+        assert!(value == false);
+        let value: Character = from_xml(&to_xml("value", &Character::new('&')).unwrap()).unwrap();
+        assert!(value == '&');
+    }
+
+    #[test]
+    fn read_prefixed_document() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+            <!-- reference value -->
+            <v1:value xmlns:v1="http://schemas.openehr.org/v1"
+                      xmlns:i="http://www.w3.org/2001/XMLSchema-instance"
+                      i:type="v1:INTEGER">42</v1:value>"#;
+        let value: Integer = from_xml(xml).unwrap();
+        assert!(value == Integer::new(42));
+    }
+
+    #[test]
+    fn reject_missing_namespace_or_type() {
+        let xml = r#"<value xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:type="INTEGER">42</value>"#;
+        assert_eq!(from_xml::<Integer>(xml).err(), Some(Error::MissingNamespace));
+        let xml = r#"<value xmlns="http://schemas.openehr.org/v1" type="INTEGER">42</value>"#;
+        assert_eq!(from_xml::<Integer>(xml).err(), Some(Error::MissingType));
+    }
+
+    #[test]
+    fn reject_unknown_or_incompatible_type() {
+        let xml = to_xml("value", &Integer::new(42)).unwrap();
+        assert_eq!(
+            from_xml::<Double>(&xml).err(),
+            Some(Error::IncompatibleType {
+                expected: "DOUBLE".to_string(),
+                found: "INTEGER".to_string(),
+            })
+        );
+        let xml = xml.replace("INTEGER", "DV_INTEGER");
+        assert_eq!(from_xml::<Integer>(&xml).err(), Some(Error::UnknownType("DV_INTEGER".to_string())));
+    }
+
+    #[test]
+    fn reject_invalid_content() {
+        let xml = to_xml("value", &Integer::new(42)).unwrap().replace(">42<", ">ten<");
+        assert!(matches!(from_xml::<Integer>(&xml), Err(Error::InvalidDocument(_))));
+        assert!(matches!(from_xml::<Integer>(""), Err(Error::InvalidDocument(_))));
+    }
+
+    #[test]
+    fn reject_invalid_element_name() {
+        assert!(matches!(to_xml("1value", &Integer::new(1)), Err(Error::InvalidDocument(_))));
+    }
+
+    #[test]
+    fn nested_types() {
+        let integers: List<OpenEhrValue> = vec![OpenEhrValue::from(Integer::new(1)), OpenEhrValue::from(Integer::new(2))].into();
+        let items: List<OpenEhrValue> = vec![
+            OpenEhrValue::from(integers),
+            OpenEhrValue::from(term()),
+        ]
+        .into();
+        let value = OpenEhrValue::from(items);
+        let xml = to_xml("value", &value).unwrap();
+        assert!(xml.contains(r#"xsi:type="LIST&lt;ANY&gt;"><items xsi:type="LIST&lt;INTEGER&gt;"><items>1</items><items>2</items></items>"#));
+        assert!(xml.contains(r#"<items xsi:type="TERMINOLOGY_TERM"><concept><terminology_id>SNOMED-CT</terminology_id>"#));
+        assert!(from_xml::<OpenEhrValue>(&xml).unwrap() == value);
+    }
+
+    #[test]
+    fn open_ehr_value_primitive() {
+        let value = OpenEhrValue::from(Integer::new(42));
+        let xml = to_xml("value", &value).unwrap();
+        assert_eq!(xml, to_xml("value", &Integer::new(42)).unwrap());
+        assert!(from_xml::<OpenEhrValue>(&xml).unwrap() == value);
+        let xml = r#"<value xmlns="http://schemas.openehr.org/v1" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance" xsi:type="LIST&lt;ANY&gt;"><items xsi:type="INTEGER64">7</items><items>x</items></value>"#;
+        let items = from_xml::<OpenEhrValue>(xml).unwrap().downcast::<List<OpenEhrValue>>().unwrap();
+        assert_eq!(items.get(0).unwrap().type_of(), "Integer64");
+        assert_eq!(items.get(1).unwrap().type_of(), "String");
+    }
+
+    #[test]
+    fn polymorphic_member_round_trip() {
+        let integers: List<OpenEhrValue> = vec![OpenEhrValue::from(Integer::new(1))].into();
+        let mut hash = Hash::new();
+        hash.insert(OpenEhrValue::from(base::foundation_types::String::from("term")), OpenEhrValue::from(term()));
+        hash.insert(OpenEhrValue::from(base::foundation_types::String::from("integers")), OpenEhrValue::from(integers));
+        let value = OpenEhrValue::from(hash);
+        let xml = to_xml("value", &value).unwrap();
+        assert!(xml.contains(r#"<entries><key>term</key><value xsi:type="TERMINOLOGY_TERM"><concept>"#));
+        assert!(xml.contains(r#"<entries><key>integers</key><value xsi:type="LIST&lt;INTEGER&gt;"><items>1</items></value></entries>"#));
+        assert!(from_xml::<OpenEhrValue>(&xml).unwrap() == value);
+    }
+
+    #[test]
+    fn reject_wrong_nested_type() {
+        let items: List<OpenEhrValue> = vec![OpenEhrValue::from(term())].into();
+        let xml = to_xml("value", &OpenEhrValue::from(items)).unwrap();
+        let result = from_xml::<OpenEhrValue>(&xml.replace(r#"<items xsi:type="TERMINOLOGY_TERM">"#, r#"<items xsi:type="DV_TEXT">"#));
+        assert_eq!(result.err(), Some(Error::UnknownType("DV_TEXT".to_string())));
+        let result = from_xml::<OpenEhrValue>(&xml.replace(r#"<items xsi:type="TERMINOLOGY_TERM">"#, r#"<items xsi:type="TERMINOLOGY_CODE">"#));
+        assert!(matches!(result, Err(Error::InvalidDocument(_))));
+        let xml = to_xml("term", &term()).unwrap();
+        let result = from_xml::<TerminologyTerm>(&xml.replace("<concept>", r#"<concept xsi:type="INTEGER">"#));
+        assert!(matches!(result, Err(Error::InvalidDocument(_))));
+        let result = from_xml::<TerminologyTerm>(&xml.replace("<terminology_id>", r#"<terminology_id xsi:type="INTEGER">"#));
+        assert!(matches!(result, Err(Error::InvalidDocument(_))));
+        let term: TerminologyTerm = from_xml(&xml.replace("<terminology_id>", r#"<terminology_id xsi:type="STRING">"#)).unwrap();
+        assert!(term == self::term());
+    }
+
+}